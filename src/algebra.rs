@@ -1,3 +1,5 @@
+use crate::error::HellError;
+
 /// Computes the factorial of a given number `n`.
 ///
 /// # Arguments
@@ -15,7 +17,9 @@
 ///
 /// # Examples
 ///
-/// ```rust 
+/// ```rust
+/// use hell::algebra::factorial;
+///
 /// assert_eq!(factorial(5), 120);
 /// assert_eq!(factorial(0), 1);
 /// ```
@@ -27,9 +31,8 @@
 ///
 /// # Performance
 ///
-/// This implementation uses an iterative approach with Rust's built-in iterator and 
-/// product method to compute the factorial, which is generally efficient in terms of 
-/// both time and space complexity.
+/// This implementation uses a simple iterative loop, which is efficient in terms of both
+/// time and space complexity. It is a `const fn`, so it can also be evaluated at compile time.
 ///
 /// # Usage
 ///
@@ -40,10 +43,97 @@
 /// # Limitations
 ///
 /// The function is limited by the size of `u64`, which means it is only suitable for 
-/// small values of `n`. For larger values, consider using a library that supports 
-/// arbitrary-precision arithmetic.
-pub fn factorial(n: u64) -> u64 {
-    (1..=n).product()
+/// small values of `n`. For larger values, enable the `bignum` feature and use
+/// `factorial_big`.
+pub const fn factorial(n: u64) -> u64 {
+    let mut result = 1;
+    let mut i = 2;
+    while i <= n {
+        result *= i;
+        i += 1;
+    }
+    result
+}
+
+/// Computes the factorial of `n`, returning `None` if the result does not fit in a `u64`.
+///
+/// Unlike [`factorial`], which overflows for `n > 20`, this function detects the overflow. It is
+/// a `const fn`, so it can be used to build compile-time constants and lookup tables.
+///
+/// # Arguments
+///
+/// * `n` - A non-negative integer whose factorial is to be computed.
+///
+/// # Returns
+///
+/// * `Some(n!)` for `n <= 20`, and `None` for larger `n`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::checked_factorial;
+///
+/// const FACTORIALS: [u64; 6] = {
+///     let mut table = [0; 6];
+///     let mut i = 0;
+///     while i < table.len() {
+///         table[i] = match checked_factorial(i as u64) {
+///             Some(value) => value,
+///             None => panic!("factorial overflow"),
+///         };
+///         i += 1;
+///     }
+///     table
+/// };
+/// assert_eq!(FACTORIALS, [1, 1, 2, 6, 24, 120]);
+///
+/// assert_eq!(checked_factorial(20), Some(2_432_902_008_176_640_000));
+/// assert_eq!(checked_factorial(21), None);
+/// ```
+pub const fn checked_factorial(n: u64) -> Option<u64> {
+    let mut result: u64 = 1;
+    let mut i = 2;
+    while i <= n {
+        result = match result.checked_mul(i) {
+            Some(value) => value,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(result)
+}
+
+/// Computes the factorial of `n` exactly, as an arbitrary-precision integer.
+///
+/// This is the arbitrary-precision counterpart of [`factorial`]. It is only available with the
+/// `bignum` feature.
+///
+/// # Arguments
+///
+/// * `n` - A non-negative integer whose factorial is to be computed. There is no upper limit
+///   other than available memory and time.
+///
+/// # Returns
+///
+/// * The factorial of `n` as a `BigUint`. If `n` is 0 or 1, the function returns 1.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "bignum")] {
+/// use hell::algebra::factorial_big;
+///
+/// assert_eq!(factorial_big(5).to_string(), "120");
+/// assert_eq!(factorial_big(25).to_string(), "15511210043330985984000000");
+/// # }
+/// ```
+///
+/// # Performance
+///
+/// The result has `O(n log n)` bits, so each multiplication grows more expensive as `n` increases.
+#[cfg(feature = "bignum")]
+pub fn factorial_big(n: u64) -> num_bigint::BigUint {
+    (1..=n).map(num_bigint::BigUint::from).product()
 }
 
 /// Computes the `n`-th Fibonacci number.
@@ -63,6 +153,8 @@ pub fn factorial(n: u64) -> u64 {
 /// # Examples
 ///
 /// ```rust
+/// use hell::algebra::fibonacci;
+///
 /// assert_eq!(fibonacci(5), 5);
 /// assert_eq!(fibonacci(10), 55);
 /// ```
@@ -91,7 +183,7 @@ pub fn factorial(n: u64) -> u64 {
 /// This recursive implementation is not suitable for large values of `n` due to its 
 /// inefficiency and the risk of stack overflow. Consider alternative implementations 
 /// for performance-critical applications or large `n`.
-pub fn fibonacci(n: u64) -> u64 {
+pub const fn fibonacci(n: u64) -> u64 {
     match n {
         0 => 0,
         1 => 1,
@@ -99,6 +191,110 @@ pub fn fibonacci(n: u64) -> u64 {
     }
 }
 
+/// Computes the `n`-th Fibonacci number modulo `m`.
+///
+/// The Fibonacci numbers satisfy `[[1, 1], [1, 0]]^n = [[F(n+1), F(n)], [F(n), F(n-1)]]`, so
+/// raising that matrix to the `n`-th power by repeated squaring, reducing every entry modulo `m`,
+/// gives `F(n) mod m` without ever computing the huge number `F(n)` itself.
+///
+/// # Arguments
+///
+/// * `n` - The position in the Fibonacci sequence. Any `u64` value works.
+/// * `m` - The modulus. Must be positive.
+///
+/// # Returns
+///
+/// * `F(n) mod m`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::{fibonacci, fibonacci_mod};
+///
+/// assert_eq!(fibonacci_mod(10, 1000), fibonacci(10));
+/// assert_eq!(fibonacci_mod(90, 1_000_000_007), 2_880_067_194_370_816_120 % 1_000_000_007);
+/// assert_eq!(fibonacci_mod(10u64.pow(18), 1_000_000_007), 209_783_453);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Performance
+///
+/// Runs in `O(log n)` time, using 128-bit intermediate products so that any `u64` modulus is safe.
+pub fn fibonacci_mod(n: u64, m: u64) -> u64 {
+    assert!(m != 0, "Modulus must be positive.");
+    let m = m as u128;
+    let mul = |a: [u128; 4], b: [u128; 4]| {
+        [
+            (a[0] * b[0] % m + a[1] * b[2] % m) % m,
+            (a[0] * b[1] % m + a[1] * b[3] % m) % m,
+            (a[2] * b[0] % m + a[3] * b[2] % m) % m,
+            (a[2] * b[1] % m + a[3] * b[3] % m) % m,
+        ]
+    };
+    // Row-major 2x2 matrices [a, b, c, d].
+    let mut result = [1 % m, 0, 0, 1 % m];
+    let mut base = [1 % m, 1 % m, 1 % m, 0];
+    let mut n = n;
+    while n > 0 {
+        if n & 1 == 1 {
+            result = mul(result, base);
+        }
+        base = mul(base, base);
+        n >>= 1;
+    }
+    result[1] as u64
+}
+
+/// Computes the Pisano period `π(m)`, the period of the Fibonacci sequence modulo `m`.
+///
+/// The Fibonacci numbers modulo any `m` repeat, and the period always starts over at the pair
+/// `(0, 1)`. Combined with [`fibonacci_mod`], this lets `F(n) mod m` be reduced to
+/// `F(n mod π(m)) mod m`.
+///
+/// # Arguments
+///
+/// * `m` - The modulus. Must be positive.
+///
+/// # Returns
+///
+/// * The length of the period. It is at most `6m`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::pisano_period;
+///
+/// assert_eq!(pisano_period(2), 3); // 0, 1, 1, 0, 1, 1, ...
+/// assert_eq!(pisano_period(10), 60);
+/// assert_eq!(pisano_period(1), 1);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Performance
+///
+/// Walks the sequence until it repeats, which takes `O(m)` time and constant memory.
+pub fn pisano_period(m: u64) -> u64 {
+    assert!(m != 0, "Modulus must be positive.");
+    if m == 1 {
+        return 1;
+    }
+    let (mut a, mut b) = (0u64, 1u64);
+    let mut period = 0;
+    loop {
+        (a, b) = (b, ((a as u128 + b as u128) % m as u128) as u64);
+        period += 1;
+        if a == 0 && b == 1 {
+            return period;
+        }
+    }
+}
+
 /// Computes the base-10 logarithm of a positive integer `n`.
 ///
 /// # Arguments
@@ -113,8 +309,10 @@ pub fn fibonacci(n: u64) -> u64 {
 /// # Examples
 ///
 /// ```rust
+/// use hell::algebra::log10;
+///
 /// assert!((log10(100.0) - 2.0).abs() < 1e-10);
-/// assert!((log10(50.0) - 1.69897).abs() < 1e-10);
+/// assert!((log10(50.0) - 1.69897).abs() < 1e-5);
 /// ```
 ///
 /// # Performance
@@ -153,6 +351,8 @@ pub fn log10(n: f64) -> f64 {
 /// # Examples
 ///
 /// ```rust
+/// use hell::algebra::power;
+///
 /// assert_eq!(power(2, 3), 8);
 /// assert_eq!(power(5, 0), 1);
 /// ```
@@ -160,7 +360,7 @@ pub fn log10(n: f64) -> f64 {
 /// # Performance
 ///
 /// This implementation uses an iterative approach which is efficient with a time 
-/// complexity of `O(exp)`.
+/// complexity of `O(exp)`. It is a `const fn`, so it can also be evaluated at compile time.
 ///
 /// # Usage
 ///
@@ -170,12 +370,357 @@ pub fn log10(n: f64) -> f64 {
 /// # Limitations
 ///
 /// The function is limited by the maximum value of `u64`, which can lead to overflow 
-/// for very large results. Consider using a library with support for arbitrary-precision 
-/// arithmetic for larger calculations.
-pub fn power(base: u64, exp: u64) -> u64 {
+/// for very large results. For larger calculations, enable the `bignum` feature and use
+/// `power_big`.
+pub const fn power(base: u64, exp: u64) -> u64 {
     let mut result = 1;
-    for _ in 0..exp {
+    let mut i = 0;
+    while i < exp {
         result *= base;
+        i += 1;
     }
     result
 }
+
+/// Computes `base` raised to the power `exp`, returning `None` if the result does not fit in a
+/// `u64`.
+///
+/// Unlike [`power`], this function detects overflow, and it uses exponentiation by squaring so
+/// that large exponents are cheap. It is a `const fn`, so it can be used to build compile-time
+/// constants and lookup tables.
+///
+/// # Arguments
+///
+/// * `base` - The base number as a `u64`.
+/// * `exp` - The exponent as a `u64`.
+///
+/// # Returns
+///
+/// * `Some(base^exp)` if the result fits in a `u64`, and `None` otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::checked_power;
+///
+/// const KIBIBYTE: u64 = match checked_power(2, 10) {
+///     Some(value) => value,
+///     None => panic!("overflow"),
+/// };
+/// assert_eq!(KIBIBYTE, 1024);
+///
+/// assert_eq!(checked_power(10, 19), Some(10_000_000_000_000_000_000));
+/// assert_eq!(checked_power(10, 20), None);
+/// assert_eq!(checked_power(1, u64::MAX), Some(1));
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(log exp)` multiplications.
+pub const fn checked_power(base: u64, exp: u64) -> Option<u64> {
+    let mut result: u64 = 1;
+    let mut base = base;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = match result.checked_mul(base) {
+                Some(value) => value,
+                None => return None,
+            };
+        }
+        exp >>= 1;
+        if exp > 0 {
+            base = match base.checked_mul(base) {
+                Some(value) => value,
+                None => return None,
+            };
+        }
+    }
+    Some(result)
+}
+
+/// Computes the result of raising `base` to the exponent `exp` exactly, as an arbitrary-precision
+/// integer.
+///
+/// This is the arbitrary-precision counterpart of [`power`]. It is only available with the
+/// `bignum` feature.
+///
+/// # Arguments
+///
+/// * `base` - The base number as a `u64`.
+/// * `exp` - The exponent as a `u64`.
+///
+/// # Returns
+///
+/// * `base` raised to the power of `exp` as a `BigUint`. If `exp` is 0, the function returns 1.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "bignum")] {
+/// use hell::algebra::power_big;
+///
+/// assert_eq!(power_big(2, 3).to_string(), "8");
+/// assert_eq!(power_big(2, 100).to_string(), "1267650600228229401496703205376");
+/// # }
+/// ```
+///
+/// # Performance
+///
+/// Uses exponentiation by squaring, so only `O(log exp)` big-integer multiplications are needed.
+#[cfg(feature = "bignum")]
+pub fn power_big(base: u64, exp: u64) -> num_bigint::BigUint {
+    num_traits::Pow::pow(num_bigint::BigUint::from(base), exp)
+}
+
+/// Converts `n` to its digits in the given radix, most significant digit first.
+///
+/// # Arguments
+///
+/// * `n` - The number to convert.
+/// * `radix` - The base, between 2 and 36 inclusive.
+///
+/// # Returns
+///
+/// * The digit values, each in `0..radix`. Zero is represented as `[0]`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::to_digits;
+///
+/// assert_eq!(to_digits(6, 2), vec![1, 1, 0]);
+/// assert_eq!(to_digits(255, 16), vec![15, 15]);
+/// assert_eq!(to_digits(0, 10), vec![0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radix` is outside the range 2 to 36.
+pub fn to_digits(n: u64, radix: u32) -> Vec<u8> {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36.");
+    let radix = radix as u64;
+    let mut digits = Vec::new();
+    let mut n = n;
+    loop {
+        digits.push((n % radix) as u8);
+        n /= radix;
+        if n == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// Converts digits in the given radix, most significant digit first, back into a number.
+///
+/// # Arguments
+///
+/// * `digits` - The digit values, each in `0..radix`. Leading zeros are allowed.
+/// * `radix` - The base, between 2 and 36 inclusive.
+///
+/// # Returns
+///
+/// * `Ok(n)` with the number the digits represent. An empty slice represents `0`.
+/// * `Err(HellError::DomainError)` if a digit is not smaller than `radix`.
+/// * `Err(HellError::Overflow)` if the number does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::from_digits;
+///
+/// assert_eq!(from_digits(&[1, 1, 0], 2), Ok(6));
+/// assert_eq!(from_digits(&[15, 15], 16), Ok(255));
+/// assert!(from_digits(&[2], 2).is_err());
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radix` is outside the range 2 to 36.
+pub fn from_digits(digits: &[u8], radix: u32) -> Result<u64, HellError> {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36.");
+    digits.iter().try_fold(0u64, |n, &digit| {
+        if digit as u32 >= radix {
+            return Err(HellError::DomainError("Digit is not valid in this radix."));
+        }
+        n.checked_mul(radix as u64)
+            .and_then(|n| n.checked_add(digit as u64))
+            .ok_or(HellError::Overflow("Number does not fit in a u64."))
+    })
+}
+
+/// Formats `n` in the given radix, using the digits `0-9` followed by the letters `a-z`.
+///
+/// # Arguments
+///
+/// * `n` - The number to format.
+/// * `radix` - The base, between 2 and 36 inclusive.
+///
+/// # Returns
+///
+/// * The representation of `n` without any prefix, in lowercase.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::to_base;
+///
+/// assert_eq!(to_base(255, 16), "ff");
+/// assert_eq!(to_base(10, 2), "1010");
+/// assert_eq!(to_base(35, 36), "z");
+/// assert_eq!(to_base(0, 7), "0");
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radix` is outside the range 2 to 36.
+pub fn to_base(n: u64, radix: u32) -> String {
+    to_digits(n, radix)
+        .into_iter()
+        .map(|d| char::from_digit(d as u32, radix).expect("Digit is smaller than the radix."))
+        .collect()
+}
+
+/// Parses a number written in the given radix.
+///
+/// Digits above 9 are the letters `a-z`, in either case. No sign, prefix (such as `0x`), or
+/// whitespace is accepted.
+///
+/// # Arguments
+///
+/// * `s` - The text to parse.
+/// * `radix` - The base, between 2 and 36 inclusive.
+///
+/// # Returns
+///
+/// * `Ok(n)` with the parsed number.
+/// * `Err(HellError::ParseError)` if `s` is empty or contains a character that is not a digit in
+///   this radix.
+/// * `Err(HellError::Overflow)` if the number does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::from_base;
+/// use hell::error::HellError;
+///
+/// assert_eq!(from_base("ff", 16), Ok(255));
+/// assert_eq!(from_base("FF", 16), Ok(255));
+/// assert_eq!(from_base("1010", 2), Ok(10));
+/// assert!(matches!(from_base("12", 2), Err(HellError::ParseError(_))));
+/// assert!(matches!(from_base("zzzzzzzzzzzzzz", 36), Err(HellError::Overflow(_))));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `radix` is outside the range 2 to 36.
+pub fn from_base(s: &str, radix: u32) -> Result<u64, HellError> {
+    assert!((2..=36).contains(&radix), "Radix must be between 2 and 36.");
+    if s.is_empty() {
+        return Err(HellError::ParseError("Empty number."));
+    }
+    let digits = s
+        .chars()
+        .map(|c| c.to_digit(radix).map(|d| d as u8))
+        .collect::<Option<Vec<u8>>>()
+        .ok_or(HellError::ParseError("Invalid digit for this radix."))?;
+    from_digits(&digits, radix)
+}
+
+/// Returns the decimal digits of `n`, most significant digit first.
+///
+/// This is [`to_digits`] with radix 10.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::digits;
+///
+/// assert_eq!(digits(1234), vec![1, 2, 3, 4]);
+/// assert_eq!(digits(0), vec![0]);
+/// ```
+pub fn digits(n: u64) -> Vec<u8> {
+    to_digits(n, 10)
+}
+
+/// Returns the sum of the decimal digits of `n`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::digit_sum;
+///
+/// assert_eq!(digit_sum(1234), 10);
+/// assert_eq!(digit_sum(u64::MAX), 87);
+/// ```
+pub fn digit_sum(n: u64) -> u64 {
+    let mut sum = 0;
+    let mut n = n;
+    while n > 0 {
+        sum += n % 10;
+        n /= 10;
+    }
+    sum
+}
+
+/// Returns the number of decimal digits of `n`. Zero has one digit.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::digit_count;
+///
+/// assert_eq!(digit_count(0), 1);
+/// assert_eq!(digit_count(999), 3);
+/// assert_eq!(digit_count(1000), 4);
+/// assert_eq!(digit_count(u64::MAX), 20);
+/// ```
+pub fn digit_count(n: u64) -> u32 {
+    n.checked_ilog10().map_or(1, |log| log + 1)
+}
+
+/// Returns the number formed by the decimal digits of `n` in reverse order.
+///
+/// Trailing zeros of `n` become leading zeros and therefore disappear, so `reverse_digits(120)` is
+/// `21`.
+///
+/// # Returns
+///
+/// * `Some(reversed)`, or `None` if the reversed number does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::reverse_digits;
+///
+/// assert_eq!(reverse_digits(1234), Some(4321));
+/// assert_eq!(reverse_digits(120), Some(21));
+/// assert_eq!(reverse_digits(u64::MAX), None);
+/// ```
+pub fn reverse_digits(n: u64) -> Option<u64> {
+    let mut reversed: u64 = 0;
+    let mut n = n;
+    while n > 0 {
+        reversed = reversed.checked_mul(10)?.checked_add(n % 10)?;
+        n /= 10;
+    }
+    Some(reversed)
+}
+
+/// Returns `true` if the decimal representation of `n` reads the same forwards and backwards.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::algebra::is_palindrome_number;
+///
+/// assert!(is_palindrome_number(12321));
+/// assert!(is_palindrome_number(7));
+/// assert!(!is_palindrome_number(120));
+/// ```
+pub fn is_palindrome_number(n: u64) -> bool {
+    let digits = digits(n);
+    digits.iter().eq(digits.iter().rev())
+}
@@ -0,0 +1,333 @@
+//! Forward-mode automatic differentiation with dual numbers.
+//!
+//! A [`Dual`] number carries a value together with its derivative. Every arithmetic operation
+//! and elementary function on duals applies the chain rule to the derivative part, so evaluating
+//! a function on `Dual::variable(x)` yields both `f(x)` and `f'(x)`, exact up to floating-point
+//! rounding. Unlike [`calculus::derivative`](crate::calculus::derivative), there is no step size
+//! to choose and no truncation or cancellation error.
+//!
+//! # Examples
+//!
+//! ```
+//! use hell::autodiff::{derivative_exact, Dual};
+//!
+//! // f(x) = x² · sin x, written once for dual numbers.
+//! let f = |x: Dual| x * x * x.sin();
+//! let df = derivative_exact(f);
+//!
+//! let x = 2.0_f64;
+//! assert!((df(x) - (2.0 * x * x.sin() + x * x * x.cos())).abs() < 1e-14);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A dual number `value + derivative·ε`, where `ε² = 0`.
+///
+/// Arithmetic on dual numbers propagates derivatives by the rules of calculus: the derivative
+/// part of `a * b` is `a.value · b.derivative + a.derivative · b.value`, and so on. `Dual`
+/// supports the usual operators (also mixed with `f64` on either side) and the elementary
+/// functions, so most formulas can be written for `Dual` exactly as they would be for `f64`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::autodiff::Dual;
+///
+/// let x = Dual::variable(3.0);
+/// let y = 2.0 * x * x + 1.0;
+///
+/// assert_eq!(y.value, 19.0); // 2·3² + 1
+/// assert_eq!(y.derivative, 12.0); // 4·3
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dual {
+    pub value: f64,
+    pub derivative: f64,
+}
+
+impl Dual {
+    /// Creates a dual number from its value and derivative parts.
+    pub const fn new(value: f64, derivative: f64) -> Self {
+        Dual { value, derivative }
+    }
+
+    /// Creates the dual number for the variable of differentiation at `x`, with derivative `1`.
+    pub const fn variable(x: f64) -> Self {
+        Dual::new(x, 1.0)
+    }
+
+    /// Creates the dual number for a constant, with derivative `0`.
+    pub const fn constant(c: f64) -> Self {
+        Dual::new(c, 0.0)
+    }
+
+    /// Applies a function with known value `f` and derivative `df` at `self.value`, by the chain
+    /// rule.
+    fn chain(self, f: f64, df: f64) -> Dual {
+        Dual::new(f, df * self.derivative)
+    }
+
+    /// Computes the sine.
+    pub fn sin(self) -> Dual {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(sin, cos)
+    }
+
+    /// Computes the cosine.
+    pub fn cos(self) -> Dual {
+        let (sin, cos) = self.value.sin_cos();
+        self.chain(cos, -sin)
+    }
+
+    /// Computes the tangent.
+    pub fn tan(self) -> Dual {
+        let tan = self.value.tan();
+        self.chain(tan, 1.0 + tan * tan)
+    }
+
+    /// Computes the exponential `e^x`.
+    pub fn exp(self) -> Dual {
+        let exp = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    /// Computes the natural logarithm.
+    pub fn ln(self) -> Dual {
+        self.chain(self.value.ln(), 1.0 / self.value)
+    }
+
+    /// Computes the square root.
+    pub fn sqrt(self) -> Dual {
+        let sqrt = self.value.sqrt();
+        self.chain(sqrt, 0.5 / sqrt)
+    }
+
+    /// Raises the number to an integer power.
+    pub fn powi(self, n: i32) -> Dual {
+        let df = if n == 0 { 0.0 } else { f64::from(n) * self.value.powi(n - 1) };
+        self.chain(self.value.powi(n), df)
+    }
+
+    /// Raises the number to a real power.
+    pub fn powf(self, p: f64) -> Dual {
+        self.chain(self.value.powf(p), p * self.value.powf(p - 1.0))
+    }
+
+    /// Raises the number to a dual power, `x^y = e^(y·ln x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::autodiff::Dual;
+    ///
+    /// // d/dx x^x = x^x · (ln x + 1)
+    /// let x = Dual::variable(2.0);
+    /// let y = x.pow(x);
+    /// assert!((y.derivative - 4.0 * (2f64.ln() + 1.0)).abs() < 1e-12);
+    /// ```
+    pub fn pow(self, exponent: Dual) -> Dual {
+        (exponent * self.ln()).exp()
+    }
+
+    /// Computes the hyperbolic tangent.
+    pub fn tanh(self) -> Dual {
+        let tanh = self.value.tanh();
+        self.chain(tanh, 1.0 - tanh * tanh)
+    }
+
+    /// Computes the absolute value. Its derivative at zero is taken to be zero.
+    pub fn abs(self) -> Dual {
+        self.chain(self.value.abs(), if self.value == 0.0 { 0.0 } else { self.value.signum() })
+    }
+}
+
+/// Returns the derivative of a function written for dual numbers, as a function of `f64`.
+///
+/// The returned closure evaluates `f` on `Dual::variable(x)` and returns the derivative part,
+/// which is exact up to floating-point rounding.
+///
+/// # Parameters
+///
+/// - `f`: The function to differentiate, taking and returning a [`Dual`].
+///
+/// # Returns
+///
+/// Returns a closure computing `f'(x)`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::autodiff::{derivative_exact, Dual};
+///
+/// let df = derivative_exact(|x: Dual| (x * x + 1.0).ln());
+/// assert!((df(1.0) - 1.0).abs() < 1e-15); // 2x / (x² + 1)
+/// ```
+pub fn derivative_exact<F>(f: F) -> impl Fn(f64) -> f64
+where
+    F: Fn(Dual) -> Dual,
+{
+    move |x| f(Dual::variable(x)).derivative
+}
+
+/// Computes the gradient of a function of several variables written for dual numbers.
+///
+/// The function is evaluated once per input, each time with that input as the variable of
+/// differentiation and the others as constants.
+///
+/// # Parameters
+///
+/// - `f`: The function to differentiate, taking a slice of [`Dual`] inputs.
+/// - `x`: The point at which the gradient is to be calculated.
+///
+/// # Returns
+///
+/// Returns the gradient as a `Vec` of `x.len()` partial derivatives.
+///
+/// # Examples
+///
+/// ```
+/// use hell::autodiff::{gradient_exact, Dual};
+///
+/// // f(x, y) = x²y + y³, so ∇f = (2xy, x² + 3y²).
+/// let g = gradient_exact(|v: &[Dual]| v[0] * v[0] * v[1] + v[1].powi(3), &[1.0, 2.0]);
+/// assert_eq!(g, vec![4.0, 13.0]);
+/// ```
+pub fn gradient_exact<F>(f: F, x: &[f64]) -> Vec<f64>
+where
+    F: Fn(&[Dual]) -> Dual,
+{
+    let mut point: Vec<Dual> = x.iter().map(|&v| Dual::constant(v)).collect();
+    (0..x.len())
+        .map(|j| {
+            point[j].derivative = 1.0;
+            let partial = f(&point).derivative;
+            point[j].derivative = 0.0;
+            partial
+        })
+        .collect()
+}
+
+impl From<f64> for Dual {
+    fn from(c: f64) -> Self {
+        Dual::constant(c)
+    }
+}
+
+impl fmt::Display for Dual {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.derivative < 0.0 {
+            write!(f, "{} - {}ε", self.value, -self.derivative)
+        } else {
+            write!(f, "{} + {}ε", self.value, self.derivative)
+        }
+    }
+}
+
+impl Add for Dual {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        Dual::new(self.value + other.value, self.derivative + other.derivative)
+    }
+}
+
+impl Sub for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        Dual::new(self.value - other.value, self.derivative - other.derivative)
+    }
+}
+
+impl Mul for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        Dual::new(self.value * other.value, self.value * other.derivative + self.derivative * other.value)
+    }
+}
+
+impl Div for Dual {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        Dual::new(
+            self.value / other.value,
+            (self.derivative * other.value - self.value * other.derivative) / (other.value * other.value),
+        )
+    }
+}
+
+impl Neg for Dual {
+    type Output = Dual;
+
+    fn neg(self) -> Dual {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl Add<f64> for Dual {
+    type Output = Dual;
+
+    fn add(self, other: f64) -> Dual {
+        Dual::new(self.value + other, self.derivative)
+    }
+}
+
+impl Sub<f64> for Dual {
+    type Output = Dual;
+
+    fn sub(self, other: f64) -> Dual {
+        Dual::new(self.value - other, self.derivative)
+    }
+}
+
+impl Mul<f64> for Dual {
+    type Output = Dual;
+
+    fn mul(self, other: f64) -> Dual {
+        Dual::new(self.value * other, self.derivative * other)
+    }
+}
+
+impl Div<f64> for Dual {
+    type Output = Dual;
+
+    fn div(self, other: f64) -> Dual {
+        Dual::new(self.value / other, self.derivative / other)
+    }
+}
+
+impl Add<Dual> for f64 {
+    type Output = Dual;
+
+    fn add(self, other: Dual) -> Dual {
+        other + self
+    }
+}
+
+impl Sub<Dual> for f64 {
+    type Output = Dual;
+
+    fn sub(self, other: Dual) -> Dual {
+        -other + self
+    }
+}
+
+impl Mul<Dual> for f64 {
+    type Output = Dual;
+
+    fn mul(self, other: Dual) -> Dual {
+        other * self
+    }
+}
+
+impl Div<Dual> for f64 {
+    type Output = Dual;
+
+    fn div(self, other: Dual) -> Dual {
+        Dual::constant(self) / other
+    }
+}
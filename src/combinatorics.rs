@@ -0,0 +1,686 @@
+/// Rearranges `items` into the next permutation in lexicographic order.
+///
+/// This is the classic algorithm used by C++'s `std::next_permutation`: find the longest
+/// non-increasing suffix, swap the element before it with the smallest larger element in the
+/// suffix, and reverse the suffix. Repeatedly calling it on a sorted slice visits every distinct
+/// arrangement exactly once, even when the slice contains duplicates.
+///
+/// # Arguments
+///
+/// * `items` - The slice to rearrange in place.
+///
+/// # Returns
+///
+/// * `true` if the slice was advanced to the next permutation, or `false` if it was already the
+///   last (non-increasing) permutation, in which case it is reset to the first (sorted) one.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::next_permutation;
+///
+/// let mut items = [1, 2, 3];
+/// assert!(next_permutation(&mut items));
+/// assert_eq!(items, [1, 3, 2]);
+///
+/// let mut last = [3, 2, 1];
+/// assert!(!next_permutation(&mut last));
+/// assert_eq!(last, [1, 2, 3]);
+///
+/// // Duplicates are visited only once: "aab", "aba", "baa".
+/// let mut letters = ['a', 'a', 'b'];
+/// let mut count = 1;
+/// while next_permutation(&mut letters) {
+///     count += 1;
+/// }
+/// assert_eq!(count, 3);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n)` time in the worst case and `O(1)` amortized over a full enumeration.
+pub fn next_permutation<T: Ord>(items: &mut [T]) -> bool {
+    let Some(pivot) = items.windows(2).rposition(|pair| pair[0] < pair[1]) else {
+        items.reverse();
+        return false;
+    };
+    let successor = items.iter().rposition(|item| *item > items[pivot]).expect("The suffix has a larger element.");
+    items.swap(pivot, successor);
+    items[pivot + 1..].reverse();
+    true
+}
+
+/// Returns a lazy iterator over all permutations of `items`, in lexicographic order of position.
+///
+/// The first permutation is `items` in its original order, and permutations are ordered as if the
+/// items were ranked by their position in the slice. Every one of the `n!` orderings of the
+/// positions is produced, so equal items yield repeated permutations; sort the items and use
+/// [`next_permutation`] directly to visit only distinct arrangements.
+///
+/// # Arguments
+///
+/// * `items` - The items to permute. They are cloned into each permutation.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::permutations;
+///
+/// let all: Vec<Vec<char>> = permutations(&['a', 'b', 'c']).collect();
+/// assert_eq!(all.len(), 6);
+/// assert_eq!(all[0], ['a', 'b', 'c']);
+/// assert_eq!(all[1], ['a', 'c', 'b']);
+/// assert_eq!(all[5], ['c', 'b', 'a']);
+///
+/// // The empty slice has exactly one permutation.
+/// assert_eq!(permutations::<u8>(&[]).count(), 1);
+/// ```
+///
+/// # Performance
+///
+/// Each step costs `O(n)` to clone the permutation. Nothing is generated ahead of time, so
+/// searches can stop early without paying for all `n!` permutations.
+pub fn permutations<T: Clone>(items: &[T]) -> Permutations<T> {
+    Permutations { items: items.to_vec(), indices: (0..items.len()).collect(), done: false }
+}
+
+/// A lazy iterator over the permutations of a collection, created by [`permutations`].
+#[derive(Debug, Clone)]
+pub struct Permutations<T> {
+    items: Vec<T>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Permutations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let permutation = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+        self.done = !next_permutation(&mut self.indices);
+        Some(permutation)
+    }
+}
+
+/// Computes the binomial coefficient `C(n, k)`, the number of ways to choose `k` items out of `n`.
+///
+/// The coefficient is built up with the multiplicative formula `C(n, i) = C(n, i − 1)·(n − i + 1)/i`,
+/// which stays exact because every intermediate value is itself a binomial coefficient.
+///
+/// # Arguments
+///
+/// * `n` - The number of items to choose from.
+/// * `k` - The number of items to choose.
+///
+/// # Returns
+///
+/// * `Some(C(n, k))`, which is `0` when `k > n`, or `None` if the result does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::binomial;
+///
+/// assert_eq!(binomial(5, 2), Some(10));
+/// assert_eq!(binomial(52, 5), Some(2_598_960));
+/// assert_eq!(binomial(3, 4), Some(0));
+/// assert_eq!(binomial(100, 50), None);
+/// ```
+pub fn binomial(n: u64, k: u64) -> Option<u64> {
+    if k > n {
+        return Some(0);
+    }
+    let k = k.min(n - k);
+    let mut result: u128 = 1;
+    for i in 1..=k as u128 {
+        result = result * (n as u128 - i + 1) / i;
+        if result > u64::MAX as u128 {
+            return None;
+        }
+    }
+    Some(result as u64)
+}
+
+/// Returns a lazy iterator over all `k`-element subsets of `items`, in lexicographic order of
+/// position.
+///
+/// Each combination keeps the items in their original relative order. There are
+/// [`binomial`]`(n, k)` combinations in total.
+///
+/// # Arguments
+///
+/// * `items` - The items to choose from. They are cloned into each combination.
+/// * `k` - The size of each combination. If `k` exceeds the number of items, the iterator is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::combinations;
+///
+/// let pairs: Vec<Vec<i32>> = combinations(&[1, 2, 3, 4], 2).collect();
+/// assert_eq!(pairs, [[1, 2], [1, 3], [1, 4], [2, 3], [2, 4], [3, 4]]);
+///
+/// assert_eq!(combinations(&[1, 2, 3], 0).count(), 1); // The empty subset.
+/// assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+/// ```
+///
+/// # Performance
+///
+/// Each step costs `O(k)`. Nothing is generated ahead of time.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> Combinations<T> {
+    Combinations { items: items.to_vec(), indices: (0..k).collect(), done: k > items.len() }
+}
+
+/// A lazy iterator over the fixed-size subsets of a collection, created by [`combinations`].
+#[derive(Debug, Clone)]
+pub struct Combinations<T> {
+    items: Vec<T>,
+    indices: Vec<usize>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Combinations<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let combination = self.indices.iter().map(|&i| self.items[i].clone()).collect();
+
+        // Advance the rightmost index that can still move, then pack the rest behind it.
+        let (n, k) = (self.items.len(), self.indices.len());
+        match (0..k).rev().find(|&i| self.indices[i] < n - k + i) {
+            Some(i) => {
+                self.indices[i] += 1;
+                for j in i + 1..k {
+                    self.indices[j] = self.indices[j - 1] + 1;
+                }
+            }
+            None => self.done = true,
+        }
+        Some(combination)
+    }
+}
+
+/// Returns a lazy iterator over all subsets of `items`.
+///
+/// Subsets are produced in binary counting order: subset number `m` contains item `j` exactly when
+/// bit `j` of `m` is set, so the first subset is empty and the last contains every item. Items in
+/// each subset keep their original relative order. For up to 64 items the counter is a single
+/// `u64` bitmask; larger inputs fall back to a growable bit vector (though enumerating `2^65`
+/// subsets is rarely practical, the iterator can still be used to inspect the first few).
+///
+/// # Arguments
+///
+/// * `items` - The items to build subsets from. They are cloned into each subset.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::power_set;
+///
+/// let subsets: Vec<Vec<char>> = power_set(&['a', 'b', 'c']).collect();
+/// assert_eq!(subsets.len(), 8);
+/// assert_eq!(subsets[0], Vec::<char>::new());
+/// assert_eq!(subsets[3], ['a', 'b']);
+/// assert_eq!(subsets[7], ['a', 'b', 'c']);
+///
+/// let large: Vec<u32> = (0..100).collect();
+/// assert_eq!(power_set(&large).nth(5), Some(vec![0, 2]));
+/// ```
+///
+/// # Performance
+///
+/// Each step costs `O(n)`. Nothing is generated ahead of time.
+pub fn power_set<T: Clone>(items: &[T]) -> PowerSet<T> {
+    let counter = if items.len() <= 64 {
+        let last = if items.len() == 64 { u64::MAX } else { (1u64 << items.len()) - 1 };
+        SubsetCounter::Mask { next: 0, last }
+    } else {
+        SubsetCounter::Bits(vec![false; items.len()])
+    };
+    PowerSet { items: items.to_vec(), counter, done: false }
+}
+
+/// A lazy iterator over the subsets of a collection, created by [`power_set`].
+#[derive(Debug, Clone)]
+pub struct PowerSet<T> {
+    items: Vec<T>,
+    counter: SubsetCounter,
+    done: bool,
+}
+
+/// The membership counter of a [`PowerSet`].
+#[derive(Debug, Clone)]
+enum SubsetCounter {
+    /// A bitmask, used for up to 64 items.
+    Mask { next: u64, last: u64 },
+    /// One flag per item, least significant first, used for more than 64 items.
+    Bits(Vec<bool>),
+}
+
+impl<T: Clone> Iterator for PowerSet<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        if self.done {
+            return None;
+        }
+        let subset;
+        match &mut self.counter {
+            SubsetCounter::Mask { next, last } => {
+                let mask = *next;
+                subset = self.items.iter().enumerate().filter(|(j, _)| mask >> j & 1 == 1).map(|(_, item)| item.clone()).collect();
+                if mask == *last {
+                    self.done = true;
+                } else {
+                    *next += 1;
+                }
+            }
+            SubsetCounter::Bits(bits) => {
+                subset = self.items.iter().zip(bits.iter()).filter(|(_, &bit)| bit).map(|(item, _)| item.clone()).collect();
+                // Binary increment: clear trailing ones, then set the first zero.
+                match bits.iter().position(|&bit| !bit) {
+                    Some(first_zero) => {
+                        bits[..first_zero].fill(false);
+                        bits[first_zero] = true;
+                    }
+                    None => self.done = true,
+                }
+            }
+        }
+        Some(subset)
+    }
+}
+
+/// Counts the derangements of `n` items: the permutations in which no item stays in its place.
+///
+/// The count satisfies `D(n) = (n − 1)·(D(n − 1) + D(n − 2))` with `D(0) = 1` and `D(1) = 0`, and
+/// `D(n)/n!` approaches `1/e` quickly, which is why the chance that nobody in a gift exchange
+/// draws their own name is about 37% regardless of group size.
+///
+/// # Arguments
+///
+/// * `n` - The number of items.
+///
+/// # Returns
+///
+/// * `Some(D(n))`, or `None` if the count does not fit in a `u64` (from `n = 21` onwards).
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::derangements_count;
+///
+/// assert_eq!(derangements_count(0), Some(1));
+/// assert_eq!(derangements_count(3), Some(2));
+/// assert_eq!(derangements_count(10), Some(1_334_961));
+/// assert_eq!(derangements_count(21), None);
+/// ```
+pub fn derangements_count(n: u64) -> Option<u64> {
+    if n == 0 {
+        return Some(1);
+    }
+    let (mut previous, mut current): (u64, u64) = (1, 0);
+    for i in 2..=n {
+        let next = (i - 1).checked_mul(previous.checked_add(current)?)?;
+        (previous, current) = (current, next);
+    }
+    Some(current)
+}
+
+/// Returns a lazy iterator over the derangements of `items`: the permutations in which no item
+/// remains at its original position.
+///
+/// Derangements are produced in the same lexicographic order of position as [`permutations`].
+/// Positions are compared, not values, so equal items at different positions may still trade
+/// places.
+///
+/// # Arguments
+///
+/// * `items` - The items to rearrange. They are cloned into each derangement.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::{derangements, derangements_count};
+///
+/// let all: Vec<Vec<char>> = derangements(&['a', 'b', 'c']).collect();
+/// assert_eq!(all, [['b', 'c', 'a'], ['c', 'a', 'b']]);
+///
+/// assert_eq!(derangements(&[0; 6]).count() as u64, derangements_count(6).unwrap());
+/// ```
+///
+/// # Performance
+///
+/// The iterator walks all permutations and skips those with a fixed point. Because roughly
+/// `1/e` of all permutations are derangements, this costs under three permutation steps per
+/// derangement on average.
+pub fn derangements<T: Clone>(items: &[T]) -> Derangements<T> {
+    Derangements { permutations: permutations(&(0..items.len()).collect::<Vec<_>>()), items: items.to_vec() }
+}
+
+/// A lazy iterator over the derangements of a collection, created by [`derangements`].
+#[derive(Debug, Clone)]
+pub struct Derangements<T> {
+    items: Vec<T>,
+    permutations: Permutations<usize>,
+}
+
+impl<T: Clone> Iterator for Derangements<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Vec<T>> {
+        let indices = self.permutations.find(|p| p.iter().enumerate().all(|(i, &j)| i != j))?;
+        Some(indices.into_iter().map(|i| self.items[i].clone()).collect())
+    }
+}
+
+/// Converts a binary number to its reflected binary Gray code.
+///
+/// Consecutive integers map to Gray codes that differ in exactly one bit, which is what makes the
+/// code useful for rotary encoders and for enumerating subsets by single insertions or removals.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::to_gray;
+///
+/// assert_eq!(to_gray(0b0110), 0b0101);
+/// assert_eq!((to_gray(7) ^ to_gray(8)).count_ones(), 1);
+/// ```
+pub const fn to_gray(n: u64) -> u64 {
+    n ^ (n >> 1)
+}
+
+/// Converts a reflected binary Gray code back to the binary number it encodes.
+///
+/// This is the inverse of [`to_gray`]: each bit of the result is the XOR of all bits of the code
+/// at or above that position.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::{from_gray, to_gray};
+///
+/// assert_eq!(from_gray(0b0101), 0b0110);
+/// assert!((0..1000).all(|n| from_gray(to_gray(n)) == n));
+/// ```
+pub const fn from_gray(code: u64) -> u64 {
+    let mut n = code;
+    let mut shift = 1;
+    while shift < 64 {
+        n ^= n >> shift;
+        shift <<= 1;
+    }
+    n
+}
+
+/// Returns an iterator over the `bits`-bit reflected binary Gray code sequence.
+///
+/// The sequence contains all `2^bits` values of `bits` bits, starting at `0`, and each value
+/// differs from the previous one in exactly one bit. The last value also differs from the first
+/// in one bit, so the sequence is cyclic.
+///
+/// # Arguments
+///
+/// * `bits` - The number of bits, at most 64. Zero bits yields the single value `0`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::gray_code;
+///
+/// let codes: Vec<u64> = gray_code(3).collect();
+/// assert_eq!(codes, [0b000, 0b001, 0b011, 0b010, 0b110, 0b111, 0b101, 0b100]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `bits` is greater than 64.
+pub fn gray_code(bits: u32) -> GrayCode {
+    assert!(bits <= 64, "At most 64 bits are supported.");
+    let last = if bits == 64 { u64::MAX } else { (1u64 << bits) - 1 };
+    GrayCode { next: 0, last, done: false }
+}
+
+/// An iterator over a Gray code sequence, created by [`gray_code`].
+#[derive(Debug, Clone)]
+pub struct GrayCode {
+    next: u64,
+    last: u64,
+    done: bool,
+}
+
+impl Iterator for GrayCode {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.done {
+            return None;
+        }
+        let n = self.next;
+        if n == self.last {
+            self.done = true;
+        } else {
+            self.next += 1;
+        }
+        Some(to_gray(n))
+    }
+}
+
+/// Returns a lazy iterator over the partitions of the integer `n`.
+///
+/// A partition writes `n` as a sum of positive parts, ignoring order. Each partition is yielded
+/// with its parts in non-increasing order, and partitions are produced in reverse lexicographic
+/// order, starting with `[n]` and ending with `[1, 1, ..., 1]`.
+///
+/// # Arguments
+///
+/// * `n` - The number to partition. Zero has exactly one partition, the empty one.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::integer_partitions;
+///
+/// let all: Vec<Vec<u64>> = integer_partitions(4).collect();
+/// assert_eq!(all, vec![vec![4], vec![3, 1], vec![2, 2], vec![2, 1, 1], vec![1, 1, 1, 1]]);
+/// assert_eq!(integer_partitions(20).count(), 627);
+/// ```
+pub fn integer_partitions(n: u64) -> IntegerPartitions {
+    integer_partitions_with_max_part(n, n)
+}
+
+/// Returns a lazy iterator over the partitions of `n` whose parts are all at most `max_part`.
+///
+/// This is [`integer_partitions`] restricted to bounded parts; for example, the partitions of `n`
+/// with parts at most 2 are the ways to pay `n` with coins of value 1 and 2.
+///
+/// # Arguments
+///
+/// * `n` - The number to partition.
+/// * `max_part` - The largest allowed part. If it is zero and `n` is positive, there are no
+///   partitions.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::integer_partitions_with_max_part;
+///
+/// let all: Vec<Vec<u64>> = integer_partitions_with_max_part(5, 2).collect();
+/// assert_eq!(all, vec![vec![2, 2, 1], vec![2, 1, 1, 1], vec![1, 1, 1, 1, 1]]);
+/// ```
+pub fn integer_partitions_with_max_part(n: u64, max_part: u64) -> IntegerPartitions {
+    let mut parts = Vec::new();
+    let done = n > 0 && max_part == 0;
+    if !done {
+        fill_parts(&mut parts, n, max_part.min(n));
+    }
+    IntegerPartitions { parts, done }
+}
+
+/// A lazy iterator over integer partitions, created by [`integer_partitions`] or
+/// [`integer_partitions_with_max_part`].
+#[derive(Debug, Clone)]
+pub struct IntegerPartitions {
+    parts: Vec<u64>,
+    done: bool,
+}
+
+impl Iterator for IntegerPartitions {
+    type Item = Vec<u64>;
+
+    fn next(&mut self) -> Option<Vec<u64>> {
+        if self.done {
+            return None;
+        }
+        let partition = self.parts.clone();
+        // Decrease the rightmost part larger than 1 and redistribute everything after it.
+        match self.parts.iter().rposition(|&part| part > 1) {
+            Some(i) => {
+                let part = self.parts[i] - 1;
+                let remainder = self.parts[i + 1..].iter().sum::<u64>() + 1;
+                self.parts.truncate(i);
+                self.parts.push(part);
+                fill_parts(&mut self.parts, remainder, part);
+            }
+            None => self.done = true,
+        }
+        Some(partition)
+    }
+}
+
+/// Appends `amount` to `parts` as greedily as possible using parts of at most `largest`.
+fn fill_parts(parts: &mut Vec<u64>, amount: u64, largest: u64) {
+    if amount == 0 {
+        return;
+    }
+    let (count, rest) = (amount / largest, amount % largest);
+    parts.extend(std::iter::repeat_n(largest, count as usize));
+    if rest > 0 {
+        parts.push(rest);
+    }
+}
+
+/// Returns a lazy iterator over the partitions of `items` into non-empty blocks.
+///
+/// Every way of splitting the items into disjoint groups is produced exactly once; the number of
+/// such set partitions is the Bell number `B(n)` (1, 1, 2, 5, 15, 52, ...), which grows very
+/// quickly, so this is intended for small collections. Blocks are ordered by their first item,
+/// and items inside a block keep their original relative order.
+///
+/// # Arguments
+///
+/// * `items` - The items to partition. They are cloned into each partition.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::set_partitions;
+///
+/// let all: Vec<Vec<Vec<char>>> = set_partitions(&['a', 'b', 'c']).collect();
+/// assert_eq!(all.len(), 5);
+/// assert_eq!(all[0], vec![vec!['a', 'b', 'c']]);
+/// assert_eq!(all[4], vec![vec!['a'], vec!['b'], vec!['c']]);
+///
+/// assert_eq!(set_partitions(&[0; 6]).count(), 203);
+/// ```
+///
+/// # Performance
+///
+/// Partitions are enumerated as restricted growth strings, where item `i` is labelled with the
+/// index of its block; each step costs `O(n)`.
+pub fn set_partitions<T: Clone>(items: &[T]) -> SetPartitions<T> {
+    set_partitions_with_max_block(items, items.len().max(1))
+}
+
+/// Returns a lazy iterator over the partitions of `items` into non-empty blocks of at most
+/// `max_block` items each.
+///
+/// This is [`set_partitions`] restricted to bounded block sizes; for example, `max_block = 2`
+/// enumerates the ways to pair people up, allowing some to remain alone.
+///
+/// # Arguments
+///
+/// * `items` - The items to partition.
+/// * `max_block` - The largest allowed block size. If it is zero and `items` is not empty, there
+///   are no partitions.
+///
+/// # Examples
+///
+/// ```
+/// use hell::combinatorics::set_partitions_with_max_block;
+///
+/// let pairings: Vec<Vec<Vec<u32>>> = set_partitions_with_max_block(&[1, 2, 3], 2).collect();
+/// assert_eq!(
+///     pairings,
+///     vec![
+///         vec![vec![1, 2], vec![3]],
+///         vec![vec![1, 3], vec![2]],
+///         vec![vec![1], vec![2, 3]],
+///         vec![vec![1], vec![2], vec![3]],
+///     ]
+/// );
+/// ```
+///
+/// # Performance
+///
+/// Partitions that violate the bound are generated and skipped, so the cost is that of
+/// enumerating all set partitions.
+pub fn set_partitions_with_max_block<T: Clone>(items: &[T], max_block: usize) -> SetPartitions<T> {
+    SetPartitions { items: items.to_vec(), labels: vec![0; items.len()], max_block, done: false }
+}
+
+/// A lazy iterator over set partitions, created by [`set_partitions`] or
+/// [`set_partitions_with_max_block`].
+#[derive(Debug, Clone)]
+pub struct SetPartitions<T> {
+    items: Vec<T>,
+    labels: Vec<usize>,
+    max_block: usize,
+    done: bool,
+}
+
+impl<T: Clone> SetPartitions<T> {
+    /// Advances the restricted growth string to its lexicographic successor.
+    fn advance(&mut self) {
+        // labels[i] may grow while it does not exceed 1 + max(labels[..i]).
+        let mut prefix_max = Vec::with_capacity(self.labels.len());
+        let mut max = 0;
+        for &label in &self.labels {
+            prefix_max.push(max);
+            max = max.max(label);
+        }
+        match (1..self.labels.len()).rev().find(|&i| self.labels[i] <= prefix_max[i]) {
+            Some(i) => {
+                self.labels[i] += 1;
+                self.labels[i + 1..].fill(0);
+            }
+            None => self.done = true,
+        }
+    }
+}
+
+impl<T: Clone> Iterator for SetPartitions<T> {
+    type Item = Vec<Vec<T>>;
+
+    fn next(&mut self) -> Option<Vec<Vec<T>>> {
+        while !self.done {
+            let block_count = self.labels.iter().max().map_or(0, |&max| max + 1);
+            let mut blocks: Vec<Vec<T>> = vec![Vec::new(); block_count];
+            for (item, &label) in self.items.iter().zip(&self.labels) {
+                blocks[label].push(item.clone());
+            }
+            self.advance();
+            if blocks.iter().all(|block| block.len() <= self.max_block) {
+                return Some(blocks);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,327 @@
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// A complex number `re + im·i` with `f64` parts.
+///
+/// `Complex` supports the usual arithmetic operators (also mixed with `f64`), conversion to and
+/// from polar form, and the exponential, logarithm, and power functions, so that computations
+/// based on Euler's formula `e^(iθ) = cos θ + i sin θ` can be written directly.
+///
+/// # Examples
+///
+/// ```
+/// use hell::complex::Complex;
+///
+/// let a = Complex::new(1.0, 2.0);
+/// let b = Complex::new(3.0, -1.0);
+///
+/// assert_eq!(a + b, Complex::new(4.0, 1.0));
+/// assert_eq!(a * b, Complex::new(5.0, 5.0));
+/// assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+/// assert_eq!(Complex::I * Complex::I, Complex::new(-1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Complex {
+    pub re: f64,
+    pub im: f64,
+}
+
+impl Complex {
+    /// The additive identity `0 + 0i`.
+    pub const ZERO: Complex = Complex { re: 0.0, im: 0.0 };
+    /// The multiplicative identity `1 + 0i`.
+    pub const ONE: Complex = Complex { re: 1.0, im: 0.0 };
+    /// The imaginary unit `i`.
+    pub const I: Complex = Complex { re: 0.0, im: 1.0 };
+
+    /// Creates a complex number from its real and imaginary parts.
+    pub const fn new(re: f64, im: f64) -> Self {
+        Complex { re, im }
+    }
+
+    /// Returns the complex conjugate `re - im·i`.
+    pub fn conj(self) -> Complex {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// Returns the modulus (absolute value) `|z| = sqrt(re² + im²)`.
+    pub fn abs(self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// Returns the squared modulus `re² + im²`, avoiding a square root.
+    pub fn norm_sqr(self) -> f64 {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// Returns the argument (phase angle) of the number in radians, in the range `(-π, π]`.
+    pub fn arg(self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// Creates a complex number from its polar form `r·e^(iθ)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The modulus.
+    /// * `theta` - The argument in radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// let z = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    /// assert!((z - Complex::new(0.0, 2.0)).abs() < 1e-12);
+    /// ```
+    pub fn from_polar(r: f64, theta: f64) -> Complex {
+        let (sin, cos) = theta.sin_cos();
+        Complex::new(r * cos, r * sin)
+    }
+
+    /// Converts the number to polar form.
+    ///
+    /// # Returns
+    ///
+    /// * A tuple `(r, theta)` with the modulus and the argument in radians (range `(-π, π]`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// let (r, theta) = Complex::new(-1.0, 0.0).to_polar();
+    /// assert_eq!(r, 1.0);
+    /// assert_eq!(theta, std::f64::consts::PI);
+    /// ```
+    pub fn to_polar(self) -> (f64, f64) {
+        (self.abs(), self.arg())
+    }
+
+    /// Computes the complex exponential `e^z = e^re · (cos im + i sin im)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// // Euler's identity: e^(iπ) + 1 = 0.
+    /// let z = Complex::new(0.0, std::f64::consts::PI).exp() + 1.0;
+    /// assert!(z.abs() < 1e-12);
+    /// ```
+    pub fn exp(self) -> Complex {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Computes the principal natural logarithm `ln|z| + i·arg(z)`.
+    ///
+    /// The imaginary part of the result lies in `(-π, π]`. The logarithm of zero has a real part
+    /// of negative infinity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// let z = Complex::new(-1.0, 0.0).ln();
+    /// assert_eq!(z, Complex::new(0.0, std::f64::consts::PI));
+    /// ```
+    pub fn ln(self) -> Complex {
+        Complex::new(self.abs().ln(), self.arg())
+    }
+
+    /// Raises the number to a real power using the principal branch, `z^p = e^(p·ln z)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// let z = Complex::new(-4.0, 0.0).powf(0.5);
+    /// assert!((z - Complex::new(0.0, 2.0)).abs() < 1e-12);
+    /// ```
+    pub fn powf(self, p: f64) -> Complex {
+        if self == Complex::ZERO {
+            return if p == 0.0 { Complex::ONE } else { Complex::ZERO };
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(p), theta * p)
+    }
+
+    /// Raises the number to a complex power using the principal branch, `z^w = e^(w·ln z)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// // i^i is a real number: e^(-π/2).
+    /// let z = Complex::I.powc(Complex::I);
+    /// assert!((z.re - (-std::f64::consts::FRAC_PI_2).exp()).abs() < 1e-12);
+    /// assert!(z.im.abs() < 1e-12);
+    /// ```
+    pub fn powc(self, w: Complex) -> Complex {
+        if self == Complex::ZERO {
+            return if w == Complex::ZERO { Complex::ONE } else { Complex::ZERO };
+        }
+        (w * self.ln()).exp()
+    }
+
+    /// Returns all `n` distinct `n`-th roots of the number.
+    ///
+    /// The roots are `r^(1/n) · e^(i(θ + 2πk)/n)` for `k = 0, 1, ..., n - 1`, where `(r, θ)` is
+    /// the polar form of `self`. They are returned in order of increasing `k`, so the first root
+    /// is the principal root.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The degree of the root. `n = 0` yields an empty vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::complex::Complex;
+    ///
+    /// // The cube roots of -8 are -2 and 1 ± i√3.
+    /// let roots = Complex::new(-8.0, 0.0).nth_roots(3);
+    /// assert_eq!(roots.len(), 3);
+    /// for root in &roots {
+    ///     let cube = *root * *root * *root;
+    ///     assert!((cube - Complex::new(-8.0, 0.0)).abs() < 1e-12);
+    /// }
+    /// assert!((roots[0] - Complex::new(1.0, 3f64.sqrt())).abs() < 1e-12);
+    /// ```
+    pub fn nth_roots(self, n: usize) -> Vec<Complex> {
+        if n == 0 {
+            return Vec::new();
+        }
+        let (r, theta) = self.to_polar();
+        let modulus = r.powf(1.0 / n as f64);
+        (0..n)
+            .map(|k| {
+                let angle = (theta + 2.0 * std::f64::consts::PI * k as f64) / n as f64;
+                Complex::from_polar(modulus, angle)
+            })
+            .collect()
+    }
+}
+
+/// Returns the `n` complex `n`-th roots of unity, `e^(2πik/n)` for `k = 0, 1, ..., n - 1`.
+///
+/// These are the solutions of `z^n = 1`. They lie evenly spaced on the unit circle starting at
+/// `1`, and are the "twiddle factors" used by the fast Fourier transform.
+///
+/// # Arguments
+///
+/// * `n` - The number of roots. `n = 0` yields an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use hell::complex::{roots_of_unity, Complex};
+///
+/// let roots = roots_of_unity(4);
+/// let expected = [Complex::ONE, Complex::I, Complex::new(-1.0, 0.0), Complex::new(0.0, -1.0)];
+/// for (root, want) in roots.iter().zip(expected) {
+///     assert!((*root - want).abs() < 1e-12);
+/// }
+/// ```
+pub fn roots_of_unity(n: usize) -> Vec<Complex> {
+    (0..n)
+        .map(|k| Complex::from_polar(1.0, 2.0 * std::f64::consts::PI * k as f64 / n as f64))
+        .collect()
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Complex::new(re, 0.0)
+    }
+}
+
+impl fmt::Display for Complex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.im < 0.0 {
+            write!(f, "{} - {}i", self.re, -self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl Add for Complex {
+    type Output = Complex;
+
+    fn add(self, other: Complex) -> Complex {
+        Complex::new(self.re + other.re, self.im + other.im)
+    }
+}
+
+impl Sub for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: Complex) -> Complex {
+        Complex::new(self.re - other.re, self.im - other.im)
+    }
+}
+
+impl Mul for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: Complex) -> Complex {
+        Complex::new(
+            self.re * other.re - self.im * other.im,
+            self.re * other.im + self.im * other.re,
+        )
+    }
+}
+
+impl Div for Complex {
+    type Output = Complex;
+
+    fn div(self, other: Complex) -> Complex {
+        let denom = other.norm_sqr();
+        let num = self * other.conj();
+        Complex::new(num.re / denom, num.im / denom)
+    }
+}
+
+impl Neg for Complex {
+    type Output = Complex;
+
+    fn neg(self) -> Complex {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+impl Add<f64> for Complex {
+    type Output = Complex;
+
+    fn add(self, other: f64) -> Complex {
+        Complex::new(self.re + other, self.im)
+    }
+}
+
+impl Sub<f64> for Complex {
+    type Output = Complex;
+
+    fn sub(self, other: f64) -> Complex {
+        Complex::new(self.re - other, self.im)
+    }
+}
+
+impl Mul<f64> for Complex {
+    type Output = Complex;
+
+    fn mul(self, other: f64) -> Complex {
+        Complex::new(self.re * other, self.im * other)
+    }
+}
+
+impl Div<f64> for Complex {
+    type Output = Complex;
+
+    fn div(self, other: f64) -> Complex {
+        Complex::new(self.re / other, self.im / other)
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt;
+
+/// The error type shared by the fallible operations of the crate.
+///
+/// Each variant identifies a class of failure and carries a short human-readable description,
+/// so callers can either `match` on the cause or simply display the message. Because every
+/// module reports failures with this type, errors from different parts of the crate can be
+/// propagated together with the `?` operator.
+///
+/// # Examples
+///
+/// ```
+/// use hell::error::HellError;
+/// use hell::Matrix;
+///
+/// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+/// let b = Matrix::identity(3);
+///
+/// match a.add(&b) {
+///     Err(HellError::DimensionMismatch(message)) => println!("cannot add: {}", message),
+///     Err(other) => panic!("unexpected error: {}", other),
+///     Ok(_) => unreachable!(),
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HellError {
+    /// The shapes of the operands are incompatible (for example adding a 2x2 and a 3x3 matrix).
+    DimensionMismatch(&'static str),
+    /// An argument lies outside the domain where the operation is defined.
+    DomainError(&'static str),
+    /// The result does not fit in the output type.
+    Overflow(&'static str),
+    /// An iterative method did not reach the requested accuracy, or the problem has no unique
+    /// solution that a method could converge to.
+    ConvergenceFailure(&'static str),
+    /// Text input could not be parsed.
+    ParseError(&'static str),
+}
+
+impl HellError {
+    /// Returns the description carried by the error.
+    pub fn message(&self) -> &'static str {
+        match *self {
+            HellError::DimensionMismatch(message)
+            | HellError::DomainError(message)
+            | HellError::Overflow(message)
+            | HellError::ConvergenceFailure(message)
+            | HellError::ParseError(message) => message,
+        }
+    }
+}
+
+impl fmt::Display for HellError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self {
+            HellError::DimensionMismatch(_) => "dimension mismatch",
+            HellError::DomainError(_) => "domain error",
+            HellError::Overflow(_) => "overflow",
+            HellError::ConvergenceFailure(_) => "convergence failure",
+            HellError::ParseError(_) => "parse error",
+        };
+        write!(f, "{}: {}", kind, self.message())
+    }
+}
+
+impl std::error::Error for HellError {}
@@ -0,0 +1,203 @@
+use crate::complex::Complex;
+use std::f64::consts::PI;
+
+/// Inputs where the shorter sequence has at most this many elements are convolved directly;
+/// longer ones go through the FFT.
+///
+/// Direct convolution costs `O(n·m)` and the FFT route `O((n + m) log(n + m))` with a larger
+/// constant factor, so the direct method wins for short kernels.
+pub const CONVOLUTION_FFT_THRESHOLD: usize = 32;
+
+/// Computes the discrete Fourier transform of `input` with the radix-2 Cooley–Tukey algorithm.
+///
+/// The transform is `X[k] = Σ x[n]·e^(−2πi·kn/N)`, unnormalised, so that [`ifft`] divides by `N`.
+///
+/// # Arguments
+///
+/// * `input` - The samples. Their number must be a power of two (zero-pad shorter signals).
+///
+/// # Returns
+///
+/// * The `N` frequency-domain coefficients.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use hell::complex::Complex;
+/// use hell::fft::fft;
+///
+/// let signal: Vec<Complex> = [1.0, 1.0, 1.0, 1.0].iter().map(|&x| Complex::from(x)).collect();
+/// let spectrum = fft(&signal);
+/// assert!((spectrum[0] - Complex::from(4.0)).abs() < 1e-12);
+/// assert!(spectrum[1..].iter().all(|c| c.abs() < 1e-12));
+///
+/// // A single sample is its own transform.
+/// assert_eq!(fft(&[Complex::from(2.0)]), vec![Complex::from(2.0)]);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(N log N)` time.
+pub fn fft(input: &[Complex]) -> Vec<Complex> {
+    transform(input, false)
+}
+
+/// Computes the inverse discrete Fourier transform of `input`.
+///
+/// This undoes [`fft`]: `ifft(&fft(x))` returns `x` up to rounding error.
+///
+/// # Arguments
+///
+/// * `input` - The frequency-domain coefficients. Their number must be a power of two.
+///
+/// # Panics
+///
+/// Panics if the length of `input` is not a power of two.
+///
+/// # Examples
+///
+/// ```
+/// use hell::complex::Complex;
+/// use hell::fft::{fft, ifft};
+///
+/// let signal: Vec<Complex> = (0..8).map(|i| Complex::new(i as f64, 0.0)).collect();
+/// let roundtrip = ifft(&fft(&signal));
+/// assert!(signal.iter().zip(&roundtrip).all(|(a, b)| (*a - *b).abs() < 1e-12));
+/// ```
+pub fn ifft(input: &[Complex]) -> Vec<Complex> {
+    let n = input.len() as f64;
+    transform(input, true).into_iter().map(|c| c / n).collect()
+}
+
+/// Computes the linear convolution of `a` and `b`.
+///
+/// The result has `a.len() + b.len() − 1` elements, with `result[k] = Σ a[i]·b[k − i]`. This is
+/// the same as multiplying polynomials whose coefficients are `a` and `b`. Short inputs are
+/// convolved directly and long ones through the FFT (see [`CONVOLUTION_FFT_THRESHOLD`]).
+///
+/// # Arguments
+///
+/// * `a` - The first sequence.
+/// * `b` - The second sequence.
+///
+/// # Returns
+///
+/// * The convolution, or an empty vector if either input is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hell::fft::convolve;
+///
+/// assert_eq!(convolve(&[1.0, 2.0, 3.0], &[0.0, 1.0, 0.5]), vec![0.0, 1.0, 2.5, 4.0, 1.5]);
+///
+/// // A long moving sum goes through the FFT and agrees to rounding error.
+/// let signal: Vec<f64> = (0..1000).map(|i| (i % 7) as f64).collect();
+/// let smoothed = convolve(&signal, &[1.0; 50]);
+/// assert_eq!(smoothed.len(), 1049);
+/// assert!((smoothed[49] - signal[..50].iter().sum::<f64>()).abs() < 1e-9);
+/// ```
+pub fn convolve(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.len().min(b.len()) <= CONVOLUTION_FFT_THRESHOLD {
+        convolve_direct(a, b)
+    } else {
+        convolve_fft(a, b)
+    }
+}
+
+/// Computes the linear convolution of `a` and `b` directly from the definition.
+///
+/// This gives the same result as [`convolve`] and is exact for integer-valued inputs, but takes
+/// `O(n·m)` time.
+///
+/// # Examples
+///
+/// ```
+/// use hell::fft::convolve_direct;
+///
+/// assert_eq!(convolve_direct(&[1.0, 1.0], &[1.0, 1.0]), vec![1.0, 2.0, 1.0]);
+/// ```
+pub fn convolve_direct(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let mut result = vec![0.0; a.len() + b.len() - 1];
+    for (i, &x) in a.iter().enumerate() {
+        for (value, &y) in result[i..].iter_mut().zip(b) {
+            *value += x * y;
+        }
+    }
+    result
+}
+
+/// Computes the linear convolution of `a` and `b` with the FFT.
+///
+/// Both inputs are zero-padded to a common power-of-two length, transformed, multiplied
+/// pointwise, and transformed back. This gives the same result as [`convolve`] up to rounding
+/// error, in `O((n + m) log(n + m))` time.
+///
+/// # Examples
+///
+/// ```
+/// use hell::fft::convolve_fft;
+///
+/// let result = convolve_fft(&[1.0, 1.0], &[1.0, 1.0]);
+/// assert!(result.iter().zip([1.0, 2.0, 1.0]).all(|(a, b)| (a - b).abs() < 1e-12));
+/// assert_eq!(convolve_fft(&[2.0], &[3.0]), vec![6.0]);
+/// ```
+pub fn convolve_fft(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
+    let len = a.len() + b.len() - 1;
+    let size = len.next_power_of_two();
+    let pad = |x: &[f64]| {
+        let mut padded: Vec<Complex> = x.iter().map(|&v| Complex::from(v)).collect();
+        padded.resize(size, Complex::ZERO);
+        fft(&padded)
+    };
+    let product: Vec<Complex> = pad(a).into_iter().zip(pad(b)).map(|(x, y)| x * y).collect();
+    ifft(&product).into_iter().take(len).map(|c| c.re).collect()
+}
+
+/// Iterative in-place radix-2 FFT on a copy of `input`. `inverse` flips the sign of the exponent
+/// but does not normalise.
+fn transform(input: &[Complex], inverse: bool) -> Vec<Complex> {
+    let n = input.len();
+    assert!(n.is_power_of_two(), "FFT length must be a power of two.");
+    let mut data = input.to_vec();
+    if n == 1 {
+        // A single sample is its own transform.
+        return data;
+    }
+
+    // Bit-reversal permutation.
+    let bits = n.trailing_zeros();
+    for i in 0..n {
+        let j = i.reverse_bits() >> (usize::BITS - bits) as usize;
+        if i < j {
+            data.swap(i, j);
+        }
+    }
+
+    let sign = if inverse { 1.0 } else { -1.0 };
+    let mut len = 2;
+    while len <= n {
+        let step = Complex::from_polar(1.0, sign * 2.0 * PI / len as f64);
+        for chunk in data.chunks_mut(len) {
+            let (lower, upper) = chunk.split_at_mut(len / 2);
+            let mut twiddle = Complex::ONE;
+            for (a, b) in lower.iter_mut().zip(upper.iter_mut()) {
+                let t = twiddle * *b;
+                (*a, *b) = (*a + t, *a - t);
+                twiddle = twiddle * step;
+            }
+        }
+        len *= 2;
+    }
+    data
+}
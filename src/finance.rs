@@ -0,0 +1,338 @@
+use crate::error::HellError;
+use crate::roots::bisection;
+
+/// Computes the future value of a principal under periodic compound interest.
+///
+/// The formula is `FV = P·(1 + r/n)^(n·t)`, where `r` is the nominal annual rate and `n` the number
+/// of compounding periods per year.
+///
+/// # Arguments
+///
+/// * `principal` - The initial amount `P`.
+/// * `annual_rate` - The nominal annual interest rate `r`, as a fraction (`0.05` for 5%).
+/// * `periods_per_year` - How often interest is compounded per year, `n` (12 for monthly).
+/// * `years` - The investment horizon `t` in years. May be fractional.
+///
+/// # Returns
+///
+/// * The value of the investment after `years` years.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::compound_interest;
+///
+/// // 1000 at 5% compounded monthly for 10 years.
+/// let value = compound_interest(1000.0, 0.05, 12, 10.0);
+/// assert!((value - 1647.01).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `periods_per_year` is zero.
+pub fn compound_interest(principal: f64, annual_rate: f64, periods_per_year: u32, years: f64) -> f64 {
+    assert!(periods_per_year > 0, "There must be at least one compounding period per year.");
+    let n = periods_per_year as f64;
+    principal * (1.0 + annual_rate / n).powf(n * years)
+}
+
+/// Computes the future value of a principal under continuous compounding, `FV = P·e^(r·t)`.
+///
+/// This is the limit of [`compound_interest`] as the number of periods per year grows without
+/// bound.
+///
+/// # Arguments
+///
+/// * `principal` - The initial amount `P`.
+/// * `annual_rate` - The annual interest rate `r`, as a fraction.
+/// * `years` - The investment horizon `t` in years.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::{compound_interest, continuous_compounding};
+///
+/// let continuous = continuous_compounding(1000.0, 0.05, 10.0);
+/// assert!((continuous - 1648.72).abs() < 0.01);
+/// assert!(continuous > compound_interest(1000.0, 0.05, 365, 10.0));
+/// ```
+pub fn continuous_compounding(principal: f64, annual_rate: f64, years: f64) -> f64 {
+    principal * (annual_rate * years).exp()
+}
+
+/// Computes the effective annual rate (EAR) of a nominal rate compounded `periods_per_year` times.
+///
+/// The EAR is the simple annual rate that produces the same growth, `(1 + r/n)^n − 1`, and is the
+/// right figure for comparing offers with different compounding schedules.
+///
+/// # Arguments
+///
+/// * `annual_rate` - The nominal annual interest rate, as a fraction.
+/// * `periods_per_year` - The number of compounding periods per year.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::effective_annual_rate;
+///
+/// // 12% compounded monthly is worth about 12.68% a year.
+/// assert!((effective_annual_rate(0.12, 12) - 0.126825).abs() < 1e-6);
+/// assert!((effective_annual_rate(0.05, 1) - 0.05).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `periods_per_year` is zero.
+pub fn effective_annual_rate(annual_rate: f64, periods_per_year: u32) -> f64 {
+    assert!(periods_per_year > 0, "There must be at least one compounding period per year.");
+    let n = periods_per_year as f64;
+    (1.0 + annual_rate / n).powf(n) - 1.0
+}
+
+/// Computes the future value of an ordinary annuity: `periods` equal payments made at the end of
+/// each period, each earning `rate` per period until the last payment.
+///
+/// The formula is `FV = PMT·((1 + r)^n − 1)/r`, which reduces to `PMT·n` when `r = 0`.
+///
+/// # Arguments
+///
+/// * `payment` - The payment made each period, `PMT`.
+/// * `rate` - The interest rate per period `r`, as a fraction (an annual rate of 6% paid monthly
+///   is `0.06 / 12`).
+/// * `periods` - The number of payments `n`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::future_value_annuity;
+///
+/// // Saving 200 a month for 5 years at 6% a year, compounded monthly.
+/// let saved = future_value_annuity(200.0, 0.06 / 12.0, 60);
+/// assert!((saved - 13_954.01).abs() < 0.01);
+/// assert_eq!(future_value_annuity(100.0, 0.0, 12), 1200.0);
+/// ```
+///
+/// # Notes
+///
+/// For an annuity due, where payments are made at the start of each period, multiply the result
+/// by `1 + rate`.
+pub fn future_value_annuity(payment: f64, rate: f64, periods: u32) -> f64 {
+    if rate == 0.0 {
+        return payment * periods as f64;
+    }
+    payment * ((1.0 + rate).powi(periods as i32) - 1.0) / rate
+}
+
+/// Computes the present value of an ordinary annuity: what `periods` equal end-of-period payments
+/// are worth today when discounted at `rate` per period.
+///
+/// The formula is `PV = PMT·(1 − (1 + r)^(−n))/r`, which reduces to `PMT·n` when `r = 0`. This is
+/// also the loan amount that a given payment can pay off.
+///
+/// # Arguments
+///
+/// * `payment` - The payment made each period, `PMT`.
+/// * `rate` - The discount rate per period `r`, as a fraction.
+/// * `periods` - The number of payments `n`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::present_value_annuity;
+///
+/// // 20 yearly payments of 1000, discounted at 5% a year.
+/// let value = present_value_annuity(1000.0, 0.05, 20);
+/// assert!((value - 12_462.21).abs() < 0.01);
+/// ```
+///
+/// # Notes
+///
+/// For an annuity due, where payments are made at the start of each period, multiply the result
+/// by `1 + rate`.
+pub fn present_value_annuity(payment: f64, rate: f64, periods: u32) -> f64 {
+    if rate == 0.0 {
+        return payment * periods as f64;
+    }
+    payment * (1.0 - (1.0 + rate).powi(-(periods as i32))) / rate
+}
+
+/// Computes the net present value of a series of cash flows.
+///
+/// The cash flow at index `t` is discounted by `t` periods: `NPV = Σ CF_t / (1 + rate)^t`. The
+/// first cash flow is therefore not discounted, which matches the usual convention of an initial
+/// investment at time zero.
+///
+/// # Arguments
+///
+/// * `rate` - The discount rate per period, as a fraction. Must be greater than `-1`.
+/// * `cashflows` - The cash flows, one per period, with outflows negative.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::npv;
+///
+/// // Invest 1000 now, receive 500 a year for three years, at a 10% discount rate.
+/// let value = npv(0.10, &[-1000.0, 500.0, 500.0, 500.0]);
+/// assert!((value - 243.43).abs() < 0.01);
+/// ```
+pub fn npv(rate: f64, cashflows: &[f64]) -> f64 {
+    let discount = 1.0 / (1.0 + rate);
+    cashflows.iter().rev().fold(0.0, |acc, &cf| acc * discount + cf)
+}
+
+/// Computes the internal rate of return (IRR): the discount rate at which the net present value of
+/// the cash flows is zero.
+///
+/// The NPV is scanned over a range of rates from -99% upwards for a sign change, and the rate is
+/// then located with [`bisection`]. When the cash flows change sign more
+/// than once there may be several IRRs; the one found is the lowest in the first bracket.
+///
+/// # Arguments
+///
+/// * `cashflows` - The cash flows, one per period, with outflows negative.
+///
+/// # Returns
+///
+/// * `Ok(rate)` with the IRR per period, as a fraction.
+/// * `Err(HellError::ConvergenceFailure)` if the NPV never changes sign, for example because all
+///   cash flows have the same sign.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::{irr, npv};
+///
+/// let cashflows = [-1000.0, 500.0, 500.0, 500.0];
+/// let rate = irr(&cashflows).unwrap();
+/// assert!((rate - 0.2338).abs() < 1e-4);
+/// assert!(npv(rate, &cashflows).abs() < 1e-6);
+///
+/// assert!(irr(&[100.0, 200.0]).is_err());
+/// ```
+pub fn irr(cashflows: &[f64]) -> Result<f64, HellError> {
+    const RATES: [f64; 14] = [-0.99, -0.9, -0.5, -0.2, 0.0, 0.05, 0.1, 0.2, 0.5, 1.0, 2.0, 10.0, 100.0, 1000.0];
+    let f = |rate: f64| npv(rate, cashflows);
+    RATES
+        .windows(2)
+        .find(|pair| {
+            let (a, b) = (f(pair[0]), f(pair[1]));
+            a == 0.0 || a.signum() != b.signum()
+        })
+        .ok_or(HellError::ConvergenceFailure("Net present value does not change sign."))
+        .and_then(|pair| bisection(f, pair[0], pair[1], 1e-12, 200))
+}
+
+/// Computes the fixed payment per period that pays off a loan over `periods` periods.
+///
+/// This inverts [`present_value_annuity`]: `PMT = P·r / (1 − (1 + r)^(−n))`, or `P/n` when
+/// `r = 0`.
+///
+/// # Arguments
+///
+/// * `principal` - The amount borrowed.
+/// * `rate` - The interest rate per period, as a fraction.
+/// * `periods` - The number of payments.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::loan_payment;
+///
+/// // 200,000 over 30 years at 6% a year, paid monthly.
+/// let payment = loan_payment(200_000.0, 0.06 / 12.0, 360);
+/// assert!((payment - 1199.10).abs() < 0.01);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `periods` is zero.
+pub fn loan_payment(principal: f64, rate: f64, periods: u32) -> f64 {
+    assert!(periods > 0, "A loan needs at least one payment.");
+    if rate == 0.0 {
+        return principal / periods as f64;
+    }
+    principal * rate / (1.0 - (1.0 + rate).powi(-(periods as i32)))
+}
+
+/// One payment of an [`AmortizationSchedule`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AmortizationRow {
+    /// The payment number, starting at 1.
+    pub period: u32,
+    /// The total amount paid this period.
+    pub payment: f64,
+    /// The part of the payment that covers interest.
+    pub interest: f64,
+    /// The part of the payment that reduces the balance.
+    pub principal: f64,
+    /// The balance remaining after the payment.
+    pub balance: f64,
+}
+
+/// A loan repayment plan, as produced by [`amortization_schedule`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct AmortizationSchedule {
+    /// One row per payment, in order.
+    pub rows: Vec<AmortizationRow>,
+    /// The sum of all payments.
+    pub total_payment: f64,
+    /// The sum of all interest paid.
+    pub total_interest: f64,
+}
+
+/// Builds the amortization schedule of a fixed-payment loan with monthly payments.
+///
+/// Each month, interest is charged on the outstanding balance at `annual_rate / 12`, and the rest
+/// of the fixed [`loan_payment`] reduces the balance. The last payment is adjusted by any rounding
+/// residue so that the final balance is exactly zero.
+///
+/// # Arguments
+///
+/// * `principal` - The amount borrowed.
+/// * `annual_rate` - The nominal annual interest rate, as a fraction.
+/// * `periods` - The number of monthly payments.
+///
+/// # Returns
+///
+/// * The schedule, with one row per payment and the totals.
+///
+/// # Examples
+///
+/// ```
+/// use hell::finance::amortization_schedule;
+///
+/// let schedule = amortization_schedule(10_000.0, 0.12, 12);
+/// assert_eq!(schedule.rows.len(), 12);
+///
+/// let first = schedule.rows[0];
+/// assert!((first.payment - 888.49).abs() < 0.01);
+/// assert!((first.interest - 100.0).abs() < 1e-9);
+/// assert!((first.principal - 788.49).abs() < 0.01);
+///
+/// assert_eq!(schedule.rows[11].balance, 0.0);
+/// assert!((schedule.total_interest - 661.85).abs() < 0.01);
+/// assert!((schedule.total_payment - schedule.total_interest - 10_000.0).abs() < 1e-6);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `periods` is zero.
+pub fn amortization_schedule(principal: f64, annual_rate: f64, periods: u32) -> AmortizationSchedule {
+    let rate = annual_rate / 12.0;
+    let payment = loan_payment(principal, rate, periods);
+    let mut balance = principal;
+    let mut rows = Vec::with_capacity(periods as usize);
+    for period in 1..=periods {
+        let interest = balance * rate;
+        let principal_part = if period == periods { balance } else { payment - interest };
+        balance -= principal_part;
+        if period == periods {
+            balance = 0.0;
+        }
+        rows.push(AmortizationRow { period, payment: interest + principal_part, interest, principal: principal_part, balance });
+    }
+    let total_payment = rows.iter().map(|row| row.payment).sum();
+    let total_interest = rows.iter().map(|row| row.interest).sum();
+    AmortizationSchedule { rows, total_payment, total_interest }
+}
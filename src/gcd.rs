@@ -6,17 +6,18 @@
 /// At that point, the other number is the GCD. This method is efficient and works well even for large integers.
 /// 
 /// This function accepts a list of integers (in the form of a slice) and iteratively computes the GCD of all the elements.
-/// It uses the `reduce` function to apply the GCD operation over the entire slice, ensuring that the GCD is calculated pairwise.
+/// The GCD operation is applied pairwise over the entire slice. The function is a `const fn`, so it can also be used to
+/// compute compile-time constants.
 /// 
 /// # Arguments
 /// 
 /// * `numbers` - A slice of unsigned integers (`&[u32]`). The slice can contain any number of elements, and the function
-/// will return the GCD of all the elements. If the slice is empty, the function returns `0`.
+///   will return the GCD of all the elements. If the slice is empty, the function returns `0`.
 /// 
 /// # Returns
 /// 
 /// * A `u32` representing the greatest common divisor of the integers in the input slice. If the slice is empty, the function returns `0`.
-/// If there is only one element in the slice, the function will return that element itself, as the GCD of a single number is the number itself.
+///   If there is only one element in the slice, the function will return that element itself, as the GCD of a single number is the number itself.
 /// 
 /// # Edge Cases
 /// 
@@ -38,6 +39,8 @@
 /// # Examples
 /// 
 /// ```rust
+/// use hell::gcd::gcd;
+///
 /// let numbers = vec![48, 18, 30];
 /// let result = gcd(&numbers);
 /// assert_eq!(result, 6);
@@ -59,14 +62,72 @@
 /// 
 /// * [Euclidean Algorithm - Wikipedia](https://en.wikipedia.org/wiki/Euclidean_algorithm)
 /// 
-pub fn gcd(numbers: &[u32]) -> u32 {
-    numbers.iter().cloned().reduce(|a, b| gcd_two(a, b)).unwrap_or(0)
+pub const fn gcd(numbers: &[u32]) -> u32 {
+    let mut result = 0;
+    let mut i = 0;
+    while i < numbers.len() {
+        result = gcd_two(result, numbers[i]);
+        i += 1;
+    }
+    result
+}
+
+/// Computes the Greatest Common Divisor (GCD) of a list of arbitrary-precision integers.
+///
+/// This is the arbitrary-precision counterpart of [`gcd`], with the same edge cases: an empty slice
+/// returns `0` and a single element is returned unchanged. It is only available with the `bignum`
+/// feature.
+///
+/// # Arguments
+///
+/// * `numbers` - A slice of `BigUint` values.
+///
+/// # Returns
+///
+/// * A `BigUint` representing the greatest common divisor of the integers in the input slice.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "bignum")] {
+/// use hell::gcd::gcd_big;
+/// use num_bigint::BigUint;
+///
+/// let a: BigUint = "123456789012345678901234567890".parse().unwrap();
+/// let b: BigUint = "987654321098765432109876543210".parse().unwrap();
+/// assert_eq!(gcd_big(&[a, b]).to_string(), "9000000000900000000090");
+/// # }
+/// ```
+#[cfg(feature = "bignum")]
+pub fn gcd_big(numbers: &[num_bigint::BigUint]) -> num_bigint::BigUint {
+    use num_traits::Zero;
+
+    let gcd_two_big = |mut a: num_bigint::BigUint, mut b: num_bigint::BigUint| {
+        while !b.is_zero() {
+            let remainder = &a % &b;
+            a = b;
+            b = remainder;
+        }
+        a
+    };
+    numbers.iter().cloned().reduce(gcd_two_big).unwrap_or_default()
 }
 
 /// Computes the GCD of two non-negative integers using the Euclidean algorithm.
 ///
-/// This is a helper function used by the `gcd` function to compute the GCD of two numbers.
-fn gcd_two(a: u32, b: u32) -> u32 {
+/// This is the building block of the `gcd` function. The GCD of `0` and `b` is `b`, and the GCD
+/// of two zeros is `0`. It is a `const fn`, so it can be used in compile-time constants.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::gcd::gcd_two;
+///
+/// const G: u32 = gcd_two(48, 18);
+/// assert_eq!(G, 6);
+/// assert_eq!(gcd_two(0, 7), 7);
+/// ```
+pub const fn gcd_two(a: u32, b: u32) -> u32 {
     let mut a = a;
     let mut b = b;
     while b != 0 {
@@ -76,3 +137,72 @@ fn gcd_two(a: u32, b: u32) -> u32 {
     }
     a
 }
+
+/// Computes the Least Common Multiple (LCM) of two non-negative integers.
+///
+/// The LCM is the smallest positive integer divisible by both `a` and `b`. It is computed as
+/// `a / gcd(a, b) * b`, dividing first so that intermediate values stay as small as possible.
+/// It is a `const fn`, so it can be used in compile-time constants.
+///
+/// # Arguments
+///
+/// * `a` - The first integer.
+/// * `b` - The second integer.
+///
+/// # Returns
+///
+/// * `Some(lcm)` if the result fits in a `u32`, and `None` on overflow. If either input is `0`,
+///   the LCM is `0` by convention.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::gcd::lcm_two;
+///
+/// const L: Option<u32> = lcm_two(4, 6);
+/// assert_eq!(L, Some(12));
+/// assert_eq!(lcm_two(0, 5), Some(0));
+/// assert_eq!(lcm_two(u32::MAX, u32::MAX - 1), None);
+/// ```
+pub const fn lcm_two(a: u32, b: u32) -> Option<u32> {
+    if a == 0 || b == 0 {
+        return Some(0);
+    }
+    (a / gcd_two(a, b)).checked_mul(b)
+}
+
+/// Computes the Least Common Multiple (LCM) of a list of non-negative integers.
+///
+/// The LCM is computed pairwise with [`lcm_two`]. It is a `const fn`, so it can be used in
+/// compile-time constants.
+///
+/// # Arguments
+///
+/// * `numbers` - A slice of unsigned integers (`&[u32]`).
+///
+/// # Returns
+///
+/// * `Some(lcm)` if the result fits in a `u32`, and `None` on overflow. An empty slice returns
+///   `Some(1)`, the identity of the LCM, and any `0` in the slice makes the result `0`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::gcd::lcm;
+///
+/// assert_eq!(lcm(&[4, 6, 10]), Some(60));
+/// assert_eq!(lcm(&[]), Some(1));
+/// assert_eq!(lcm(&[65_536, 65_535, 65_533]), None);
+/// ```
+pub const fn lcm(numbers: &[u32]) -> Option<u32> {
+    let mut result = 1;
+    let mut i = 0;
+    while i < numbers.len() {
+        result = match lcm_two(result, numbers[i]) {
+            Some(value) => value,
+            None => return None,
+        };
+        i += 1;
+    }
+    Some(result)
+}
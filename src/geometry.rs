@@ -0,0 +1,21 @@
+//! Two- and three-dimensional geometry built on small `Copy` vector types.
+//!
+//! The [`Vec2`] and [`Vec3`] types are the building blocks for the rest of the module. They
+//! support the usual vector algebra (dot and cross products, norms, normalisation) and overload
+//! the arithmetic operators so that geometric code reads like the underlying mathematics.
+//!
+//! Polygon measurements (area, centroid, perimeter, containment) live in [`polygon`], and
+//! line/segment intersection queries live in [`intersection`]. 3D ray casting against planes,
+//! spheres, and boxes is provided by [`ray::Ray`], and closest-point queries by [`distance`].
+//! Quadratic and cubic Bézier curves are in [`bezier`], and homogeneous-matrix affine transforms
+//! in [`affine`].
+
+pub mod affine;
+pub mod bezier;
+pub mod distance;
+pub mod intersection;
+pub mod polygon;
+pub mod ray;
+pub mod vector;
+
+pub use vector::{Vec2, Vec3, Vector};
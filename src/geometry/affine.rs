@@ -0,0 +1,295 @@
+use super::{Vec2, Vec3};
+use crate::matrix::Matrix;
+
+/// A 2D affine transformation (any combination of translation, rotation, scaling, and shear),
+/// stored as a 3x3 homogeneous [`Matrix`].
+///
+/// Transforms are composed with [`Affine2::then`] or [`Affine2::compose`], so pipelines such as
+/// "scale, then rotate, then translate" can be built without manual matrix bookkeeping.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{affine::Affine2, Vec2};
+///
+/// let transform = Affine2::scaling(2.0, 2.0)
+///     .then(&Affine2::rotation(std::f64::consts::FRAC_PI_2))
+///     .then(&Affine2::translation(1.0, 0.0));
+///
+/// let p = transform.apply(Vec2::new(1.0, 0.0));
+/// assert!((p - Vec2::new(1.0, 2.0)).norm() < 1e-12);
+///
+/// let back = transform.invert().unwrap().apply(p);
+/// assert!((back - Vec2::new(1.0, 0.0)).norm() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affine2 {
+    matrix: Matrix,
+}
+
+/// A 3D affine transformation (any combination of translation, rotation, scaling, and shear),
+/// stored as a 4x4 homogeneous [`Matrix`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{affine::Affine3, Vec3};
+///
+/// let transform = Affine3::rotation_z(std::f64::consts::FRAC_PI_2)
+///     .then(&Affine3::translation(0.0, 0.0, 5.0));
+///
+/// let p = transform.apply(Vec3::new(1.0, 0.0, 0.0));
+/// assert!((p - Vec3::new(0.0, 1.0, 5.0)).norm() < 1e-12);
+///
+/// // Directions are rotated but not translated.
+/// let d = transform.apply_vector(Vec3::new(1.0, 0.0, 0.0));
+/// assert!((d - Vec3::new(0.0, 1.0, 0.0)).norm() < 1e-12);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Affine3 {
+    matrix: Matrix,
+}
+
+impl Affine2 {
+    /// Builds a transform from the linear part `[[a, b], [c, d]]` and translation `(tx, ty)`.
+    fn from_parts(a: f64, b: f64, c: f64, d: f64, tx: f64, ty: f64) -> Self {
+        Affine2 {
+            matrix: Matrix::new(3, 3, vec![vec![a, b, tx], vec![c, d, ty], vec![0.0, 0.0, 1.0]]),
+        }
+    }
+
+    /// Returns the identity transform, which leaves every point unchanged.
+    pub fn identity() -> Self {
+        Affine2 { matrix: Matrix::identity(3) }
+    }
+
+    /// Returns a translation by `(tx, ty)`.
+    pub fn translation(tx: f64, ty: f64) -> Self {
+        Affine2::from_parts(1.0, 0.0, 0.0, 1.0, tx, ty)
+    }
+
+    /// Returns a counter-clockwise rotation about the origin by `angle_rad` radians.
+    pub fn rotation(angle_rad: f64) -> Self {
+        let (sin, cos) = angle_rad.sin_cos();
+        Affine2::from_parts(cos, -sin, sin, cos, 0.0, 0.0)
+    }
+
+    /// Returns a scaling about the origin by `sx` along `x` and `sy` along `y`.
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Affine2::from_parts(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// Wraps an existing 3x3 homogeneous matrix.
+    ///
+    /// # Returns
+    /// - `Some(transform)` if `matrix` is 3x3 with a bottom row of `[0, 0, 1]`.
+    /// - `None` otherwise, since such a matrix is not an affine transform.
+    pub fn from_matrix(matrix: Matrix) -> Option<Self> {
+        if matrix.shape() != (3, 3) {
+            return None;
+        }
+        if matrix.at(2, 0) != 0.0 || matrix.at(2, 1) != 0.0 || matrix.at(2, 2) != 1.0 {
+            return None;
+        }
+        Some(Affine2 { matrix })
+    }
+
+    /// Returns the underlying 3x3 homogeneous matrix.
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// Returns the transform that applies `other` first and then `self`, i.e. the matrix product
+    /// `self * other`.
+    pub fn compose(&self, other: &Affine2) -> Affine2 {
+        Affine2 {
+            matrix: self.matrix.multiply(&other.matrix).expect("3x3 matrices always multiply"),
+        }
+    }
+
+    /// Returns the transform that applies `self` first and then `next`. This reads left-to-right
+    /// in pipeline order and is equivalent to `next.compose(self)`.
+    pub fn then(&self, next: &Affine2) -> Affine2 {
+        next.compose(self)
+    }
+
+    /// Returns the inverse transform.
+    ///
+    /// # Returns
+    /// - `Some(inverse)` such that `inverse.compose(self)` is the identity.
+    /// - `None` if the transform is singular (for example a scaling by zero).
+    pub fn invert(&self) -> Option<Affine2> {
+        let m = &self.matrix;
+        let (a, b, c, d) = (m.at(0, 0), m.at(0, 1), m.at(1, 0), m.at(1, 1));
+        let (tx, ty) = (m.at(0, 2), m.at(1, 2));
+        let det = a * d - b * c;
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let (ia, ib, ic, id) = (d / det, -b / det, -c / det, a / det);
+        Some(Affine2::from_parts(ia, ib, ic, id, -(ia * tx + ib * ty), -(ic * tx + id * ty)))
+    }
+
+    /// Applies the transform to a point (translation included).
+    pub fn apply(&self, point: Vec2) -> Vec2 {
+        let m = &self.matrix;
+        Vec2::new(
+            m.at(0, 0) * point.x + m.at(0, 1) * point.y + m.at(0, 2),
+            m.at(1, 0) * point.x + m.at(1, 1) * point.y + m.at(1, 2),
+        )
+    }
+
+    /// Applies the transform to a direction vector (translation ignored).
+    pub fn apply_vector(&self, vector: Vec2) -> Vec2 {
+        let m = &self.matrix;
+        Vec2::new(
+            m.at(0, 0) * vector.x + m.at(0, 1) * vector.y,
+            m.at(1, 0) * vector.x + m.at(1, 1) * vector.y,
+        )
+    }
+}
+
+impl Affine3 {
+    /// Builds a transform from a row-major 3x3 linear part and a translation.
+    fn from_parts(linear: [[f64; 3]; 3], translation: [f64; 3]) -> Self {
+        let mut data: Vec<Vec<f64>> = linear
+            .iter()
+            .zip(translation)
+            .map(|(row, t)| vec![row[0], row[1], row[2], t])
+            .collect();
+        data.push(vec![0.0, 0.0, 0.0, 1.0]);
+        Affine3 { matrix: Matrix::new(4, 4, data) }
+    }
+
+    /// Returns the row-major 3x3 linear part of the transform.
+    fn linear(&self) -> [[f64; 3]; 3] {
+        let mut linear = [[0.0; 3]; 3];
+        for (i, row) in linear.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = self.matrix.at(i, j);
+            }
+        }
+        linear
+    }
+
+    /// Returns the identity transform, which leaves every point unchanged.
+    pub fn identity() -> Self {
+        Affine3 { matrix: Matrix::identity(4) }
+    }
+
+    /// Returns a translation by `(tx, ty, tz)`.
+    pub fn translation(tx: f64, ty: f64, tz: f64) -> Self {
+        Affine3::from_parts([[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], [tx, ty, tz])
+    }
+
+    /// Returns a scaling about the origin by `sx`, `sy`, and `sz` along the coordinate axes.
+    pub fn scaling(sx: f64, sy: f64, sz: f64) -> Self {
+        Affine3::from_parts([[sx, 0.0, 0.0], [0.0, sy, 0.0], [0.0, 0.0, sz]], [0.0; 3])
+    }
+
+    /// Returns a right-handed rotation about the `x` axis by `angle_rad` radians.
+    pub fn rotation_x(angle_rad: f64) -> Self {
+        let (s, c) = angle_rad.sin_cos();
+        Affine3::from_parts([[1.0, 0.0, 0.0], [0.0, c, -s], [0.0, s, c]], [0.0; 3])
+    }
+
+    /// Returns a right-handed rotation about the `y` axis by `angle_rad` radians.
+    pub fn rotation_y(angle_rad: f64) -> Self {
+        let (s, c) = angle_rad.sin_cos();
+        Affine3::from_parts([[c, 0.0, s], [0.0, 1.0, 0.0], [-s, 0.0, c]], [0.0; 3])
+    }
+
+    /// Returns a right-handed rotation about the `z` axis by `angle_rad` radians.
+    pub fn rotation_z(angle_rad: f64) -> Self {
+        let (s, c) = angle_rad.sin_cos();
+        Affine3::from_parts([[c, -s, 0.0], [s, c, 0.0], [0.0, 0.0, 1.0]], [0.0; 3])
+    }
+
+    /// Wraps an existing 4x4 homogeneous matrix.
+    ///
+    /// # Returns
+    /// - `Some(transform)` if `matrix` is 4x4 with a bottom row of `[0, 0, 0, 1]`.
+    /// - `None` otherwise, since such a matrix is not an affine transform.
+    pub fn from_matrix(matrix: Matrix) -> Option<Self> {
+        if matrix.shape() != (4, 4) {
+            return None;
+        }
+        let bottom = [matrix.at(3, 0), matrix.at(3, 1), matrix.at(3, 2), matrix.at(3, 3)];
+        if bottom != [0.0, 0.0, 0.0, 1.0] {
+            return None;
+        }
+        Some(Affine3 { matrix })
+    }
+
+    /// Returns the underlying 4x4 homogeneous matrix.
+    pub fn matrix(&self) -> &Matrix {
+        &self.matrix
+    }
+
+    /// Returns the transform that applies `other` first and then `self`, i.e. the matrix product
+    /// `self * other`.
+    pub fn compose(&self, other: &Affine3) -> Affine3 {
+        Affine3 {
+            matrix: self.matrix.multiply(&other.matrix).expect("4x4 matrices always multiply"),
+        }
+    }
+
+    /// Returns the transform that applies `self` first and then `next`. This reads left-to-right
+    /// in pipeline order and is equivalent to `next.compose(self)`.
+    pub fn then(&self, next: &Affine3) -> Affine3 {
+        next.compose(self)
+    }
+
+    /// Returns the inverse transform.
+    ///
+    /// # Returns
+    /// - `Some(inverse)` such that `inverse.compose(self)` is the identity.
+    /// - `None` if the transform is singular (for example a scaling by zero).
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::geometry::{affine::Affine3, Vec3};
+    ///
+    /// let t = Affine3::scaling(2.0, 4.0, 8.0).then(&Affine3::translation(1.0, 2.0, 3.0));
+    /// let p = Vec3::new(1.0, 1.0, 1.0);
+    /// assert_eq!(t.invert().unwrap().apply(t.apply(p)), p);
+    /// assert!(Affine3::scaling(1.0, 0.0, 1.0).invert().is_none());
+    /// ```
+    pub fn invert(&self) -> Option<Affine3> {
+        let m = self.linear();
+        let cofactor = |r1: usize, r2: usize, c1: usize, c2: usize| m[r1][c1] * m[r2][c2] - m[r1][c2] * m[r2][c1];
+        let det = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2) + m[0][2] * cofactor(1, 2, 0, 1);
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        // Inverse of the linear part via the adjugate.
+        let inv = [
+            [cofactor(1, 2, 1, 2) / det, -cofactor(0, 2, 1, 2) / det, cofactor(0, 1, 1, 2) / det],
+            [-cofactor(1, 2, 0, 2) / det, cofactor(0, 2, 0, 2) / det, -cofactor(0, 1, 0, 2) / det],
+            [cofactor(1, 2, 0, 1) / det, -cofactor(0, 2, 0, 1) / det, cofactor(0, 1, 0, 1) / det],
+        ];
+        let t = [self.matrix.at(0, 3), self.matrix.at(1, 3), self.matrix.at(2, 3)];
+        let translation = [
+            -(inv[0][0] * t[0] + inv[0][1] * t[1] + inv[0][2] * t[2]),
+            -(inv[1][0] * t[0] + inv[1][1] * t[1] + inv[1][2] * t[2]),
+            -(inv[2][0] * t[0] + inv[2][1] * t[1] + inv[2][2] * t[2]),
+        ];
+        Some(Affine3::from_parts(inv, translation))
+    }
+
+    /// Applies the transform to a point (translation included).
+    pub fn apply(&self, point: Vec3) -> Vec3 {
+        let translation = Vec3::new(self.matrix.at(0, 3), self.matrix.at(1, 3), self.matrix.at(2, 3));
+        self.apply_vector(point) + translation
+    }
+
+    /// Applies the transform to a direction vector (translation ignored).
+    pub fn apply_vector(&self, vector: Vec3) -> Vec3 {
+        let m = &self.matrix;
+        Vec3::new(
+            m.at(0, 0) * vector.x + m.at(0, 1) * vector.y + m.at(0, 2) * vector.z,
+            m.at(1, 0) * vector.x + m.at(1, 1) * vector.y + m.at(1, 2) * vector.z,
+            m.at(2, 0) * vector.x + m.at(2, 1) * vector.y + m.at(2, 2) * vector.z,
+        )
+    }
+}
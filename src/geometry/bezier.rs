@@ -0,0 +1,229 @@
+use super::Vector;
+use crate::calculus::integral;
+
+/// A quadratic Bézier curve defined by a start point, one control point, and an end point.
+///
+/// The curve is generic over [`Vector`], so it works with both [`Vec2`](super::Vec2) and
+/// [`Vec3`](super::Vec3) control points.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{bezier::QuadraticBezier, Vec2};
+///
+/// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+/// assert_eq!(curve.point_at(0.5), Vec2::new(1.0, 1.0));
+/// assert_eq!(curve.tangent_at(0.5), Vec2::new(2.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<V> {
+    pub start: V,
+    pub control: V,
+    pub end: V,
+}
+
+/// A cubic Bézier curve defined by a start point, two control points, and an end point.
+///
+/// The curve is generic over [`Vector`], so it works with both [`Vec2`](super::Vec2) and
+/// [`Vec3`](super::Vec3) control points.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{bezier::CubicBezier, Vec2};
+///
+/// let curve = CubicBezier::new(
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(0.0, 1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 0.0),
+/// );
+/// assert_eq!(curve.point_at(0.0), Vec2::new(0.0, 0.0));
+/// assert_eq!(curve.point_at(0.5), Vec2::new(0.5, 0.75));
+/// assert_eq!(curve.point_at(1.0), Vec2::new(1.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<V> {
+    pub start: V,
+    pub control1: V,
+    pub control2: V,
+    pub end: V,
+}
+
+impl<V: Vector> QuadraticBezier<V> {
+    /// Creates a new quadratic Bézier curve.
+    pub fn new(start: V, control: V, end: V) -> Self {
+        QuadraticBezier { start, control, end }
+    }
+
+    /// Evaluates the curve at parameter `t`, where `t = 0` is the start and `t = 1` the end.
+    pub fn point_at(&self, t: f64) -> V {
+        let mt = 1.0 - t;
+        self.start * (mt * mt) + self.control * (2.0 * mt * t) + self.end * (t * t)
+    }
+
+    /// Evaluates the first derivative `dB/dt` of the curve at parameter `t`.
+    ///
+    /// The result points along the direction of travel; its length is the speed at which the
+    /// curve is traversed with respect to `t`.
+    pub fn tangent_at(&self, t: f64) -> V {
+        (self.control - self.start) * (2.0 * (1.0 - t)) + (self.end - self.control) * (2.0 * t)
+    }
+
+    /// Approximates the arc length of the curve.
+    ///
+    /// The length is the integral of `|B'(t)|` over `[0, 1]`, evaluated with the trapezoidal rule
+    /// from [`crate::calculus::integral`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of subintervals used for the integration. Larger values are more
+    ///   accurate; a few hundred is plenty for typical curves.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{bezier::QuadraticBezier, Vec2};
+    ///
+    /// // A "curve" whose control point lies on the chord is a straight line of length 2.
+    /// let line = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0));
+    /// assert!((line.arc_length(100) - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn arc_length(&self, n: usize) -> f64 {
+        integral(|t| self.tangent_at(t).norm(), 0.0, 1.0, n)
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    ///
+    /// # Returns
+    ///
+    /// * A pair of curves `(left, right)`: `left` traces the original curve over `[0, t]` and
+    ///   `right` over `[t, 1]`, each reparameterised to `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{bezier::QuadraticBezier, Vec2};
+    ///
+    /// let curve = QuadraticBezier::new(Vec2::new(0.0, 0.0), Vec2::new(1.0, 2.0), Vec2::new(2.0, 0.0));
+    /// let (left, right) = curve.subdivide(0.5);
+    /// assert_eq!(left.end, curve.point_at(0.5));
+    /// assert_eq!(right.start, curve.point_at(0.5));
+    /// assert_eq!(left.point_at(0.5), curve.point_at(0.25));
+    /// ```
+    pub fn subdivide(&self, t: f64) -> (Self, Self) {
+        let p01 = lerp(self.start, self.control, t);
+        let p12 = lerp(self.control, self.end, t);
+        let mid = lerp(p01, p12, t);
+        (
+            QuadraticBezier::new(self.start, p01, mid),
+            QuadraticBezier::new(mid, p12, self.end),
+        )
+    }
+}
+
+impl<V: Vector> CubicBezier<V> {
+    /// Creates a new cubic Bézier curve.
+    pub fn new(start: V, control1: V, control2: V, end: V) -> Self {
+        CubicBezier { start, control1, control2, end }
+    }
+
+    /// Evaluates the curve at parameter `t`, where `t = 0` is the start and `t = 1` the end.
+    pub fn point_at(&self, t: f64) -> V {
+        let mt = 1.0 - t;
+        self.start * (mt * mt * mt)
+            + self.control1 * (3.0 * mt * mt * t)
+            + self.control2 * (3.0 * mt * t * t)
+            + self.end * (t * t * t)
+    }
+
+    /// Evaluates the first derivative `dB/dt` of the curve at parameter `t`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{bezier::CubicBezier, Vec2};
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(0.0, 1.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(1.0, 0.0),
+    /// );
+    /// // The tangent at each end points towards the adjacent control point.
+    /// assert_eq!(curve.tangent_at(0.0), Vec2::new(0.0, 3.0));
+    /// assert_eq!(curve.tangent_at(1.0), Vec2::new(0.0, -3.0));
+    /// ```
+    pub fn tangent_at(&self, t: f64) -> V {
+        let mt = 1.0 - t;
+        (self.control1 - self.start) * (3.0 * mt * mt)
+            + (self.control2 - self.control1) * (6.0 * mt * t)
+            + (self.end - self.control2) * (3.0 * t * t)
+    }
+
+    /// Approximates the arc length of the curve.
+    ///
+    /// The length is the integral of `|B'(t)|` over `[0, 1]`, evaluated with the trapezoidal rule
+    /// from [`crate::calculus::integral`].
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - The number of subintervals used for the integration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{bezier::CubicBezier, Vec3};
+    ///
+    /// let line = CubicBezier::new(
+    ///     Vec3::ZERO,
+    ///     Vec3::new(1.0, 0.0, 0.0),
+    ///     Vec3::new(2.0, 0.0, 0.0),
+    ///     Vec3::new(3.0, 0.0, 0.0),
+    /// );
+    /// assert!((line.arc_length(100) - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn arc_length(&self, n: usize) -> f64 {
+        integral(|t| self.tangent_at(t).norm(), 0.0, 1.0, n)
+    }
+
+    /// Splits the curve at parameter `t` using de Casteljau's algorithm.
+    ///
+    /// # Returns
+    ///
+    /// * A pair of curves `(left, right)`: `left` traces the original curve over `[0, t]` and
+    ///   `right` over `[t, 1]`, each reparameterised to `[0, 1]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{bezier::CubicBezier, Vec2};
+    ///
+    /// let curve = CubicBezier::new(
+    ///     Vec2::new(0.0, 0.0),
+    ///     Vec2::new(0.0, 1.0),
+    ///     Vec2::new(1.0, 1.0),
+    ///     Vec2::new(1.0, 0.0),
+    /// );
+    /// let (left, right) = curve.subdivide(0.5);
+    /// assert_eq!(left.end, curve.point_at(0.5));
+    /// assert_eq!(right.point_at(0.5), curve.point_at(0.75));
+    /// ```
+    pub fn subdivide(&self, t: f64) -> (Self, Self) {
+        let p01 = lerp(self.start, self.control1, t);
+        let p12 = lerp(self.control1, self.control2, t);
+        let p23 = lerp(self.control2, self.end, t);
+        let p012 = lerp(p01, p12, t);
+        let p123 = lerp(p12, p23, t);
+        let mid = lerp(p012, p123, t);
+        (
+            CubicBezier::new(self.start, p01, p012, mid),
+            CubicBezier::new(mid, p123, p23, self.end),
+        )
+    }
+}
+
+/// Linearly interpolates between two points.
+fn lerp<V: Vector>(a: V, b: V, t: f64) -> V {
+    a + (b - a) * t
+}
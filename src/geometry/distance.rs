@@ -0,0 +1,113 @@
+use super::{Vec3, Vector};
+
+/// Computes the distance from a point to the line segment `a`–`b`, and the closest point on it.
+///
+/// Works for both [`Vec2`](super::Vec2) and [`Vec3`] points.
+///
+/// # Arguments
+///
+/// * `point` - The query point.
+/// * `a`, `b` - The endpoints of the segment. If they coincide the segment is treated as a point.
+///
+/// # Returns
+///
+/// * A tuple `(distance, closest)` where `closest` is the point of the segment nearest to `point`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{distance::point_segment, Vec2};
+///
+/// let (d, closest) = point_segment(Vec2::new(1.0, 2.0), Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+/// assert_eq!(d, 2.0);
+/// assert_eq!(closest, Vec2::new(1.0, 0.0));
+///
+/// // Beyond the end of the segment the nearest point is the endpoint itself.
+/// let (d, closest) = point_segment(Vec2::new(7.0, 4.0), Vec2::new(0.0, 0.0), Vec2::new(4.0, 0.0));
+/// assert_eq!(d, 5.0);
+/// assert_eq!(closest, Vec2::new(4.0, 0.0));
+/// ```
+pub fn point_segment<V: Vector>(point: V, a: V, b: V) -> (f64, V) {
+    let t = projection_parameter(point, a, b).clamp(0.0, 1.0);
+    let closest = a + (b - a) * t;
+    ((point - closest).norm(), closest)
+}
+
+/// Computes the distance from a point to the infinite line through `a` and `b`, and the closest
+/// point on it.
+///
+/// Works for both [`Vec2`](super::Vec2) and [`Vec3`] points.
+///
+/// # Arguments
+///
+/// * `point` - The query point.
+/// * `a`, `b` - Two points on the line. If they coincide the line degenerates to the point `a`.
+///
+/// # Returns
+///
+/// * A tuple `(distance, closest)` where `closest` is the orthogonal projection of `point` onto
+///   the line.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{distance::point_line, Vec3};
+///
+/// let (d, closest) = point_line(
+///     Vec3::new(5.0, 3.0, 0.0),
+///     Vec3::ZERO,
+///     Vec3::new(1.0, 0.0, 0.0),
+/// );
+/// assert_eq!(d, 3.0);
+/// assert_eq!(closest, Vec3::new(5.0, 0.0, 0.0));
+/// ```
+pub fn point_line<V: Vector>(point: V, a: V, b: V) -> (f64, V) {
+    let t = projection_parameter(point, a, b);
+    let closest = a + (b - a) * t;
+    ((point - closest).norm(), closest)
+}
+
+/// Computes the distance from a point to a plane, and the closest point on the plane.
+///
+/// # Arguments
+///
+/// * `point` - The query point.
+/// * `plane_point` - Any point on the plane.
+/// * `normal` - A (not necessarily unit) normal vector of the plane.
+///
+/// # Returns
+///
+/// * `Some((distance, closest))` with the unsigned distance and the orthogonal projection of
+///   `point` onto the plane, or `None` if `normal` is the zero vector.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{distance::point_plane, Vec3};
+///
+/// let (d, closest) = point_plane(
+///     Vec3::new(1.0, 2.0, 3.0),
+///     Vec3::ZERO,
+///     Vec3::new(0.0, 0.0, 2.0),
+/// )
+/// .unwrap();
+/// assert_eq!(d, 3.0);
+/// assert_eq!(closest, Vec3::new(1.0, 2.0, 0.0));
+/// ```
+pub fn point_plane(point: Vec3, plane_point: Vec3, normal: Vec3) -> Option<(f64, Vec3)> {
+    let unit = normal.normalize()?;
+    let signed = (point - plane_point).dot(unit);
+    Some((signed.abs(), point - unit * signed))
+}
+
+/// Returns `t` such that `a + (b - a) * t` is the projection of `point` onto the line `a`–`b`,
+/// or `0` if the line is degenerate.
+fn projection_parameter<V: Vector>(point: V, a: V, b: V) -> f64 {
+    let ab = b - a;
+    let len_sq = ab.norm_squared();
+    if len_sq == 0.0 {
+        0.0
+    } else {
+        (point - a).dot(ab) / len_sq
+    }
+}
@@ -0,0 +1,175 @@
+use super::distance::point_segment;
+use super::Vec2;
+
+/// The result of intersecting two infinite lines in the plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineIntersection {
+    /// The lines are parallel and distinct, so they never meet.
+    None,
+    /// The lines cross at exactly one point.
+    Point(Vec2),
+    /// The lines are coincident and share every point.
+    Overlapping,
+}
+
+/// The result of intersecting two line segments in the plane.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentIntersection {
+    /// The segments do not touch.
+    None,
+    /// The segments touch or cross at exactly one point.
+    Point(Vec2),
+    /// The segments are collinear and share the sub-segment between the two given endpoints.
+    Overlapping(Vec2, Vec2),
+}
+
+/// Intersects the infinite line through `p1` and `p2` with the infinite line through `q1` and `q2`.
+///
+/// # Arguments
+///
+/// * `p1`, `p2` - Two distinct points on the first line.
+/// * `q1`, `q2` - Two distinct points on the second line.
+/// * `epsilon` - The tolerance used to decide that the lines are parallel (compared against the
+///   sine of the angle between them) and, if so, that they are coincident (compared against the
+///   distance between them).
+///
+/// # Returns
+///
+/// * A [`LineIntersection`] describing how the lines meet. If either line is degenerate (its two
+///   defining points coincide) the result is [`LineIntersection::None`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::intersection::{line_intersection, LineIntersection};
+/// use hell::geometry::Vec2;
+///
+/// let hit = line_intersection(
+///     Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0),
+///     Vec2::new(0.0, 2.0), Vec2::new(2.0, 0.0),
+///     1e-9,
+/// );
+/// assert_eq!(hit, LineIntersection::Point(Vec2::new(1.0, 1.0)));
+///
+/// let parallel = line_intersection(
+///     Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0),
+///     Vec2::new(0.0, 1.0), Vec2::new(1.0, 1.0),
+///     1e-9,
+/// );
+/// assert_eq!(parallel, LineIntersection::None);
+/// ```
+pub fn line_intersection(p1: Vec2, p2: Vec2, q1: Vec2, q2: Vec2, epsilon: f64) -> LineIntersection {
+    let r = p2 - p1;
+    let s = q2 - q1;
+    let (r_len, s_len) = (r.norm(), s.norm());
+    if r_len == 0.0 || s_len == 0.0 {
+        return LineIntersection::None;
+    }
+
+    let denom = r.cross(s);
+    let qp = q1 - p1;
+    if denom.abs() <= epsilon * r_len * s_len {
+        // Parallel: coincident if q1 lies on the first line.
+        return if (qp.cross(r) / r_len).abs() <= epsilon {
+            LineIntersection::Overlapping
+        } else {
+            LineIntersection::None
+        };
+    }
+
+    let t = qp.cross(s) / denom;
+    LineIntersection::Point(p1 + r * t)
+}
+
+/// Intersects the segment from `p1` to `p2` with the segment from `q1` to `q2`.
+///
+/// # Arguments
+///
+/// * `p1`, `p2` - The endpoints of the first segment.
+/// * `q1`, `q2` - The endpoints of the second segment.
+/// * `epsilon` - The tolerance used for near-parallel and near-touching decisions. Segments that
+///   miss each other by no more than roughly `epsilon` are reported as touching.
+///
+/// # Returns
+///
+/// * A [`SegmentIntersection`]: a single crossing point, a shared collinear sub-segment, or no
+///   contact. A degenerate segment (equal endpoints) is treated as a single point.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::intersection::{segment_intersection, SegmentIntersection};
+/// use hell::geometry::Vec2;
+///
+/// // Crossing segments.
+/// let hit = segment_intersection(
+///     Vec2::new(0.0, 0.0), Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0), Vec2::new(2.0, 0.0),
+///     1e-9,
+/// );
+/// assert_eq!(hit, SegmentIntersection::Point(Vec2::new(1.0, 1.0)));
+///
+/// // Collinear segments sharing the piece from x = 1 to x = 2.
+/// let overlap = segment_intersection(
+///     Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0),
+///     Vec2::new(1.0, 0.0), Vec2::new(3.0, 0.0),
+///     1e-9,
+/// );
+/// assert_eq!(overlap, SegmentIntersection::Overlapping(Vec2::new(1.0, 0.0), Vec2::new(2.0, 0.0)));
+///
+/// // The lines cross, but outside the segments.
+/// let miss = segment_intersection(
+///     Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0),
+///     Vec2::new(2.0, -1.0), Vec2::new(2.0, 1.0),
+///     1e-9,
+/// );
+/// assert_eq!(miss, SegmentIntersection::None);
+/// ```
+pub fn segment_intersection(p1: Vec2, p2: Vec2, q1: Vec2, q2: Vec2, epsilon: f64) -> SegmentIntersection {
+    let r = p2 - p1;
+    let s = q2 - q1;
+    let (r_len, s_len) = (r.norm(), s.norm());
+
+    // Degenerate segments behave like points.
+    if r_len == 0.0 || s_len == 0.0 {
+        let (point, a, b) = if r_len == 0.0 { (p1, q1, q2) } else { (q1, p1, p2) };
+        let (distance, _) = point_segment(point, a, b);
+        return if distance <= epsilon {
+            SegmentIntersection::Point(point)
+        } else {
+            SegmentIntersection::None
+        };
+    }
+
+    let denom = r.cross(s);
+    let qp = q1 - p1;
+
+    if denom.abs() <= epsilon * r_len * s_len {
+        if (qp.cross(r) / r_len).abs() > epsilon {
+            return SegmentIntersection::None; // Parallel and distinct.
+        }
+        // Collinear: project the second segment onto the first and clip to [0, 1].
+        let rr = r.dot(r);
+        let t0 = qp.dot(r) / rr;
+        let t1 = t0 + s.dot(r) / rr;
+        let (lo, hi) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+        let tol = epsilon / r_len;
+        let start = lo.max(0.0);
+        let end = hi.min(1.0);
+        if start > end + tol {
+            return SegmentIntersection::None;
+        }
+        if end - start <= tol {
+            return SegmentIntersection::Point(p1 + r * start.min(1.0));
+        }
+        return SegmentIntersection::Overlapping(p1 + r * start, p1 + r * end);
+    }
+
+    let t = qp.cross(s) / denom;
+    let u = qp.cross(r) / denom;
+    let (t_tol, u_tol) = (epsilon / r_len, epsilon / s_len);
+    if t < -t_tol || t > 1.0 + t_tol || u < -u_tol || u > 1.0 + u_tol {
+        return SegmentIntersection::None;
+    }
+    SegmentIntersection::Point(p1 + r * t.clamp(0.0, 1.0))
+}
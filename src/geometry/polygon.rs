@@ -0,0 +1,168 @@
+use super::Vec2;
+
+/// Computes the signed area of a simple polygon using the shoelace formula.
+///
+/// The vertices are taken in order and the polygon is implicitly closed (the last vertex connects
+/// back to the first). The sign encodes the winding order: positive for counter-clockwise
+/// vertices, negative for clockwise ones.
+///
+/// # Arguments
+///
+/// * `vertices` - The polygon's vertices in order. Fewer than three vertices give an area of `0`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{polygon::signed_area, Vec2};
+///
+/// let ccw = [Vec2::new(0.0, 0.0), Vec2::new(1.0, 0.0), Vec2::new(0.0, 1.0)];
+/// assert_eq!(signed_area(&ccw), 0.5);
+///
+/// let cw = [Vec2::new(0.0, 0.0), Vec2::new(0.0, 1.0), Vec2::new(1.0, 0.0)];
+/// assert_eq!(signed_area(&cw), -0.5);
+/// ```
+pub fn signed_area(vertices: &[Vec2]) -> f64 {
+    if vertices.len() < 3 {
+        return 0.0;
+    }
+    edges(vertices).map(|(a, b)| a.cross(b)).sum::<f64>() / 2.0
+}
+
+/// Computes the (unsigned) area of a simple polygon using the shoelace formula.
+///
+/// # Arguments
+///
+/// * `vertices` - The polygon's vertices in order, in either winding direction.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{polygon::area, Vec2};
+///
+/// let square = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(area(&square), 4.0);
+/// ```
+///
+/// # Limitations
+///
+/// The shoelace formula assumes the polygon does not intersect itself. For self-intersecting
+/// polygons the result is the difference of the areas wound in each direction.
+pub fn area(vertices: &[Vec2]) -> f64 {
+    signed_area(vertices).abs()
+}
+
+/// Computes the perimeter of a closed polygon.
+///
+/// # Arguments
+///
+/// * `vertices` - The polygon's vertices in order. The closing edge from the last vertex back to
+///   the first is included.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{polygon::perimeter, Vec2};
+///
+/// let triangle = [Vec2::new(0.0, 0.0), Vec2::new(3.0, 0.0), Vec2::new(3.0, 4.0)];
+/// assert_eq!(perimeter(&triangle), 12.0);
+/// ```
+pub fn perimeter(vertices: &[Vec2]) -> f64 {
+    if vertices.len() < 2 {
+        return 0.0;
+    }
+    edges(vertices).map(|(a, b)| a.distance(b)).sum()
+}
+
+/// Computes the centroid (centre of mass) of the area enclosed by a simple polygon.
+///
+/// # Arguments
+///
+/// * `vertices` - The polygon's vertices in order, in either winding direction.
+///
+/// # Returns
+///
+/// * `Some(centroid)` for a polygon with non-zero area, or `None` if the polygon is degenerate
+///   (fewer than three vertices, or all vertices collinear).
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{polygon::centroid, Vec2};
+///
+/// let square = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+/// assert_eq!(centroid(&square), Some(Vec2::new(1.0, 1.0)));
+/// ```
+pub fn centroid(vertices: &[Vec2]) -> Option<Vec2> {
+    let area = signed_area(vertices);
+    if area == 0.0 {
+        return None;
+    }
+    let sum = edges(vertices).fold(Vec2::ZERO, |acc, (a, b)| acc + (a + b) * a.cross(b));
+    Some(sum / (6.0 * area))
+}
+
+/// Tests whether a point lies inside a polygon using the even-odd (ray casting) rule.
+///
+/// A horizontal ray is cast from `point` and the number of polygon edges it crosses is counted;
+/// an odd count means the point is inside. Works for convex and concave polygons.
+///
+/// # Arguments
+///
+/// * `point` - The point to test.
+/// * `vertices` - The polygon's vertices in order.
+///
+/// # Returns
+///
+/// * `true` if the point is inside the polygon. Points exactly on an edge may be classified either
+///   way, as is usual for floating-point ray casting.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{polygon::contains_point, Vec2};
+///
+/// // An L-shaped (concave) polygon.
+/// let shape = [
+///     Vec2::new(0.0, 0.0),
+///     Vec2::new(2.0, 0.0),
+///     Vec2::new(2.0, 1.0),
+///     Vec2::new(1.0, 1.0),
+///     Vec2::new(1.0, 2.0),
+///     Vec2::new(0.0, 2.0),
+/// ];
+/// assert!(contains_point(Vec2::new(0.5, 1.5), &shape));
+/// assert!(!contains_point(Vec2::new(1.5, 1.5), &shape));
+/// ```
+pub fn contains_point(point: Vec2, vertices: &[Vec2]) -> bool {
+    if vertices.len() < 3 {
+        return false;
+    }
+    let mut inside = false;
+    for (a, b) in edges(vertices) {
+        if (a.y > point.y) != (b.y > point.y) {
+            let x_cross = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y);
+            if point.x < x_cross {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// Iterates over the edges `(v[i], v[i + 1])` of a closed polygon, including the closing edge.
+fn edges(vertices: &[Vec2]) -> impl Iterator<Item = (Vec2, Vec2)> + '_ {
+    vertices
+        .iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .map(|(&a, &b)| (a, b))
+}
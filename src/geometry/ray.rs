@@ -0,0 +1,184 @@
+use super::Vec3;
+
+/// A half-line in 3D space starting at `origin` and extending along `direction`.
+///
+/// Points on the ray are `origin + t * direction` for `t >= 0`. The direction does not have to be
+/// normalised; intersection routines report the parameter `t` in units of `direction`, so with a
+/// unit direction `t` is the distance travelled.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{ray::Ray, Vec3};
+///
+/// let ray = Ray::new(Vec3::ZERO, Vec3::new(1.0, 0.0, 0.0));
+/// assert_eq!(ray.at(2.5), Vec3::new(2.5, 0.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Ray {
+    pub origin: Vec3,
+    pub direction: Vec3,
+}
+
+/// The result of a successful ray query: where along the ray the hit happened, and where in space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RayHit {
+    /// The ray parameter of the hit, so that `point == ray.at(t)`.
+    pub t: f64,
+    /// The point of intersection.
+    pub point: Vec3,
+}
+
+impl Ray {
+    /// Creates a new ray from an origin and a direction.
+    pub fn new(origin: Vec3, direction: Vec3) -> Self {
+        Ray { origin, direction }
+    }
+
+    /// Returns the point `origin + t * direction`.
+    pub fn at(&self, t: f64) -> Vec3 {
+        self.origin + self.direction * t
+    }
+
+    /// Builds a [`RayHit`] for parameter `t`.
+    fn hit(&self, t: f64) -> RayHit {
+        RayHit { t, point: self.at(t) }
+    }
+
+    /// Intersects the ray with the plane through `point` with normal `normal`.
+    ///
+    /// # Arguments
+    ///
+    /// * `point` - Any point on the plane.
+    /// * `normal` - A (not necessarily unit) normal vector of the plane.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(hit)` for the first point where the ray meets the plane, or `None` if the ray is
+    ///   parallel to the plane or the plane lies behind the ray's origin.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{ray::Ray, Vec3};
+    ///
+    /// let ray = Ray::new(Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0));
+    /// let hit = ray.intersect_plane(Vec3::ZERO, Vec3::new(0.0, 0.0, 1.0)).unwrap();
+    /// assert_eq!(hit.t, 5.0);
+    /// assert_eq!(hit.point, Vec3::ZERO);
+    /// ```
+    pub fn intersect_plane(&self, point: Vec3, normal: Vec3) -> Option<RayHit> {
+        let denom = normal.dot(self.direction);
+        if denom == 0.0 {
+            return None;
+        }
+        let t = normal.dot(point - self.origin) / denom;
+        if t >= 0.0 && t.is_finite() {
+            Some(self.hit(t))
+        } else {
+            None
+        }
+    }
+
+    /// Intersects the ray with a sphere.
+    ///
+    /// # Arguments
+    ///
+    /// * `center` - The centre of the sphere.
+    /// * `radius` - The radius of the sphere.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(hit)` for the nearest intersection in front of the origin, or `None` if the ray
+    ///   misses. If the origin is inside the sphere, the exit point is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{ray::Ray, Vec3};
+    ///
+    /// let ray = Ray::new(Vec3::new(-5.0, 0.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// let hit = ray.intersect_sphere(Vec3::ZERO, 1.0).unwrap();
+    /// assert_eq!(hit.point, Vec3::new(-1.0, 0.0, 0.0));
+    ///
+    /// let miss = Ray::new(Vec3::new(-5.0, 2.0, 0.0), Vec3::new(1.0, 0.0, 0.0));
+    /// assert!(miss.intersect_sphere(Vec3::ZERO, 1.0).is_none());
+    /// ```
+    pub fn intersect_sphere(&self, center: Vec3, radius: f64) -> Option<RayHit> {
+        // Solve |o + t d - c|^2 = r^2, a quadratic in t.
+        let oc = self.origin - center;
+        let a = self.direction.norm_squared();
+        if a == 0.0 {
+            return None;
+        }
+        let half_b = oc.dot(self.direction);
+        let c = oc.norm_squared() - radius * radius;
+        let discriminant = half_b * half_b - a * c;
+        if discriminant < 0.0 {
+            return None;
+        }
+        let sqrt_d = discriminant.sqrt();
+        let near = (-half_b - sqrt_d) / a;
+        let far = (-half_b + sqrt_d) / a;
+        if near >= 0.0 {
+            Some(self.hit(near))
+        } else if far >= 0.0 {
+            Some(self.hit(far))
+        } else {
+            None
+        }
+    }
+
+    /// Intersects the ray with an axis-aligned bounding box using the slab method.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The corner of the box with the smallest coordinates.
+    /// * `max` - The corner of the box with the largest coordinates.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(hit)` for the entry point of the ray into the box (or the origin itself, with
+    ///   `t = 0`, if the ray starts inside), or `None` if the ray misses.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{ray::Ray, Vec3};
+    ///
+    /// let ray = Ray::new(Vec3::new(-3.0, 0.5, 0.5), Vec3::new(1.0, 0.0, 0.0));
+    /// let hit = ray.intersect_aabb(Vec3::ZERO, Vec3::new(1.0, 1.0, 1.0)).unwrap();
+    /// assert_eq!(hit.t, 3.0);
+    /// assert_eq!(hit.point, Vec3::new(0.0, 0.5, 0.5));
+    /// ```
+    pub fn intersect_aabb(&self, min: Vec3, max: Vec3) -> Option<RayHit> {
+        let origin = [self.origin.x, self.origin.y, self.origin.z];
+        let direction = [self.direction.x, self.direction.y, self.direction.z];
+        let lower = [min.x, min.y, min.z];
+        let upper = [max.x, max.y, max.z];
+
+        let mut t_enter = 0.0_f64;
+        let mut t_exit = f64::INFINITY;
+        for axis in 0..3 {
+            if direction[axis] == 0.0 {
+                // Parallel to this slab: the origin must already lie between its planes.
+                if origin[axis] < lower[axis] || origin[axis] > upper[axis] {
+                    return None;
+                }
+                continue;
+            }
+            let inv = 1.0 / direction[axis];
+            let mut t0 = (lower[axis] - origin[axis]) * inv;
+            let mut t1 = (upper[axis] - origin[axis]) * inv;
+            if t0 > t1 {
+                std::mem::swap(&mut t0, &mut t1);
+            }
+            t_enter = t_enter.max(t0);
+            t_exit = t_exit.min(t1);
+            if t_enter > t_exit {
+                return None;
+            }
+        }
+        Some(self.hit(t_enter))
+    }
+}
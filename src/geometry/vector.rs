@@ -0,0 +1,400 @@
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// A two-dimensional vector (or point) with `f64` components.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::Vec2;
+///
+/// let a = Vec2::new(3.0, 4.0);
+/// let b = Vec2::new(1.0, 0.0);
+///
+/// assert_eq!(a.norm(), 5.0);
+/// assert_eq!(a.dot(b), 3.0);
+/// assert_eq!(a + b, Vec2::new(4.0, 4.0));
+/// assert_eq!(2.0 * b, Vec2::new(2.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A three-dimensional vector (or point) with `f64` components.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::Vec3;
+///
+/// let x = Vec3::new(1.0, 0.0, 0.0);
+/// let y = Vec3::new(0.0, 1.0, 0.0);
+///
+/// assert_eq!(x.cross(y), Vec3::new(0.0, 0.0, 1.0));
+/// assert_eq!(x.dot(y), 0.0);
+/// assert_eq!((x - y).norm_squared(), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Vec2 {
+    /// The zero vector `(0, 0)`.
+    pub const ZERO: Vec2 = Vec2 { x: 0.0, y: 0.0 };
+
+    /// Creates a new vector from its components.
+    pub const fn new(x: f64, y: f64) -> Self {
+        Vec2 { x, y }
+    }
+
+    /// Computes the dot (scalar) product `self · other`.
+    pub fn dot(self, other: Vec2) -> f64 {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Computes the two-dimensional cross product `self.x * other.y - self.y * other.x`.
+    ///
+    /// This is the `z` component of the 3D cross product of the two vectors embedded in the
+    /// `xy`-plane. It is positive when `other` lies counter-clockwise from `self`, negative when it
+    /// lies clockwise, and zero when the vectors are parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::Vec2;
+    ///
+    /// let x = Vec2::new(1.0, 0.0);
+    /// let y = Vec2::new(0.0, 1.0);
+    /// assert_eq!(x.cross(y), 1.0);
+    /// assert_eq!(y.cross(x), -1.0);
+    /// ```
+    pub fn cross(self, other: Vec2) -> f64 {
+        self.x * other.y - self.y * other.x
+    }
+
+    /// Returns the squared Euclidean length of the vector. Cheaper than [`Vec2::norm`] when only
+    /// comparisons are needed.
+    pub fn norm_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the Euclidean length of the vector.
+    pub fn norm(self) -> f64 {
+        self.x.hypot(self.y)
+    }
+
+    /// Returns a unit vector pointing in the same direction.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(unit)` for a non-zero vector, or `None` for the zero vector, which has no direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::Vec2;
+    ///
+    /// assert_eq!(Vec2::new(0.0, 5.0).normalize(), Some(Vec2::new(0.0, 1.0)));
+    /// assert_eq!(Vec2::ZERO.normalize(), None);
+    /// ```
+    pub fn normalize(self) -> Option<Vec2> {
+        let norm = self.norm();
+        if norm == 0.0 || !norm.is_finite() {
+            None
+        } else {
+            Some(self / norm)
+        }
+    }
+
+    /// Returns the Euclidean distance between two points.
+    pub fn distance(self, other: Vec2) -> f64 {
+        (self - other).norm()
+    }
+
+    /// Returns the vector rotated by 90 degrees counter-clockwise, `(-y, x)`.
+    pub fn perp(self) -> Vec2 {
+        Vec2::new(-self.y, self.x)
+    }
+}
+
+impl Vec3 {
+    /// The zero vector `(0, 0, 0)`.
+    pub const ZERO: Vec3 = Vec3 { x: 0.0, y: 0.0, z: 0.0 };
+
+    /// Creates a new vector from its components.
+    pub const fn new(x: f64, y: f64, z: f64) -> Self {
+        Vec3 { x, y, z }
+    }
+
+    /// Computes the dot (scalar) product `self · other`.
+    pub fn dot(self, other: Vec3) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// Computes the cross product `self × other`.
+    ///
+    /// The result is perpendicular to both inputs, follows the right-hand rule, and has a length
+    /// equal to the area of the parallelogram spanned by the two vectors.
+    pub fn cross(self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x,
+        )
+    }
+
+    /// Returns the squared Euclidean length of the vector. Cheaper than [`Vec3::norm`] when only
+    /// comparisons are needed.
+    pub fn norm_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the Euclidean length of the vector.
+    pub fn norm(self) -> f64 {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns a unit vector pointing in the same direction.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(unit)` for a non-zero vector, or `None` for the zero vector, which has no direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::Vec3;
+    ///
+    /// assert_eq!(Vec3::new(0.0, 0.0, -2.0).normalize(), Some(Vec3::new(0.0, 0.0, -1.0)));
+    /// assert_eq!(Vec3::ZERO.normalize(), None);
+    /// ```
+    pub fn normalize(self) -> Option<Vec3> {
+        let norm = self.norm();
+        if norm == 0.0 || !norm.is_finite() {
+            None
+        } else {
+            Some(self / norm)
+        }
+    }
+
+    /// Returns the Euclidean distance between two points.
+    pub fn distance(self, other: Vec3) -> f64 {
+        (self - other).norm()
+    }
+}
+
+/// Operations shared by [`Vec2`] and [`Vec3`], allowing geometric routines to be written once for
+/// both dimensions.
+///
+/// # Examples
+///
+/// ```
+/// use hell::geometry::{Vec2, Vec3, Vector};
+///
+/// fn midpoint<V: Vector>(a: V, b: V) -> V {
+///     a + (b - a) * 0.5
+/// }
+///
+/// assert_eq!(midpoint(Vec2::new(0.0, 0.0), Vec2::new(2.0, 4.0)), Vec2::new(1.0, 2.0));
+/// assert_eq!(midpoint(Vec3::ZERO, Vec3::new(2.0, 2.0, 2.0)), Vec3::new(1.0, 1.0, 1.0));
+/// ```
+pub trait Vector:
+    Copy + PartialEq + Add<Output = Self> + Sub<Output = Self> + Neg<Output = Self> + Mul<f64, Output = Self>
+{
+    /// Computes the dot (scalar) product `self · other`.
+    fn dot(self, other: Self) -> f64;
+
+    /// Returns the squared Euclidean length of the vector.
+    fn norm_squared(self) -> f64 {
+        self.dot(self)
+    }
+
+    /// Returns the Euclidean length of the vector.
+    fn norm(self) -> f64 {
+        self.norm_squared().sqrt()
+    }
+
+    /// Returns the signed length of the projection of `self` onto the direction of `onto`.
+    ///
+    /// The result is `|self| cos θ`, where `θ` is the angle between the vectors. It is negative
+    /// when the vectors point in opposing directions, and `0` if `onto` is the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{Vec2, Vector};
+    ///
+    /// let v = Vec2::new(3.0, 4.0);
+    /// assert_eq!(v.scalar_projection(Vec2::new(2.0, 0.0)), 3.0);
+    /// assert_eq!(v.scalar_projection(Vec2::new(0.0, -1.0)), -4.0);
+    /// ```
+    fn scalar_projection(self, onto: Self) -> f64 {
+        let norm = onto.norm();
+        if norm == 0.0 {
+            0.0
+        } else {
+            self.dot(onto) / norm
+        }
+    }
+
+    /// Returns the vector projection of `self` onto `onto`: the component of `self` that is
+    /// parallel to `onto`.
+    ///
+    /// Projecting onto the zero vector yields the zero vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{Vec3, Vector};
+    ///
+    /// let v = Vec3::new(2.0, 3.0, 4.0);
+    /// let onto = Vec3::new(0.0, 5.0, 0.0);
+    /// assert_eq!(v.project(onto), Vec3::new(0.0, 3.0, 0.0));
+    ///
+    /// // What remains after removing the projection is perpendicular to `onto`.
+    /// assert_eq!((v - v.project(onto)).dot(onto), 0.0);
+    /// ```
+    fn project(self, onto: Self) -> Self {
+        let len_sq = onto.norm_squared();
+        if len_sq == 0.0 {
+            onto
+        } else {
+            onto * (self.dot(onto) / len_sq)
+        }
+    }
+
+    /// Reflects `self` across the line (2D) or plane (3D) whose normal is `normal`.
+    ///
+    /// This is the classic "bounce" used in physics and ray tracing: the component of `self`
+    /// along `normal` is reversed while the tangential component is kept. The normal does not
+    /// need to be normalised. Reflecting across the zero vector leaves `self` unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::geometry::{Vec2, Vector};
+    ///
+    /// // A ball moving down and to the right bounces off the floor.
+    /// let velocity = Vec2::new(1.0, -2.0);
+    /// let floor_normal = Vec2::new(0.0, 1.0);
+    /// assert_eq!(velocity.reflect(floor_normal), Vec2::new(1.0, 2.0));
+    /// ```
+    fn reflect(self, normal: Self) -> Self {
+        self - self.project(normal) * 2.0
+    }
+}
+
+impl Vector for Vec2 {
+    fn dot(self, other: Vec2) -> f64 {
+        Vec2::dot(self, other)
+    }
+}
+
+impl Vector for Vec3 {
+    fn dot(self, other: Vec3) -> f64 {
+        Vec3::dot(self, other)
+    }
+}
+
+macro_rules! impl_vector_ops {
+    ($name:ident { $($field:ident),+ }) => {
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name { $($field: -self.$field),+ }
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, scalar: f64) -> $name {
+                $name { $($field: self.$field * scalar),+ }
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+
+            fn mul(self, vector: $name) -> $name {
+                vector * self
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = $name;
+
+            fn div(self, scalar: f64) -> $name {
+                $name { $($field: self.$field / scalar),+ }
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                $(self.$field += other.$field;)+
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                $(self.$field -= other.$field;)+
+            }
+        }
+
+        impl MulAssign<f64> for $name {
+            fn mul_assign(&mut self, scalar: f64) {
+                $(self.$field *= scalar;)+
+            }
+        }
+    };
+}
+
+impl_vector_ops!(Vec2 { x, y });
+impl_vector_ops!(Vec3 { x, y, z });
+
+impl From<(f64, f64)> for Vec2 {
+    fn from((x, y): (f64, f64)) -> Self {
+        Vec2::new(x, y)
+    }
+}
+
+impl From<Vec2> for (f64, f64) {
+    fn from(v: Vec2) -> Self {
+        (v.x, v.y)
+    }
+}
+
+impl From<(f64, f64, f64)> for Vec3 {
+    fn from((x, y, z): (f64, f64, f64)) -> Self {
+        Vec3::new(x, y, z)
+    }
+}
+
+impl From<Vec3> for (f64, f64, f64) {
+    fn from(v: Vec3) -> Self {
+        (v.x, v.y, v.z)
+    }
+}
@@ -0,0 +1,279 @@
+//! Graph algorithms on weighted adjacency matrices.
+//!
+//! A directed graph with `n` vertices is represented by an `n × n` [`Matrix`] whose entry `(i, j)`
+//! is the weight of the edge from vertex `i` to vertex `j`. A missing edge is marked with
+//! `f64::INFINITY`; every finite entry, including zero, is an edge. An undirected graph is simply a
+//! symmetric matrix.
+//!
+//! # Examples
+//!
+//! ```
+//! use hell::graph::{floyd_warshall, NO_EDGE};
+//! use hell::Matrix;
+//!
+//! let graph = Matrix::new(3, 3, vec![
+//!     vec![0.0, 4.0, 1.0],
+//!     vec![NO_EDGE, 0.0, NO_EDGE],
+//!     vec![NO_EDGE, 2.0, 0.0],
+//! ]);
+//! let distances = floyd_warshall(&graph).unwrap().to_rows();
+//! assert_eq!(distances[0][1], 3.0); // 0 → 2 → 1 beats the direct edge.
+//! assert_eq!(distances[1][0], NO_EDGE);
+//! ```
+
+use crate::error::HellError;
+use crate::matrix::Matrix;
+
+/// The entry that marks a missing edge in an adjacency matrix.
+pub const NO_EDGE: f64 = f64::INFINITY;
+
+/// Returns the rows of `graph`, or an error if it is not square.
+fn adjacency_rows(graph: &Matrix) -> Result<Vec<Vec<f64>>, HellError> {
+    let (rows, cols) = graph.shape();
+    if rows != cols {
+        return Err(HellError::DimensionMismatch("Adjacency matrix must be square."));
+    }
+    Ok(graph.to_rows())
+}
+
+/// Computes the shortest path length between every pair of vertices with the Floyd–Warshall
+/// algorithm.
+///
+/// Negative edge weights are allowed as long as the graph has no negative cycle.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted adjacency matrix.
+///
+/// # Returns
+///
+/// * `Ok(distances)`, where entry `(i, j)` is the length of the shortest path from `i` to `j`, or
+///   [`NO_EDGE`] if `j` is unreachable from `i`. The diagonal is zero.
+/// * `Err(HellError::DimensionMismatch)` if `graph` is not square.
+/// * `Err(HellError::DomainError)` if the graph contains a negative cycle, so that some shortest
+///   paths are undefined.
+///
+/// # Examples
+///
+/// ```
+/// use hell::graph::{floyd_warshall, NO_EDGE};
+/// use hell::Matrix;
+///
+/// let graph = Matrix::new(2, 2, vec![vec![0.0, 1.0], vec![-2.0, 0.0]]);
+/// assert!(floyd_warshall(&graph).is_err()); // 0 → 1 → 0 has length −1.
+///
+/// let graph = Matrix::new(2, 2, vec![vec![0.0, 5.0], vec![NO_EDGE, 0.0]]);
+/// assert_eq!(floyd_warshall(&graph).unwrap().to_rows(), vec![vec![0.0, 5.0], vec![NO_EDGE, 0.0]]);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n³)` time and `O(n²)` space.
+pub fn floyd_warshall(graph: &Matrix) -> Result<Matrix, HellError> {
+    let mut dist = adjacency_rows(graph)?;
+    let n = dist.len();
+    for (i, row) in dist.iter_mut().enumerate() {
+        row[i] = row[i].min(0.0);
+    }
+    for k in 0..n {
+        let through = dist[k].clone();
+        for row in dist.iter_mut() {
+            let to_k = row[k];
+            if to_k == NO_EDGE {
+                continue;
+            }
+            for (entry, &from_k) in row.iter_mut().zip(&through) {
+                let candidate = to_k + from_k;
+                if candidate < *entry {
+                    *entry = candidate;
+                }
+            }
+        }
+    }
+    if (0..n).any(|i| dist[i][i] < 0.0) {
+        return Err(HellError::DomainError("Graph contains a negative cycle."));
+    }
+    Ok(Matrix::new(n, n, dist))
+}
+
+/// Computes the transitive closure of a graph with Warshall's algorithm.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted adjacency matrix. Only the presence of edges matters, not their weights.
+///
+/// # Returns
+///
+/// * `Ok(reach)`, where entry `(i, j)` is `1.0` if there is a path of one or more edges from `i` to
+///   `j`, and `0.0` otherwise. A diagonal entry is `1.0` only if its vertex lies on a cycle.
+/// * `Err(HellError::DimensionMismatch)` if `graph` is not square.
+///
+/// # Examples
+///
+/// ```
+/// use hell::graph::{transitive_closure, NO_EDGE};
+/// use hell::Matrix;
+///
+/// let graph = Matrix::new(3, 3, vec![
+///     vec![NO_EDGE, 1.0, NO_EDGE],
+///     vec![NO_EDGE, NO_EDGE, 1.0],
+///     vec![NO_EDGE, NO_EDGE, NO_EDGE],
+/// ]);
+/// assert_eq!(transitive_closure(&graph).unwrap().to_rows(), vec![
+///     vec![0.0, 1.0, 1.0],
+///     vec![0.0, 0.0, 1.0],
+///     vec![0.0, 0.0, 0.0],
+/// ]);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n³)` time.
+pub fn transitive_closure(graph: &Matrix) -> Result<Matrix, HellError> {
+    let rows = adjacency_rows(graph)?;
+    let n = rows.len();
+    let mut reach: Vec<Vec<bool>> = rows.iter().map(|row| row.iter().map(|w| w.is_finite()).collect()).collect();
+    for k in 0..n {
+        let through = reach[k].clone();
+        for row in reach.iter_mut() {
+            if row[k] {
+                for (entry, &from_k) in row.iter_mut().zip(&through) {
+                    *entry |= from_k;
+                }
+            }
+        }
+    }
+    let data = reach.iter().map(|row| row.iter().map(|&r| if r { 1.0 } else { 0.0 }).collect()).collect();
+    Ok(Matrix::new(n, n, data))
+}
+
+/// Labels the weakly connected components of a graph.
+///
+/// Edge directions are ignored, so two vertices share a component if they are joined by a path
+/// in the underlying undirected graph.
+///
+/// # Arguments
+///
+/// * `graph` - The weighted adjacency matrix.
+///
+/// # Returns
+///
+/// * `Ok(labels)`, where `labels[v]` is the component of vertex `v`. Components are numbered from
+///   zero in order of their lowest vertex.
+/// * `Err(HellError::DimensionMismatch)` if `graph` is not square.
+///
+/// # Examples
+///
+/// ```
+/// use hell::graph::{connected_components, NO_EDGE};
+/// use hell::Matrix;
+///
+/// let graph = Matrix::new(4, 4, vec![
+///     vec![NO_EDGE, NO_EDGE, 1.0, NO_EDGE],
+///     vec![NO_EDGE, NO_EDGE, NO_EDGE, NO_EDGE],
+///     vec![NO_EDGE, NO_EDGE, NO_EDGE, NO_EDGE],
+///     vec![NO_EDGE, 1.0, NO_EDGE, NO_EDGE],
+/// ]);
+/// assert_eq!(connected_components(&graph).unwrap(), vec![0, 1, 0, 1]);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n²)` time.
+pub fn connected_components(graph: &Matrix) -> Result<Vec<usize>, HellError> {
+    let rows = adjacency_rows(graph)?;
+    let n = rows.len();
+    let mut labels = vec![usize::MAX; n];
+    let mut count = 0;
+    for start in 0..n {
+        if labels[start] != usize::MAX {
+            continue;
+        }
+        labels[start] = count;
+        let mut stack = vec![start];
+        while let Some(v) = stack.pop() {
+            for w in 0..n {
+                if labels[w] == usize::MAX && (rows[v][w].is_finite() || rows[w][v].is_finite()) {
+                    labels[w] = count;
+                    stack.push(w);
+                }
+            }
+        }
+        count += 1;
+    }
+    Ok(labels)
+}
+
+/// Returns whether a graph is weakly connected, i.e. connected when edge directions are ignored.
+///
+/// A graph with no vertices is considered connected.
+///
+/// # Returns
+///
+/// * `Ok(connected)` on success.
+/// * `Err(HellError::DimensionMismatch)` if `graph` is not square.
+///
+/// # Examples
+///
+/// ```
+/// use hell::graph::{is_connected, NO_EDGE};
+/// use hell::Matrix;
+///
+/// let path = Matrix::new(2, 2, vec![vec![NO_EDGE, 1.0], vec![NO_EDGE, NO_EDGE]]);
+/// assert!(is_connected(&path).unwrap());
+///
+/// let isolated = Matrix::new(2, 2, vec![vec![NO_EDGE; 2]; 2]);
+/// assert!(!is_connected(&isolated).unwrap());
+/// ```
+pub fn is_connected(graph: &Matrix) -> Result<bool, HellError> {
+    Ok(connected_components(graph)?.iter().all(|&label| label == 0))
+}
+
+/// Returns whether a graph is strongly connected, i.e. every vertex can reach every other vertex
+/// along directed edges.
+///
+/// A graph with no vertices is considered strongly connected.
+///
+/// # Returns
+///
+/// * `Ok(connected)` on success.
+/// * `Err(HellError::DimensionMismatch)` if `graph` is not square.
+///
+/// # Examples
+///
+/// ```
+/// use hell::graph::{is_strongly_connected, NO_EDGE};
+/// use hell::Matrix;
+///
+/// let path = Matrix::new(2, 2, vec![vec![NO_EDGE, 1.0], vec![NO_EDGE, NO_EDGE]]);
+/// assert!(!is_strongly_connected(&path).unwrap());
+///
+/// let cycle = Matrix::new(2, 2, vec![vec![NO_EDGE, 1.0], vec![1.0, NO_EDGE]]);
+/// assert!(is_strongly_connected(&cycle).unwrap());
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n²)` time, using one search along the edges and one against them.
+pub fn is_strongly_connected(graph: &Matrix) -> Result<bool, HellError> {
+    let rows = adjacency_rows(graph)?;
+    let n = rows.len();
+    if n == 0 {
+        return Ok(true);
+    }
+    let reaches_all = |edge: &dyn Fn(usize, usize) -> bool| {
+        let mut seen = vec![false; n];
+        seen[0] = true;
+        let mut stack = vec![0];
+        while let Some(v) = stack.pop() {
+            for (w, seen_w) in seen.iter_mut().enumerate() {
+                if !*seen_w && edge(v, w) {
+                    *seen_w = true;
+                    stack.push(w);
+                }
+            }
+        }
+        seen.iter().all(|&s| s)
+    };
+    Ok(reaches_all(&|v, w| rows[v][w].is_finite()) && reaches_all(&|v, w| rows[w][v].is_finite()))
+}
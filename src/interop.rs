@@ -0,0 +1,75 @@
+//! Conversions between [`Matrix`] and the matrix types of the `nalgebra` and `ndarray` crates,
+//! enabled by the features of the same names.
+//!
+//! Each conversion preserves the shape and the element at every `(row, column)` index. `Matrix`
+//! and `ndarray::Array2` both store their elements in row-major order, so converting a `Matrix`
+//! into an `Array2` moves the buffer without copying; `nalgebra::DMatrix` is column-major, so
+//! conversions to and from it reorder the elements.
+
+use crate::matrix::{Matrix, Scalar};
+
+/// Converts a [`Matrix`] into a `nalgebra::DMatrix` with the same shape and elements.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "nalgebra")] {
+/// use hell::Matrix;
+/// use nalgebra::DMatrix;
+///
+/// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+/// let converted: DMatrix<f64> = matrix.clone().into();
+/// assert_eq!(converted.shape(), (2, 3));
+/// assert_eq!(converted[(1, 0)], 4.0);
+/// assert_eq!(Matrix::from(converted), matrix);
+/// # }
+/// ```
+#[cfg(feature = "nalgebra")]
+impl<T: Scalar + nalgebra::Scalar> From<Matrix<T>> for nalgebra::DMatrix<T> {
+    fn from(matrix: Matrix<T>) -> Self {
+        let (rows, cols) = matrix.shape();
+        nalgebra::DMatrix::from_row_iterator(rows, cols, matrix.iter().copied())
+    }
+}
+
+/// Converts a `nalgebra::DMatrix` into a [`Matrix`] with the same shape and elements.
+#[cfg(feature = "nalgebra")]
+impl<T: Scalar + nalgebra::Scalar> From<nalgebra::DMatrix<T>> for Matrix<T> {
+    fn from(matrix: nalgebra::DMatrix<T>) -> Self {
+        Matrix::from_fn(matrix.nrows(), matrix.ncols(), |i, j| matrix[(i, j)])
+    }
+}
+
+/// Converts a [`Matrix`] into an `ndarray::Array2` with the same shape and elements, moving the
+/// buffer without copying.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "ndarray")] {
+/// use hell::Matrix;
+/// use ndarray::{array, Array2};
+///
+/// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+/// let converted: Array2<f64> = matrix.clone().into();
+/// assert_eq!(converted, array![[1.0, 2.0], [3.0, 4.0]]);
+///
+/// // Any memory layout converts back, including transposed views turned into owned arrays.
+/// assert_eq!(Matrix::from(converted.t().to_owned()), matrix.transpose());
+/// # }
+/// ```
+#[cfg(feature = "ndarray")]
+impl<T: Scalar> From<Matrix<T>> for ndarray::Array2<T> {
+    fn from(matrix: Matrix<T>) -> Self {
+        let shape = matrix.shape();
+        ndarray::Array2::from_shape_vec(shape, matrix.into_data()).expect("Matrix data length matches its shape.")
+    }
+}
+
+/// Converts an `ndarray::Array2` into a [`Matrix`] with the same shape and elements, in whatever
+/// memory layout the array uses.
+#[cfg(feature = "ndarray")]
+impl<T: Scalar> From<ndarray::Array2<T>> for Matrix<T> {
+    fn from(array: ndarray::Array2<T>) -> Self {
+        let (rows, cols) = array.dim();
+        Matrix::from_flat(rows, cols, array.iter().copied().collect())
+    }
+}
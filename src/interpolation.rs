@@ -0,0 +1,341 @@
+use crate::matrix::Matrix;
+
+/// Linearly interpolates between `a` and `b`.
+///
+/// # Arguments
+///
+/// * `a` - The value returned when `t = 0`.
+/// * `b` - The value returned when `t = 1`.
+/// * `t` - The interpolation parameter. Values outside `[0, 1]` extrapolate along the same line.
+///
+/// # Returns
+///
+/// * `a + (b - a) * t`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::lerp;
+///
+/// assert_eq!(lerp(10.0, 20.0, 0.25), 12.5);
+/// assert_eq!(lerp(10.0, 20.0, 1.5), 25.0); // Extrapolation.
+/// ```
+pub fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Linearly interpolates between `a` and `b`, clamping `t` to `[0, 1]` so the result never
+/// leaves the segment between the two values.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::lerp_clamped;
+///
+/// assert_eq!(lerp_clamped(10.0, 20.0, 0.5), 15.0);
+/// assert_eq!(lerp_clamped(10.0, 20.0, 1.5), 20.0);
+/// assert_eq!(lerp_clamped(10.0, 20.0, -3.0), 10.0);
+/// ```
+pub fn lerp_clamped(a: f64, b: f64, t: f64) -> f64 {
+    lerp(a, b, t.clamp(0.0, 1.0))
+}
+
+/// Computes the interpolation parameter `t` for which `lerp(a, b, t) == value`.
+///
+/// # Arguments
+///
+/// * `a` - The start of the range.
+/// * `b` - The end of the range.
+/// * `value` - The value to locate within the range.
+///
+/// # Returns
+///
+/// * `(value - a) / (b - a)`. Values outside the range give `t` outside `[0, 1]`. If `a == b` the
+///   range is empty and the result is `NaN` or infinite.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::inverse_lerp;
+///
+/// assert_eq!(inverse_lerp(10.0, 20.0, 12.5), 0.25);
+/// assert_eq!(inverse_lerp(10.0, 20.0, 30.0), 2.0);
+/// ```
+pub fn inverse_lerp(a: f64, b: f64, value: f64) -> f64 {
+    (value - a) / (b - a)
+}
+
+/// Computes the interpolation parameter for `value` within `[a, b]`, clamped to `[0, 1]`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::inverse_lerp_clamped;
+///
+/// assert_eq!(inverse_lerp_clamped(10.0, 20.0, 15.0), 0.5);
+/// assert_eq!(inverse_lerp_clamped(10.0, 20.0, 30.0), 1.0);
+/// ```
+pub fn inverse_lerp_clamped(a: f64, b: f64, value: f64) -> f64 {
+    inverse_lerp(a, b, value).clamp(0.0, 1.0)
+}
+
+/// Maps `value` from one range onto another, preserving its relative position.
+///
+/// # Arguments
+///
+/// * `value` - The value to remap.
+/// * `from_range` - The source range `(start, end)`.
+/// * `to_range` - The target range `(start, end)`.
+///
+/// # Returns
+///
+/// * The value at the same relative position within `to_range` as `value` has within
+///   `from_range`. Values outside the source range are extrapolated.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::remap;
+///
+/// // Celsius to Fahrenheit.
+/// assert_eq!(remap(25.0, (0.0, 100.0), (32.0, 212.0)), 77.0);
+/// // Ranges may run backwards.
+/// assert_eq!(remap(0.25, (0.0, 1.0), (100.0, 0.0)), 75.0);
+/// ```
+pub fn remap(value: f64, from_range: (f64, f64), to_range: (f64, f64)) -> f64 {
+    lerp(to_range.0, to_range.1, inverse_lerp(from_range.0, from_range.1, value))
+}
+
+/// Maps `value` from one range onto another, clamping the result to the target range.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::remap_clamped;
+///
+/// // An 8-bit sensor reading mapped to a percentage.
+/// assert_eq!(remap_clamped(300.0, (0.0, 255.0), (0.0, 100.0)), 100.0);
+/// assert_eq!(remap_clamped(51.0, (0.0, 255.0), (0.0, 100.0)), 20.0);
+/// ```
+pub fn remap_clamped(value: f64, from_range: (f64, f64), to_range: (f64, f64)) -> f64 {
+    lerp_clamped(to_range.0, to_range.1, inverse_lerp(from_range.0, from_range.1, value))
+}
+
+/// Evaluates the Lagrange interpolating polynomial through the given samples at `x`.
+///
+/// For `n` samples `(x_i, y_i)` with distinct `x_i`, there is exactly one polynomial of degree at
+/// most `n - 1` passing through all of them. This function evaluates that polynomial directly in
+/// Lagrange form:
+///
+/// \[
+/// P(x) = \sum_i y_i \prod_{j \ne i} \frac{x - x_j}{x_i - x_j}
+/// \]
+///
+/// # Arguments
+///
+/// * `points` - The samples `(x, y)`, in any order. The `x` values must be distinct.
+/// * `x` - The point at which to evaluate the interpolating polynomial.
+///
+/// # Returns
+///
+/// * `Some(value)` with the interpolated (or extrapolated) value, or `None` if `points` is empty
+///   or contains two samples with the same `x`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::lagrange;
+///
+/// // Three samples of y = x^2 determine it exactly.
+/// let points = [(0.0, 0.0), (1.0, 1.0), (3.0, 9.0)];
+/// assert_eq!(lagrange(&points, 2.0), Some(4.0));
+///
+/// assert_eq!(lagrange(&[(1.0, 2.0), (1.0, 3.0)], 0.0), None); // Duplicate x.
+/// ```
+///
+/// # Performance
+///
+/// Each evaluation costs `O(n^2)`. When evaluating the same samples at many points, the Newton
+/// form in [`NewtonInterpolator`] is considerably cheaper.
+///
+/// # Limitations
+///
+/// High-degree interpolation through equally spaced samples oscillates wildly near the ends of
+/// the interval (Runge's phenomenon). Prefer piecewise or least-squares methods for large `n`.
+pub fn lagrange(points: &[(f64, f64)], x: f64) -> Option<f64> {
+    if points.is_empty() {
+        return None;
+    }
+    let mut sum = 0.0;
+    for (i, &(xi, yi)) in points.iter().enumerate() {
+        let mut basis = 1.0;
+        for (j, &(xj, _)) in points.iter().enumerate() {
+            if i == j {
+                continue;
+            }
+            if xi == xj {
+                return None;
+            }
+            basis *= (x - xj) / (xi - xj);
+        }
+        sum += yi * basis;
+    }
+    Some(sum)
+}
+
+/// The Newton (divided-difference) form of the interpolating polynomial through a set of samples.
+///
+/// Building the interpolator computes the divided-difference table once in `O(n^2)`. After that,
+/// each evaluation is a nested (Horner-style) multiplication costing only `O(n)`, which makes it
+/// far cheaper than [`lagrange`] when the same samples are queried many times, as in table lookups.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::NewtonInterpolator;
+///
+/// // Samples of y = x^3 - 2x.
+/// let points = [(-1.0, 1.0), (0.0, 0.0), (1.0, -1.0), (2.0, 4.0)];
+/// let newton = NewtonInterpolator::new(&points).unwrap();
+///
+/// assert_eq!(newton.evaluate(3.0), 21.0);
+/// assert_eq!(newton.evaluate(0.5), -0.875);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewtonInterpolator {
+    nodes: Vec<f64>,
+    coefficients: Vec<f64>,
+}
+
+impl NewtonInterpolator {
+    /// Builds the divided-difference table for the given samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `points` - The samples `(x, y)`, in any order. The `x` values must be distinct.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(interpolator)`, or `None` if `points` is empty or contains duplicate `x` values.
+    pub fn new(points: &[(f64, f64)]) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        let nodes: Vec<f64> = points.iter().map(|&(x, _)| x).collect();
+        let mut coefficients: Vec<f64> = points.iter().map(|&(_, y)| y).collect();
+        let n = nodes.len();
+
+        // In-place divided differences: after pass `level`, coefficients[i] holds f[x_{i-level}, ..., x_i].
+        for level in 1..n {
+            for i in (level..n).rev() {
+                let dx = nodes[i] - nodes[i - level];
+                if dx == 0.0 {
+                    return None;
+                }
+                coefficients[i] = (coefficients[i] - coefficients[i - 1]) / dx;
+            }
+        }
+        Some(NewtonInterpolator { nodes, coefficients })
+    }
+
+    /// Evaluates the interpolating polynomial at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        let n = self.coefficients.len();
+        let mut result = self.coefficients[n - 1];
+        for i in (0..n - 1).rev() {
+            result = result * (x - self.nodes[i]) + self.coefficients[i];
+        }
+        result
+    }
+
+    /// Returns the Newton coefficients `f[x_0], f[x_0, x_1], ..., f[x_0, ..., x_{n-1}]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::interpolation::NewtonInterpolator;
+    ///
+    /// let newton = NewtonInterpolator::new(&[(0.0, 1.0), (1.0, 3.0), (2.0, 7.0)]).unwrap();
+    /// assert_eq!(newton.coefficients(), &[1.0, 2.0, 1.0]);
+    /// ```
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+}
+
+/// Samples a grid of values at a fractional position using bilinear interpolation.
+///
+/// The grid is a [`Matrix`] whose element `(row, col)` is the sample at integer coordinates
+/// `x = col`, `y = row`, as for an image or heightmap. The value at a fractional position is a
+/// weighted blend of the four surrounding samples.
+///
+/// # Arguments
+///
+/// * `grid` - The sample grid.
+/// * `x` - The horizontal (column) coordinate, in `[0, cols - 1]`.
+/// * `y` - The vertical (row) coordinate, in `[0, rows - 1]`.
+///
+/// # Returns
+///
+/// * `Some(value)`, or `None` if the position lies outside the grid or is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::bilinear;
+/// use hell::Matrix;
+///
+/// let grid = Matrix::new(2, 2, vec![vec![0.0, 10.0], vec![20.0, 30.0]]);
+/// assert_eq!(bilinear(&grid, 0.5, 0.5), Some(15.0));
+/// assert_eq!(bilinear(&grid, 1.0, 0.25), Some(15.0));
+/// assert_eq!(bilinear(&grid, 1.5, 0.0), None);
+/// ```
+pub fn bilinear(grid: &Matrix, x: f64, y: f64) -> Option<f64> {
+    let (rows, cols) = grid.shape();
+    if !in_grid(x, cols) || !in_grid(y, rows) {
+        return None;
+    }
+    // Pick the lower cell corner so that the far edge still has a neighbour to blend with.
+    let col = (x.floor() as usize).min(cols.saturating_sub(2));
+    let row = (y.floor() as usize).min(rows.saturating_sub(2));
+    let col_next = (col + 1).min(cols - 1);
+    let row_next = (row + 1).min(rows - 1);
+    let (tx, ty) = (x - col as f64, y - row as f64);
+
+    let top = lerp(grid.at(row, col), grid.at(row, col_next), tx);
+    let bottom = lerp(grid.at(row_next, col), grid.at(row_next, col_next), tx);
+    Some(lerp(top, bottom, ty))
+}
+
+/// Samples a grid of values at a fractional position by taking the nearest sample.
+///
+/// Uses the same coordinate convention as [`bilinear`]: `x` indexes columns and `y` indexes rows.
+/// Positions exactly halfway between samples round away from zero.
+///
+/// # Returns
+///
+/// * `Some(value)`, or `None` if the position lies outside the grid or is not finite.
+///
+/// # Examples
+///
+/// ```
+/// use hell::interpolation::nearest;
+/// use hell::Matrix;
+///
+/// let grid = Matrix::new(2, 2, vec![vec![0.0, 10.0], vec![20.0, 30.0]]);
+/// assert_eq!(nearest(&grid, 0.7, 0.2), Some(10.0));
+/// assert_eq!(nearest(&grid, 0.2, 0.9), Some(20.0));
+/// assert_eq!(nearest(&grid, -0.1, 0.0), None);
+/// ```
+pub fn nearest(grid: &Matrix, x: f64, y: f64) -> Option<f64> {
+    let (rows, cols) = grid.shape();
+    if !in_grid(x, cols) || !in_grid(y, rows) {
+        return None;
+    }
+    Some(grid.at(y.round() as usize, x.round() as usize))
+}
+
+/// Returns `true` if `coord` lies within `[0, len - 1]`.
+fn in_grid(coord: f64, len: usize) -> bool {
+    len > 0 && coord >= 0.0 && coord <= (len - 1) as f64
+}
@@ -5,5 +5,37 @@ pub mod algebra;
 pub mod matrix;
 pub mod trigonometry;
 pub mod calculus;
+pub mod autodiff;
+pub mod ode;
 pub mod time;
 pub mod gcd;
+pub mod number_theory;
+pub mod rational;
+pub mod combinatorics;
+pub mod error;
+pub mod tolerance;
+pub mod rng;
+pub mod geometry;
+pub mod complex;
+pub mod fft;
+pub mod signal;
+pub mod finance;
+pub mod roots;
+pub mod optimize;
+pub mod units;
+pub mod graph;
+pub mod statistics;
+pub mod interpolation;
+pub mod polynomial;
+pub mod symbolic;
+pub mod plot;
+mod matrix_io;
+pub mod sparse;
+#[cfg(any(feature = "nalgebra", feature = "ndarray"))]
+mod interop;
+#[cfg(feature = "simd")]
+mod simd;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+
+pub use matrix::Matrix;
@@ -1,6 +1,126 @@
-use hell::algebra::log10;
+use std::env;
+use std::fs;
+use std::process::ExitCode;
 
-fn main() {
-    let main = log10(15.0);
-    println!("{}", main);
+use hell::calculus::integral;
+use hell::error::HellError;
+use hell::gcd::gcd;
+use hell::quadratic::solve_quadratic;
+use hell::symbolic::Expr;
+use hell::Matrix;
+
+const USAGE: &str = "\
+Usage: hell <command> [arguments]
+
+Commands:
+  gcd <n>...                       Greatest common divisor of the integers
+  solve-quadratic <a> <b> <c>      Real roots of ax^2 + bx + c = 0
+  integrate <expr> <a> <b> [n]     Integral of expr(x) over [a, b] (n subintervals, default 1000)
+  matrix transpose <file.csv>      Transpose of a comma-separated matrix
+  matrix det <file.csv>            Determinant of a comma-separated square matrix
+  help                             Show this message";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    match run(&args) {
+        Ok(output) => {
+            println!("{}", output);
+            ExitCode::SUCCESS
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Dispatches a command line to its subcommand and returns the text to print.
+fn run(args: &[String]) -> Result<String, String> {
+    let (command, rest) = args.split_first().ok_or("no command given")?;
+    match command.as_str() {
+        "gcd" => cmd_gcd(rest),
+        "solve-quadratic" => cmd_solve_quadratic(rest),
+        "integrate" => cmd_integrate(rest),
+        "matrix" => cmd_matrix(rest),
+        "help" | "--help" | "-h" => Ok(USAGE.to_string()),
+        other => Err(format!("unknown command '{}'", other)),
+    }
+}
+
+fn cmd_gcd(args: &[String]) -> Result<String, String> {
+    if args.is_empty() {
+        return Err("gcd needs at least one number".to_string());
+    }
+    let numbers = args.iter().map(|a| parse_arg::<u32>(a)).collect::<Result<Vec<_>, _>>()?;
+    Ok(gcd(&numbers).to_string())
+}
+
+fn cmd_solve_quadratic(args: &[String]) -> Result<String, String> {
+    let [a, b, c] = args else {
+        return Err("solve-quadratic needs exactly three coefficients".to_string());
+    };
+    let (a, b, c) = (parse_arg::<f64>(a)?, parse_arg::<f64>(b)?, parse_arg::<f64>(c)?);
+    match solve_quadratic(a, b, c) {
+        Ok((r1, r2)) => Ok(format!("{}\n{}", r1, r2)),
+        Err(HellError::DomainError(_)) if a != 0.0 => Ok("no real roots".to_string()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn cmd_integrate(args: &[String]) -> Result<String, String> {
+    let (expr, a, b, n) = match args {
+        [expr, a, b] => (expr, a, b, 1000),
+        [expr, a, b, n] => (expr, a, b, parse_arg::<usize>(n)?),
+        _ => return Err("integrate needs an expression and two bounds".to_string()),
+    };
+    let compiled = Expr::parse(expr).map_err(|e| e.to_string())?.compile();
+    match compiled.variables() {
+        [] => {}
+        [var] if var == "x" => {}
+        _ => return Err("the expression may only use the variable x".to_string()),
+    }
+    let (a, b) = (parse_arg::<f64>(a)?, parse_arg::<f64>(b)?);
+    Ok(integral(|x| compiled.eval(&[x]), a, b, n).to_string())
+}
+
+fn cmd_matrix(args: &[String]) -> Result<String, String> {
+    let [operation, path] = args else {
+        return Err("matrix needs an operation and a file".to_string());
+    };
+    let matrix = read_csv_matrix(path)?;
+    match operation.as_str() {
+        "transpose" => Ok(format_matrix(&matrix.transpose())),
+        "det" => matrix.determinant().map(|det| det.to_string()).map_err(|e| e.to_string()),
+        other => Err(format!("unknown matrix operation '{}'", other)),
+    }
+}
+
+/// Reads a matrix from a CSV file with one row per line. Blank lines are ignored.
+fn read_csv_matrix(path: &str) -> Result<Matrix, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("cannot read '{}': {}", path, e))?;
+    let data = text
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.split(',').map(|v| parse_arg::<f64>(v.trim())).collect::<Result<Vec<_>, _>>())
+        .collect::<Result<Vec<_>, _>>()?;
+    let rows = data.len();
+    let cols = data.first().map_or(0, Vec::len);
+    if rows == 0 || data.iter().any(|row| row.len() != cols) {
+        return Err(format!("'{}' is not a rectangular matrix", path));
+    }
+    Ok(Matrix::new(rows, cols, data))
+}
+
+/// Formats a matrix as CSV, one row per line.
+fn format_matrix(matrix: &Matrix) -> String {
+    matrix
+        .to_rows()
+        .iter()
+        .map(|row| row.iter().map(f64::to_string).collect::<Vec<_>>().join(","))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_arg<T: std::str::FromStr>(arg: &str) -> Result<T, String> {
+    arg.parse().map_err(|_| format!("invalid number '{}'", arg))
 }
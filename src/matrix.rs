@@ -1,9 +1,200 @@
-/// Represents a two-dimensional matrix with `f64` elements.
+use crate::complex::Complex;
+use crate::error::HellError;
+use crate::geometry::Vec3;
+use crate::rational::Rational;
+use crate::tolerance::Tolerance;
+use crate::trigonometry::{cosine, sine};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::{Add, Bound, Div, Index, IndexMut, Mul, Neg, Range, RangeBounds, Sub};
+
+/// The tile size, in rows and columns, of the cache-blocked multiplication kernel.
+const MULTIPLY_BLOCK: usize = 128;
+
+/// The number of result rows each rayon task computes in [`Matrix::par_multiply`].
+#[cfg(feature = "parallel")]
+const PAR_MULTIPLY_ROWS: usize = 8;
+
+/// The size above which [`Matrix::multiply`] switches to [`Matrix::multiply_strassen`] for square
+/// `f64` matrices. It is also the block size at which that recursion stops.
+pub const STRASSEN_THRESHOLD: usize = 512;
+
+/// The element types a [`Matrix`] can hold.
+///
+/// A scalar is a `Copy` value with addition, subtraction, multiplication, and the identities
+/// `zero` and `one`. It is implemented for every primitive integer and floating-point type, for
+/// [`Rational`], and for [`Complex`]. Implement it for your own type to store that in a matrix.
+///
+/// # Examples
+/// ```
+/// use hell::matrix::Scalar;
+///
+/// assert_eq!(<i32 as Scalar>::zero(), 0);
+/// assert_eq!(<f32 as Scalar>::one(), 1.0);
+///
+/// let mut y = [1, 2, 3];
+/// i32::axpy(10, &[1, 1, 1], &mut y);
+/// assert_eq!(y, [11, 12, 13]);
+/// assert_eq!(i32::dot(&[1, 2], &[3, 4]), 11);
+/// ```
+pub trait Scalar: Copy + PartialEq + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the multiplicative identity.
+    fn one() -> Self;
+
+    /// Adds `alpha · x[i]` to every `y[i]`, over the common length of the slices.
+    ///
+    /// This is the inner loop of [`Matrix::multiply`]. The provided implementation is a plain
+    /// loop; `f64` replaces it with a vectorized kernel when the `simd` feature is enabled.
+    fn axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+        for (y, &x) in y.iter_mut().zip(x) {
+            *y = *y + alpha * x;
+        }
+    }
+
+    /// Adds every `x[i]` to `y[i]`, over the common length of the slices.
+    ///
+    /// This is the inner loop of [`Matrix::add`], and is vectorized for `f64` like
+    /// [`Scalar::axpy`].
+    fn add_slice(x: &[Self], y: &mut [Self]) {
+        for (y, &x) in y.iter_mut().zip(x) {
+            *y = *y + x;
+        }
+    }
+
+    /// Returns the dot product of `x` and `y`, over the common length of the slices.
+    ///
+    /// This is vectorized for `f64` like [`Scalar::axpy`]. The vectorized version adds the
+    /// products in a different order, so its result can differ in the last bits.
+    fn dot(x: &[Self], y: &[Self]) -> Self {
+        x.iter().zip(y).fold(Self::zero(), |sum, (&a, &b)| sum + a * b)
+    }
+
+    /// Adds the product of two row-major blocks to a third: `c += a · b`, where `a` is `m × k`,
+    /// `b` is `k × n` and `c` is `m × n`, and `lda`, `ldb` and `ldc` are the distances between
+    /// consecutive rows of each block in its slice.
+    ///
+    /// This is the kernel of [`Matrix::multiply`], called on cache-sized tiles of the operands.
+    /// Every element of `c` must add its products in increasing order of `k`, so that the result
+    /// does not depend on the tiling. The provided implementation applies [`Scalar::axpy`] row
+    /// by row; `f64` replaces it with a register-blocked kernel when the `simd` feature is
+    /// enabled.
+    ///
+    /// # Panics
+    /// Panics if a slice is too short for its dimensions and stride.
+    #[allow(clippy::too_many_arguments)]
+    fn gemm(m: usize, k: usize, n: usize, a: &[Self], lda: usize, b: &[Self], ldb: usize, c: &mut [Self], ldc: usize) {
+        for i in 0..m {
+            let c_row = &mut c[i * ldc..i * ldc + n];
+            for (kk, &alpha) in a[i * lda..i * lda + k].iter().enumerate() {
+                Self::axpy(alpha, &b[kk * ldb..kk * ldb + n], c_row);
+            }
+        }
+    }
+
+    /// Multiplies two matrices with an algorithm other than the blocked kernel, or returns
+    /// `None` to use the kernel. The dimensions have already been checked.
+    ///
+    /// [`Matrix::multiply`] tries this first. The provided implementation always returns `None`;
+    /// `f64` switches to [`Matrix::multiply_strassen`] for square matrices larger than
+    /// [`STRASSEN_THRESHOLD`].
+    fn multiply_fast(a: &Matrix<Self>, b: &Matrix<Self>) -> Option<Matrix<Self>> {
+        let _ = (a, b);
+        None
+    }
+}
+
+macro_rules! impl_scalar {
+    ($zero:literal, $one:literal; $($t:ty),+) => {
+        $(
+            impl Scalar for $t {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )+
+    };
+}
+
+impl_scalar!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_scalar!(0.0, 1.0; f32);
+
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn multiply_fast(a: &Matrix, b: &Matrix) -> Option<Matrix> {
+        let n = a.rows;
+        if n <= STRASSEN_THRESHOLD || a.cols != n || b.rows != n || b.cols != n {
+            return None;
+        }
+        a.multiply_strassen(b, STRASSEN_THRESHOLD).ok()
+    }
+
+    #[cfg(feature = "simd")]
+    fn axpy(alpha: Self, x: &[Self], y: &mut [Self]) {
+        crate::simd::axpy(alpha, x, y);
+    }
+
+    #[cfg(feature = "simd")]
+    fn add_slice(x: &[Self], y: &mut [Self]) {
+        crate::simd::add(x, y);
+    }
+
+    #[cfg(feature = "simd")]
+    fn dot(x: &[Self], y: &[Self]) -> Self {
+        crate::simd::dot(x, y)
+    }
+
+    #[cfg(feature = "simd")]
+    fn gemm(m: usize, k: usize, n: usize, a: &[Self], lda: usize, b: &[Self], ldb: usize, c: &mut [Self], ldc: usize) {
+        crate::simd::gemm(m, k, n, a, lda, b, ldb, c, ldc);
+    }
+}
+
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Rational::from(0)
+    }
+
+    fn one() -> Self {
+        Rational::from(1)
+    }
+}
+
+impl Scalar for Complex {
+    fn zero() -> Self {
+        Complex::ZERO
+    }
+
+    fn one() -> Self {
+        Complex::ONE
+    }
+}
+
+/// Represents a two-dimensional matrix, with `f64` elements unless another [`Scalar`] type is
+/// given.
+///
+/// `Matrix` on its own means `Matrix<f64>`. The structural and arithmetic operations (building,
+/// transposing, adding, multiplying, mapping, indexing) work for any scalar type, such as `f32`
+/// for compact storage or integers and [`Rational`] for exact results. The numerical algorithms
+/// (determinant, solvers, decompositions, eigenvalues) are provided for `Matrix<f64>`.
 ///
 /// # Fields
 /// - `rows`: The number of rows in the matrix.
 /// - `cols`: The number of columns in the matrix.
-/// - `data`: A `Vec<Vec<f64>>` holding the matrix elements. The outer `Vec` represents rows, while the inner `Vec` represents columns within each row.
+/// - `data`: A single contiguous `Vec<T>` holding the elements in row-major order, so that element `(i, j)` is stored at index `i * cols + j`.
 ///
 /// # Examples
 ///
@@ -52,20 +243,93 @@
 /// let matrix2 = Matrix::new(2, 2, data2);
 /// let result = matrix1.multiply(&matrix2).unwrap();
 /// ```
+///
+/// Writing expressions with operators and indexing:
+/// ```
+/// use hell::Matrix;
+///
+/// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+/// let b = Matrix::identity(2);
+///
+/// let c = &(&a * &b) - &(2.0 * &b);
+/// assert_eq!(c[(0, 0)], -1.0);
+/// assert_eq!(c[(1, 0)], 3.0);
+///
+/// let mut d = -&c;
+/// d[(1, 1)] = 0.0;
+/// assert_eq!(d.to_rows(), vec![vec![1.0, -2.0], vec![-3.0, 0.0]]);
+/// ```
+///
+/// Using other element types:
+/// ```
+/// use hell::rational::Rational;
+/// use hell::Matrix;
+///
+/// // Exact integer arithmetic.
+/// let fib = Matrix::new(2, 2, vec![vec![1u64, 1], vec![1, 0]]);
+/// let fib4 = &(&fib * &fib) * &(&fib * &fib);
+/// assert_eq!(fib4[(0, 1)], 3);
+///
+/// // Single precision.
+/// let small: Matrix<f32> = Matrix::new(1, 2, vec![vec![0.5, 1.5]]);
+/// assert_eq!(small.transpose().to_rows(), vec![vec![0.5], vec![1.5]]);
+///
+/// // Exact fractions.
+/// let r = |n, d| Rational::new(n, d);
+/// let m = Matrix::new(1, 2, vec![vec![r(1, 2), r(1, 3)]]);
+/// assert_eq!((&m * r(6, 1)).to_rows(), vec![vec![r(3, 1), r(2, 1)]]);
+/// ```
+///
+/// Storing a matrix as JSON (requires the `serde` feature):
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use hell::Matrix;
+///
+/// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+/// let json = serde_json::to_string(&matrix).unwrap();
+/// assert_eq!(json, r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0,4.0]}"#);
+/// assert_eq!(serde_json::from_str::<Matrix>(&json).unwrap(), matrix);
+///
+/// // Inconsistent dimensions are rejected.
+/// assert!(serde_json::from_str::<Matrix>(r#"{"rows":2,"cols":2,"data":[1.0]}"#).is_err());
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct Matrix {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "MatrixFields<T>"))]
+pub struct Matrix<T = f64> {
     rows: usize,
     cols: usize,
-    data: Vec<Vec<f64>>,
+    data: Vec<T>,
 }
 
-impl Matrix {
+/// The serialized form of a [`Matrix`], checked for consistent dimensions on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixFields<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> TryFrom<MatrixFields<T>> for Matrix<T> {
+    type Error = HellError;
+
+    fn try_from(fields: MatrixFields<T>) -> Result<Self, HellError> {
+        if fields.rows.checked_mul(fields.cols) != Some(fields.data.len()) {
+            return Err(HellError::DimensionMismatch("Data length does not match the matrix dimensions."));
+        }
+        Ok(Matrix { rows: fields.rows, cols: fields.cols, data: fields.data })
+    }
+}
+
+impl<T: Scalar> Matrix<T> {
     /// Creates a new `Matrix` instance with the specified number of rows and columns, and initializes it with the given data.
     ///
     /// # Arguments
     /// - `rows`: The number of rows in the matrix.
     /// - `cols`: The number of columns in the matrix.
-    /// - `data`: A `Vec<Vec<f64>>` where each inner `Vec` represents a row of the matrix.
+    /// - `data`: A `Vec<Vec<T>>` where each inner `Vec` represents a row of the matrix.
     ///
     /// # Panics
     /// Panics if `rows` does not match the number of rows in `data`, or if any row in `data` does not have exactly `cols` elements.
@@ -77,31 +341,141 @@ impl Matrix {
     /// let data = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
     /// let matrix = Matrix::new(2, 2, data);
     /// ```
-    pub fn new(rows: usize, cols: usize, data: Vec<Vec<f64>>) -> Self {
+    pub fn new(rows: usize, cols: usize, data: Vec<Vec<T>>) -> Self {
         assert_eq!(rows, data.len(), "Number of rows does not match data length.");
         assert!(data.iter().all(|row| row.len() == cols), "Not all rows have the same number of columns.");
+        Matrix { rows, cols, data: data.concat() }
+    }
+
+    /// Creates a matrix from a `Vec` of rows, taking the dimensions from the data.
+    ///
+    /// This is [`Matrix::new`] without the explicit dimensions. An empty `Vec` gives a `0 × 0`
+    /// matrix.
+    ///
+    /// # Arguments
+    /// - `rows`: The rows of the matrix, which must all have the same length.
+    ///
+    /// # Panics
+    /// Panics if the rows do not all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::from_rows(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix, Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]));
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        Matrix::new(rows.len(), cols, rows)
+    }
+
+    /// Creates a matrix from its elements in row-major order.
+    ///
+    /// # Arguments
+    /// - `rows`: The number of rows.
+    /// - `cols`: The number of columns.
+    /// - `data`: The `rows * cols` elements, listed row by row.
+    ///
+    /// # Panics
+    /// Panics if `data.len()` is not `rows * cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::from_flat(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// ```
+    pub fn from_flat(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        assert_eq!(rows * cols, data.len(), "Data length does not match the matrix dimensions.");
         Matrix { rows, cols, data }
     }
 
-    /// Creates an identity matrix of the given size.
+    /// Creates a matrix by calling `f(i, j)` for the element at each row `i` and column `j`.
     ///
-    /// An identity matrix is a square matrix with ones on the diagonal and zeros elsewhere.
+    /// This works for every element type; for example, an identity matrix of integers is
+    /// `Matrix::from_fn(n, n, |i, j| if i == j { 1 } else { 0 })`.
     ///
     /// # Arguments
-    /// - `size`: The size of the identity matrix (i.e., the number of rows and columns).
+    /// - `rows`: The number of rows.
+    /// - `cols`: The number of columns.
+    /// - `f`: Computes the element at a given position.
     ///
     /// # Examples
     /// ```
     /// use hell::Matrix;
     ///
-    /// let identity_matrix = Matrix::identity(3);
+    /// let hilbert = Matrix::from_fn(2, 2, |i, j| 1.0 / (i + j + 1) as f64);
+    /// assert_eq!(hilbert.to_rows(), vec![vec![1.0, 0.5], vec![0.5, 1.0 / 3.0]]);
+    ///
+    /// let identity: Matrix<i32> = Matrix::from_fn(2, 2, |i, j| (i == j) as i32);
+    /// assert_eq!(identity.to_rows(), vec![vec![1, 0], vec![0, 1]]);
     /// ```
-    pub fn identity(size: usize) -> Self {
-        let mut data = vec![vec![0.0; size]; size];
-        for i in 0..size {
-            data[i][i] = 1.0;
+    pub fn from_fn<F>(rows: usize, cols: usize, f: F) -> Self
+    where
+        F: Fn(usize, usize) -> T,
+    {
+        let data = (0..rows).flat_map(|i| (0..cols).map(move |j| (i, j))).map(|(i, j)| f(i, j)).collect();
+        Matrix { rows, cols, data }
+    }
+
+    /// Returns the element at row `i` and column `j`, or `None` if the index is out of bounds.
+    ///
+    /// Unlike indexing with `matrix[(i, j)]`, this never panics.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.get(1, 0), Some(3.0));
+    /// assert_eq!(matrix.get(0, 2), None);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> Option<T> {
+        (i < self.rows && j < self.cols).then(|| self.data[i * self.cols + j])
+    }
+
+    /// Sets the element at row `i` and column `j`.
+    ///
+    /// Unlike assigning through `matrix[(i, j)]`, this reports a bad index instead of panicking.
+    ///
+    /// # Arguments
+    /// - `i`: The row index.
+    /// - `j`: The column index.
+    /// - `value`: The new value of the element.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the element was set.
+    /// - `Err(HellError::DimensionMismatch)`: If the index is out of bounds. The matrix is left
+    ///   unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::zeros(2, 2);
+    /// matrix.set(0, 1, 5.0).unwrap();
+    /// assert_eq!(matrix.get(0, 1), Some(5.0));
+    /// assert!(matrix.set(2, 0, 1.0).is_err());
+    /// ```
+    pub fn set(&mut self, i: usize, j: usize, value: T) -> Result<(), HellError> {
+        if i >= self.rows || j >= self.cols {
+            return Err(HellError::DimensionMismatch("Matrix index out of bounds."));
         }
-        Matrix { rows: size, cols: size, data }
+        self.data[i * self.cols + j] = value;
+        Ok(())
+    }
+
+    /// Wraps row-major data whose length is already known to be `rows * cols`.
+    fn from_row_major(rows: usize, cols: usize, data: Vec<T>) -> Self {
+        debug_assert_eq!(data.len(), rows * cols);
+        Matrix { rows, cols, data }
+    }
+
+    /// Returns row `i` as a slice.
+    fn row_slice(&self, i: usize) -> &[T] {
+        &self.data[i * self.cols..(i + 1) * self.cols]
     }
 
     /// Transposes the matrix.
@@ -117,10 +491,10 @@ impl Matrix {
     /// let transposed_matrix = matrix.transpose();
     /// ```
     pub fn transpose(&self) -> Self {
-        let mut transposed = vec![vec![0.0; self.rows]; self.cols];
+        let mut transposed = vec![T::zero(); self.data.len()];
         for i in 0..self.rows {
-            for j in 0..self.cols {
-                transposed[j][i] = self.data[i][j];
+            for (j, &value) in self.row_slice(i).iter().enumerate() {
+                transposed[j * self.rows + i] = value;
             }
         }
         Matrix {
@@ -130,73 +504,3016 @@ impl Matrix {
         }
     }
 
-    /// Adds two matrices.
+    /// Transposes the matrix, computing the rows of the result in parallel.
     ///
-    /// The matrices must have the same dimensions for addition.
+    /// This is the parallel counterpart of [`Matrix::transpose`]. It is only available with the
+    /// `parallel` feature.
     ///
-    /// # Arguments
-    /// - `other`: The matrix to add to the current matrix.
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
     ///
-    /// # Returns
-    /// - `Ok(Matrix)`: The result of the addition if dimensions match.
-    /// - `Err(&'static str)`: An error message if the dimensions do not match.
+    /// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix.par_transpose(), matrix.transpose());
+    /// # }
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn par_transpose(&self) -> Self
+    where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        let mut transposed = vec![T::zero(); self.data.len()];
+        transposed.par_chunks_exact_mut(self.rows.max(1)).enumerate().for_each(|(j, result_row)| {
+            for (i, value) in result_row.iter_mut().enumerate() {
+                *value = self.data[i * self.cols + j];
+            }
+        });
+        Matrix::from_row_major(self.cols, self.rows, transposed)
+    }
+
+    /// Returns row `i` as a slice, without copying.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
     ///
     /// # Examples
     /// ```
     /// use hell::Matrix;
     ///
-    /// let data1 = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
-    /// let data2 = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
-    /// let matrix1 = Matrix::new(2, 2, data1);
-    /// let matrix2 = Matrix::new(2, 2, data2);
-    /// let result = matrix1.add(&matrix2).unwrap();
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.row(1), &[3.0, 4.0]);
     /// ```
-    pub fn add(&self, other: &Matrix) -> Result<Matrix, &'static str> {
-        if self.rows != other.rows || self.cols != other.cols {
-            return Err("Matrices dimensions do not match for addition.");
-        }
-        let mut result = self.data.clone();
-        for i in 0..self.rows {
-            for j in 0..self.cols {
-                result[i][j] += other.data[i][j];
-            }
-        }
-        Ok(Matrix::new(self.rows, self.cols, result))
+    pub fn row(&self, i: usize) -> &[T] {
+        assert!(i < self.rows, "Row index out of bounds.");
+        self.row_slice(i)
     }
 
-    /// Multiplies two matrices.
+    /// Returns a copy of column `j`.
     ///
-    /// The number of columns in the first matrix must equal the number of rows in the second matrix.
+    /// Columns are not contiguous in the row-major storage, so unlike [`Matrix::row`] this
+    /// allocates.
+    ///
+    /// # Panics
+    /// Panics if `j` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.col(1), vec![2.0, 4.0]);
+    /// ```
+    pub fn col(&self, j: usize) -> Vec<T> {
+        assert!(j < self.cols, "Column index out of bounds.");
+        (0..self.rows).map(|i| self.data[i * self.cols + j]).collect()
+    }
+
+    /// Returns an iterator over the elements in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.iter().sum::<f64>(), 10.0);
+    /// assert_eq!(matrix.iter().copied().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.data.iter()
+    }
+
+    /// Returns an iterator over mutable references to the elements in row-major order.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// for x in matrix.iter_mut() {
+    ///     *x *= 10.0;
+    /// }
+    /// assert_eq!(matrix.row(1), &[30.0, 40.0]);
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Returns an iterator over the rows, each as a slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// let sums: Vec<f64> = matrix.rows().map(|row| row.iter().sum()).collect();
+    /// assert_eq!(sums, vec![6.0, 15.0]);
+    /// assert_eq!(matrix.rows().len(), 2);
+    /// ```
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.rows).map(move |i| self.row_slice(i))
+    }
+
+    /// Returns an iterator over the columns, each as an iterator over its elements from top to
+    /// bottom.
+    ///
+    /// Columns are not contiguous in the row-major storage, so each column is walked with a
+    /// stride rather than returned as a slice. Nothing is copied.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// let sums: Vec<f64> = matrix.cols().map(|col| col.sum()).collect();
+    /// assert_eq!(sums, vec![5.0, 7.0, 9.0]);
+    /// assert_eq!(matrix.cols().nth(1).unwrap().copied().collect::<Vec<_>>(), vec![2.0, 5.0]);
+    /// ```
+    pub fn cols(&self) -> impl DoubleEndedIterator<Item = impl ExactSizeIterator<Item = &T>> + ExactSizeIterator {
+        (0..self.cols).map(move |j| self.data.iter().skip(j).step_by(self.cols))
+    }
+
+    /// Returns an iterator over the elements in row-major order, each paired with its
+    /// `(row, column)` index.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 0.0], vec![0.0, 4.0]]);
+    /// let nonzero: Vec<_> = matrix.enumerate_cells().filter(|&(_, &x)| x != 0.0).map(|(index, _)| index).collect();
+    /// assert_eq!(nonzero, vec![(0, 0), (1, 1)]);
+    /// ```
+    pub fn enumerate_cells(&self) -> impl DoubleEndedIterator<Item = ((usize, usize), &T)> + ExactSizeIterator {
+        let cols = self.cols;
+        self.data.iter().enumerate().map(move |(k, x)| ((k / cols, k % cols), x))
+    }
+
+    /// Returns a read-only view of a rectangular block of the matrix, without copying.
     ///
     /// # Arguments
-    /// - `other`: The matrix to multiply with the current matrix.
+    /// - `rows`: The range of rows to include, such as `1..3`, `..2` or `..`.
+    /// - `cols`: The range of columns to include.
     ///
-    /// # Returns
-    /// - `Ok(Matrix)`: The result of the multiplication if dimensions are compatible.
-    /// - `Err(&'static str)`: An error message if the dimensions are not compatible.
+    /// # Panics
+    /// Panics if either range is decreasing or extends past the end of the matrix.
     ///
     /// # Examples
     /// ```
     /// use hell::Matrix;
     ///
-    /// let data1 = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
-    /// let data2 = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
-    /// let matrix1 = Matrix::new(2, 2, data1);
-    /// let matrix2 = Matrix::new(2, 2, data2);
-    /// let result = matrix1.multiply(&matrix2).unwrap();
+    /// let matrix = Matrix::from_fn(3, 3, |i, j| (3 * i + j) as f64);
+    /// let block = matrix.view(1.., ..2);
+    /// assert_eq!(block.shape(), (2, 2));
+    /// assert_eq!(block[(1, 0)], 6.0);
+    /// assert_eq!(block.row(0), &[3.0, 4.0]);
     /// ```
-    pub fn multiply(&self, other: &Matrix) -> Result<Matrix, &'static str> {
-        if self.cols != other.rows {
-            return Err("Matrices dimensions do not match for multiplication.");
-        }
-        let mut result = vec![vec![0.0; other.cols]; self.rows];
-        for i in 0..self.rows {
-            for j in 0..other.cols {
-                for k in 0..self.cols {
-                    result[i][j] += self.data[i][k] * other.data[k][j];
-                }
-            }
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(rows, self.rows);
+        let cols = resolve_range(cols, self.cols);
+        MatrixView {
+            matrix: self,
+            row_start: rows.start,
+            col_start: cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
         }
-        Ok(Matrix::new(self.rows, other.cols, result))
     }
+
+    /// Copies a rectangular block of the matrix into a new matrix.
+    ///
+    /// This is shorthand for `self.view(rows, cols).to_matrix()`; use [`Matrix::view`] to avoid
+    /// the copy.
+    ///
+    /// # Arguments
+    /// - `rows`: The range of rows to include.
+    /// - `cols`: The range of columns to include.
+    ///
+    /// # Panics
+    /// Panics if either range is decreasing or extends past the end of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(3, 3, vec![
+    ///     vec![1.0, 2.0, 3.0],
+    ///     vec![4.0, 5.0, 6.0],
+    ///     vec![7.0, 8.0, 9.0],
+    /// ]);
+    /// let minor = matrix.submatrix(1..3, 0..2);
+    /// assert_eq!(minor.to_rows(), vec![vec![4.0, 5.0], vec![7.0, 8.0]]);
+    /// ```
+    pub fn submatrix<R, C>(&self, rows: R, cols: C) -> Matrix<T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        self.view(rows, cols).to_matrix()
+    }
+
+    /// Joins two matrices side by side, placing the columns of `other` to the right of `self`.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to append. It must have the same number of rows.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix<T>)`: The `rows × (self.cols + other.cols)` result.
+    /// - `Err(HellError::DimensionMismatch)`: If the row counts differ.
+    ///
+    /// # Examples
+    /// Building the augmented matrix `[A | b]`:
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0, 3.0]]);
+    /// let b = Matrix::new(2, 1, vec![vec![3.0], vec![4.0]]);
+    /// let augmented = a.hstack(&b).unwrap();
+    /// assert_eq!(augmented.to_rows(), vec![vec![2.0, 1.0, 3.0], vec![1.0, 3.0, 4.0]]);
+    ///
+    /// assert!(a.hstack(&Matrix::zeros(3, 1)).is_err());
+    /// ```
+    pub fn hstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        if self.rows != other.rows {
+            return Err(HellError::DimensionMismatch("Matrices must have the same number of rows to be stacked horizontally."));
+        }
+        let mut data = Vec::with_capacity(self.data.len() + other.data.len());
+        for i in 0..self.rows {
+            data.extend_from_slice(self.row_slice(i));
+            data.extend_from_slice(other.row_slice(i));
+        }
+        Ok(Matrix::from_row_major(self.rows, self.cols + other.cols, data))
+    }
+
+    /// Joins two matrices vertically, placing the rows of `other` below `self`.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to append. It must have the same number of columns.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix<T>)`: The `(self.rows + other.rows) × cols` result.
+    /// - `Err(HellError::DimensionMismatch)`: If the column counts differ.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let top = Matrix::new(1, 2, vec![vec![1.0, 2.0]]);
+    /// let bottom = Matrix::identity(2);
+    /// let stacked = top.vstack(&bottom).unwrap();
+    /// assert_eq!(stacked.to_rows(), vec![vec![1.0, 2.0], vec![1.0, 0.0], vec![0.0, 1.0]]);
+    ///
+    /// assert!(top.vstack(&Matrix::zeros(1, 3)).is_err());
+    /// ```
+    pub fn vstack(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        if self.cols != other.cols {
+            return Err(HellError::DimensionMismatch("Matrices must have the same number of columns to be stacked vertically."));
+        }
+        let data = [self.data.as_slice(), other.data.as_slice()].concat();
+        Ok(Matrix::from_row_major(self.rows + other.rows, self.cols, data))
+    }
+
+    /// Computes the trace of a square matrix: the sum of its diagonal elements.
+    ///
+    /// # Returns
+    /// - `Ok(f64)`: The trace if the matrix is square.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert_eq!(matrix.trace().unwrap(), 5.0);
+    /// ```
+    pub fn trace(&self) -> Result<T, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Trace requires a square matrix."));
+        }
+        Ok(self.diagonal().into_iter().fold(T::zero(), |sum, v| sum + v))
+    }
+
+    /// Returns a copy of the main diagonal.
+    ///
+    /// For a rectangular matrix this is the `min(rows, cols)` elements at positions `(i, i)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(matrix.diagonal(), vec![1.0, 5.0]);
+    /// ```
+    pub fn diagonal(&self) -> Vec<T> {
+        (0..self.rows.min(self.cols)).map(|i| self.data[i * self.cols + i]).collect()
+    }
+
+    /// Overwrites the main diagonal with the given values.
+    ///
+    /// # Arguments
+    /// - `values`: The new diagonal, with `min(rows, cols)` elements.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the diagonal was replaced.
+    /// - `Err(HellError::DimensionMismatch)`: If `values` has the wrong length. The matrix is left
+    ///   unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// matrix.set_diagonal(&[0.0, -1.0]).unwrap();
+    /// assert_eq!(matrix.to_rows(), vec![vec![0.0, 2.0], vec![3.0, -1.0]]);
+    ///
+    /// assert!(matrix.set_diagonal(&[1.0]).is_err());
+    /// ```
+    pub fn set_diagonal(&mut self, values: &[T]) -> Result<(), HellError> {
+        if values.len() != self.rows.min(self.cols) {
+            return Err(HellError::DimensionMismatch("Diagonal length does not match the matrix."));
+        }
+        for (i, &value) in values.iter().enumerate() {
+            self.data[i * self.cols + i] = value;
+        }
+        Ok(())
+    }
+
+    /// Swaps rows `a` and `b` in place.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// matrix.swap_rows(0, 1);
+    /// assert_eq!(matrix.to_rows(), vec![vec![3.0, 4.0], vec![1.0, 2.0]]);
+    /// ```
+    pub fn swap_rows(&mut self, a: usize, b: usize) {
+        assert!(a < self.rows && b < self.rows, "Row index out of bounds.");
+        if a == b {
+            return;
+        }
+        let (low, high) = (a.min(b), a.max(b));
+        let (head, tail) = self.data.split_at_mut(high * self.cols);
+        head[low * self.cols..(low + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+    }
+
+    /// Swaps columns `a` and `b` in place.
+    ///
+    /// # Panics
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// matrix.swap_cols(0, 1);
+    /// assert_eq!(matrix.to_rows(), vec![vec![2.0, 1.0], vec![4.0, 3.0]]);
+    /// ```
+    pub fn swap_cols(&mut self, a: usize, b: usize) {
+        assert!(a < self.cols && b < self.cols, "Column index out of bounds.");
+        for row in self.data.chunks_exact_mut(self.cols) {
+            row.swap(a, b);
+        }
+    }
+
+    /// Inserts a new row before row `index`, shifting the rows below it down.
+    ///
+    /// A matrix with no rows accepts a row of any length and takes its column count from it, so a
+    /// matrix can be built up row by row starting from `Matrix::new(0, 0, vec![])`.
+    ///
+    /// # Arguments
+    /// - `index`: The position of the new row; `rows` appends it at the bottom.
+    /// - `values`: The elements of the new row.
+    ///
+    /// # Returns
+    /// - `Ok(())`: If the row was inserted.
+    /// - `Err(HellError::DimensionMismatch)`: If `values` does not have one element per column.
+    ///   The matrix is left unchanged.
+    ///
+    /// # Panics
+    /// Panics if `index` is greater than the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![5.0, 6.0]]);
+    /// matrix.insert_row(1, &[3.0, 4.0]).unwrap();
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0, 6.0]]);
+    ///
+    /// assert!(matrix.insert_row(0, &[1.0]).is_err());
+    /// ```
+    pub fn insert_row(&mut self, index: usize, values: &[T]) -> Result<(), HellError> {
+        assert!(index <= self.rows, "Row index out of bounds.");
+        if self.rows == 0 {
+            self.cols = values.len();
+        } else if values.len() != self.cols {
+            return Err(HellError::DimensionMismatch("Row length does not match the number of columns."));
+        }
+        let at = index * self.cols;
+        self.data.splice(at..at, values.iter().copied());
+        self.rows += 1;
+        Ok(())
+    }
+
+    /// Removes row `index`, shifting the rows below it up, and returns its elements.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(3, 1, vec![vec![1.0], vec![2.0], vec![3.0]]);
+    /// assert_eq!(matrix.remove_row(1), vec![2.0]);
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0], vec![3.0]]);
+    /// ```
+    pub fn remove_row(&mut self, index: usize) -> Vec<T> {
+        assert!(index < self.rows, "Row index out of bounds.");
+        self.rows -= 1;
+        self.data.drain(index * self.cols..(index + 1) * self.cols).collect()
+    }
+
+    /// Multiplies every element of row `i` by `k` in place.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// matrix.scale_row(1, 0.5);
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0, 2.0], vec![1.5, 2.0]]);
+    /// ```
+    pub fn scale_row(&mut self, i: usize, k: T) {
+        assert!(i < self.rows, "Row index out of bounds.");
+        for value in &mut self.data[i * self.cols..(i + 1) * self.cols] {
+            *value = *value * k;
+        }
+    }
+
+    /// Adds `k` times row `source` to row `target` in place.
+    ///
+    /// Together with [`Matrix::swap_rows`] and [`Matrix::scale_row`], this is one of the three
+    /// elementary row operations used by Gaussian elimination.
+    ///
+    /// # Arguments
+    /// - `target`: The row to update.
+    /// - `source`: The row to add. It may equal `target`, in which case the row is scaled by `1 + k`.
+    /// - `k`: The multiple of `source` to add.
+    ///
+    /// # Panics
+    /// Panics if `target` or `source` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// // Eliminate the entry below the first pivot.
+    /// let mut matrix = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![4.0, 5.0]]);
+    /// matrix.add_scaled_row(1, 0, -2.0);
+    /// assert_eq!(matrix.to_rows(), vec![vec![2.0, 1.0], vec![0.0, 3.0]]);
+    /// ```
+    pub fn add_scaled_row(&mut self, target: usize, source: usize, k: T) {
+        assert!(target < self.rows && source < self.rows, "Row index out of bounds.");
+        let cols = self.cols;
+        for j in 0..cols {
+            let added = self.data[source * cols + j] * k;
+            self.data[target * cols + j] = self.data[target * cols + j] + added;
+        }
+    }
+
+    /// Adds two matrices.
+    ///
+    /// The matrices must have the same dimensions for addition.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to add to the current matrix.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The result of the addition if dimensions match.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let data1 = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+    /// let data2 = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
+    /// let matrix1 = Matrix::new(2, 2, data1);
+    /// let matrix2 = Matrix::new(2, 2, data2);
+    /// let result = matrix1.add(&matrix2).unwrap();
+    /// ```
+    pub fn add(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for addition."));
+        }
+        let mut result = self.data.clone();
+        T::add_slice(&other.data, &mut result);
+        Ok(Matrix::from_row_major(self.rows, self.cols, result))
+    }
+
+    /// Adds two matrices, computing the elements in parallel.
+    ///
+    /// This is the parallel counterpart of [`Matrix::add`]: it takes the same arguments, returns
+    /// the same result and reports the same errors, but distributes the work across the rayon
+    /// thread pool. It is only available with the `parallel` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// let b = Matrix::identity(2);
+    /// assert_eq!(a.par_add(&b), a.add(&b));
+    /// # }
+    /// ```
+    ///
+    /// # Notes
+    /// Addition does little work per element, so it only benefits from parallelism for large
+    /// matrices.
+    #[cfg(feature = "parallel")]
+    pub fn par_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError>
+    where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for addition."));
+        }
+        let result = self.data.par_iter().zip(&other.data).map(|(&a, &b)| a + b).collect();
+        Ok(Matrix::from_row_major(self.rows, self.cols, result))
+    }
+
+    /// Subtracts another matrix from this one.
+    ///
+    /// The matrices must have the same dimensions for subtraction.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to subtract from the current matrix.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The difference if dimensions match.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
+    /// let b = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    ///
+    /// // A - 2B
+    /// let result = a.sub(&b.scale(2.0)).unwrap();
+    /// assert_eq!(result.to_rows(), vec![vec![3.0, 2.0], vec![1.0, 0.0]]);
+    /// ```
+    pub fn sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for subtraction."));
+        }
+        let mut result = self.data.clone();
+        for (value, other_value) in result.iter_mut().zip(&other.data) {
+            *value = *value - *other_value;
+        }
+        Ok(Matrix::from_row_major(self.rows, self.cols, result))
+    }
+
+    /// Multiplies every element of the matrix by a scalar.
+    ///
+    /// # Arguments
+    /// - `k`: The scalar factor.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(1, 2, vec![vec![1.0, -2.0]]);
+    /// assert_eq!(matrix.scale(3.0).to_rows(), vec![vec![3.0, -6.0]]);
+    /// ```
+    pub fn scale(&self, k: T) -> Matrix<T> {
+        self.map(|v| v * k)
+    }
+
+    /// Negates every element of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(1, 2, vec![vec![1.0, -2.0]]);
+    /// assert_eq!(matrix.neg().to_rows(), vec![vec![-1.0, 2.0]]);
+    /// ```
+    pub fn neg(&self) -> Matrix<T>
+    where
+        T: Neg<Output = T>,
+    {
+        self.map(|v| -v)
+    }
+
+    /// Applies a function to every element, returning a new matrix of the same shape.
+    ///
+    /// # Arguments
+    /// - `f`: The function to apply.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(1, 3, vec![vec![-1.0, 0.5, 2.0]]);
+    /// let relu = matrix.map(|x: f64| x.max(0.0));
+    /// assert_eq!(relu.to_rows(), vec![vec![0.0, 0.5, 2.0]]);
+    /// ```
+    pub fn map<F>(&self, f: F) -> Matrix<T>
+    where
+        F: Fn(T) -> T,
+    {
+        Matrix::from_row_major(self.rows, self.cols, self.data.iter().map(|&v| f(v)).collect())
+    }
+
+    /// Combines two matrices of the same shape element by element.
+    ///
+    /// # Arguments
+    /// - `other`: The second matrix.
+    /// - `f`: The function combining an element of `self` with the matching element of `other`.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The combined matrix if dimensions match.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(1, 2, vec![vec![1.0, 5.0]]);
+    /// let b = Matrix::new(1, 2, vec![vec![3.0, 2.0]]);
+    /// assert_eq!(a.zip_map(&b, f64::max).unwrap().to_rows(), vec![vec![3.0, 5.0]]);
+    /// ```
+    pub fn zip_map<F>(&self, other: &Matrix<T>, f: F) -> Result<Matrix<T>, HellError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if self.rows != other.rows || self.cols != other.cols {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for element-wise operation."));
+        }
+        let data = self.data.iter().zip(&other.data).map(|(&a, &b)| f(a, b)).collect();
+        Ok(Matrix::from_row_major(self.rows, self.cols, data))
+    }
+
+    /// Multiplies two matrices element by element (the Hadamard product).
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to multiply with, of the same shape.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The element-wise product if dimensions match.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// let b = Matrix::new(2, 2, vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
+    /// assert_eq!(a.hadamard(&b).unwrap().to_rows(), vec![vec![5.0, 12.0], vec![21.0, 32.0]]);
+    /// ```
+    pub fn hadamard(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        self.zip_map(other, |a, b| a * b)
+    }
+
+    /// Divides two matrices element by element.
+    ///
+    /// Division by a zero element follows IEEE 754 rules and produces an infinity or NaN.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix of divisors, of the same shape.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The element-wise quotient if dimensions match.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions do not match.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(1, 2, vec![vec![6.0, 1.0]]);
+    /// let b = Matrix::new(1, 2, vec![vec![3.0, 4.0]]);
+    /// assert_eq!(a.element_div(&b).unwrap().to_rows(), vec![vec![2.0, 0.25]]);
+    /// ```
+    pub fn element_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError>
+    where
+        T: Div<Output = T>,
+    {
+        self.zip_map(other, |a, b| a / b)
+    }
+
+    /// Multiplies two matrices.
+    ///
+    /// The number of columns in the first matrix must equal the number of rows in the second matrix.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to multiply with the current matrix.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The result of the multiplication if dimensions are compatible.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions are not compatible.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let data1 = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+    /// let data2 = vec![vec![5.0, 6.0], vec![7.0, 8.0]];
+    /// let matrix1 = Matrix::new(2, 2, data1);
+    /// let matrix2 = Matrix::new(2, 2, data2);
+    /// let result = matrix1.multiply(&matrix2).unwrap();
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time. The kernel works on tiles of both operands that fit in cache, which
+    /// keeps large products from being limited by memory bandwidth. Square `f64` matrices larger
+    /// than [`STRASSEN_THRESHOLD`] go through [`Matrix::multiply_strassen`] instead, which does
+    /// asymptotically less work; call that directly to choose another threshold. With the `simd`
+    /// feature, `f64` tiles go through an AVX kernel that gives the same results two to three
+    /// times faster.
+    pub fn multiply(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError> {
+        if self.cols != other.rows {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for multiplication."));
+        }
+        Ok(T::multiply_fast(self, other).unwrap_or_else(|| self.multiply_blocked(other)))
+    }
+
+    /// Multiplies two matrices of compatible dimensions with the cache-blocked kernel.
+    fn multiply_blocked(&self, other: &Matrix<T>) -> Matrix<T> {
+        let mut result = vec![T::zero(); self.rows * other.cols];
+        for (block, result_rows) in result.chunks_mut(MULTIPLY_BLOCK * other.cols.max(1)).enumerate() {
+            self.multiply_rows_into(other, block * MULTIPLY_BLOCK, result_rows);
+        }
+        Matrix::from_row_major(self.rows, other.cols, result)
+    }
+
+    /// Multiplies two matrices, computing the rows of the result in parallel.
+    ///
+    /// This is the parallel counterpart of [`Matrix::multiply`]: it takes the same arguments,
+    /// returns the same result and reports the same errors, but distributes the output rows
+    /// across the rayon thread pool in small chunks, each of which runs the same cache-tiled
+    /// kernel. It never switches to Strassen, so for square `f64` matrices larger than
+    /// [`STRASSEN_THRESHOLD`] the two results can differ by rounding. It is only available with
+    /// the `parallel` feature.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to multiply with the current matrix.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The result of the multiplication if dimensions are compatible.
+    /// - `Err(HellError::DimensionMismatch)`: If the dimensions are not compatible.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// let b = Matrix::new(2, 2, vec![vec![5.0, 6.0], vec![7.0, 8.0]]);
+    /// assert_eq!(a.par_multiply(&b), a.multiply(&b));
+    ///
+    /// // Row counts that are not a multiple of the chunk size give the same result too.
+    /// let a = Matrix::from_fn(37, 20, |i, j| (i * 7 + j) as f64 - 40.0);
+    /// let b = Matrix::from_fn(20, 11, |i, j| (i as f64 - j as f64) / 3.0);
+    /// assert_eq!(a.par_multiply(&b), a.multiply(&b));
+    /// # }
+    /// ```
+    ///
+    /// # Notes
+    /// Spawning work has a fixed cost, so for small matrices the serial version is usually faster.
+    #[cfg(feature = "parallel")]
+    pub fn par_multiply(&self, other: &Matrix<T>) -> Result<Matrix<T>, HellError>
+    where
+        T: Send + Sync,
+    {
+        use rayon::prelude::*;
+
+        if self.cols != other.rows {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for multiplication."));
+        }
+        let mut result = vec![T::zero(); self.rows * other.cols];
+        result
+            .par_chunks_mut(PAR_MULTIPLY_ROWS * other.cols.max(1))
+            .enumerate()
+            .for_each(|(chunk, result_rows)| self.multiply_rows_into(other, chunk * PAR_MULTIPLY_ROWS, result_rows));
+        Ok(Matrix::from_row_major(self.rows, other.cols, result))
+    }
+
+    /// Accumulates the rows of `self · other` starting at row `first_row` into `result_rows`,
+    /// which holds one or more consecutive rows of the result.
+    ///
+    /// The loops run in `i-k-j` order, so the innermost loop streams through contiguous rows of
+    /// both `other` and the result. They are tiled so that a `MULTIPLY_BLOCK × MULTIPLY_BLOCK`
+    /// block of `other` stays in cache while it is applied to every row in `result_rows`. Each
+    /// element still sums its products in increasing order of `k`, so tiling does not change the
+    /// result.
+    fn multiply_rows_into(&self, other: &Matrix<T>, first_row: usize, result_rows: &mut [T]) {
+        let n = other.cols.max(1);
+        for k_start in (0..self.cols).step_by(MULTIPLY_BLOCK) {
+            let k_end = (k_start + MULTIPLY_BLOCK).min(self.cols);
+            for j_start in (0..other.cols).step_by(MULTIPLY_BLOCK) {
+                let j_end = (j_start + MULTIPLY_BLOCK).min(other.cols);
+                let rows = result_rows.len() / n;
+                T::gemm(rows, k_end - k_start, j_end - j_start, &self.data[first_row * self.cols + k_start..], self.cols, &other.data[k_start * other.cols + j_start..], other.cols, &mut result_rows[j_start..], n);
+            }
+        }
+    }
+
+    /// Raises a square matrix to a non-negative integer power.
+    ///
+    /// `pow(0)` is the identity matrix.
+    ///
+    /// # Arguments
+    /// - `n`: The exponent.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix<T>)`: The matrix multiplied by itself `n` times.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// // Fibonacci numbers from powers of [[1, 1], [1, 0]].
+    /// let fib = Matrix::new(2, 2, vec![vec![1u64, 1], vec![1, 0]]);
+    /// assert_eq!(fib.pow(10).unwrap()[(0, 1)], 55);
+    ///
+    /// // Long-run behavior of a two-state Markov chain.
+    /// let transition: Matrix = Matrix::new(2, 2, vec![vec![0.9, 0.1], vec![0.5, 0.5]]);
+    /// let steady = transition.pow(64).unwrap();
+    /// assert!((steady[(0, 0)] - 5.0 / 6.0).abs() < 1e-12);
+    /// assert!((steady[(1, 0)] - 5.0 / 6.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Performance
+    /// Uses exponentiation by squaring, so it performs `O(log n)` matrix multiplications.
+    pub fn pow(&self, n: u32) -> Result<Matrix<T>, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Matrix power requires a square matrix."));
+        }
+        let mut result = Matrix::from_fn(self.rows, self.cols, |i, j| if i == j { T::one() } else { T::zero() });
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.multiply(&base)?;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base.multiply(&base)?;
+            }
+        }
+        Ok(result)
+    }
+
+    /// Renders the matrix as a LaTeX `bmatrix` environment.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.5]]);
+    /// assert_eq!(
+    ///     matrix.to_latex(),
+    ///     "\\begin{bmatrix}\n1 & 2 \\\\\n3 & 4.5\n\\end{bmatrix}"
+    /// );
+    /// ```
+    pub fn to_latex(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let rows: Vec<String> = (0..self.rows)
+            .map(|i| self.row_slice(i).iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" & "))
+            .collect();
+        format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", rows.join(" \\\\\n"))
+    }
+
+    /// Renders the matrix as a Markdown table.
+    ///
+    /// Markdown tables require a header row, so the table starts with an empty header; most
+    /// renderers show it as a thin blank row above the elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.5]]);
+    /// assert_eq!(matrix.to_markdown(), "|  |  |\n|---|---|\n| 1 | 2 |\n| 3 | 4.5 |");
+    /// ```
+    pub fn to_markdown(&self) -> String
+    where
+        T: fmt::Display,
+    {
+        let mut lines = vec![format!("|{}", "  |".repeat(self.cols)), format!("|{}", "---|".repeat(self.cols))];
+        for i in 0..self.rows {
+            let cells: Vec<String> = self.row_slice(i).iter().map(|v| format!(" {} |", v)).collect();
+            lines.push(format!("|{}", cells.concat()));
+        }
+        lines.join("\n")
+    }
+
+    /// Returns a copy of the elements as a `Vec` of rows.
+    ///
+    /// This is the inverse of [`Matrix::new`] and is the simplest way to hand the contents of a
+    /// matrix to code outside the crate.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let data = vec![vec![1.0, 2.0], vec![3.0, 4.0]];
+    /// let matrix = Matrix::new(2, 2, data.clone());
+    /// assert_eq!(matrix.to_rows(), data);
+    /// ```
+    pub fn to_rows(&self) -> Vec<Vec<T>> {
+        (0..self.rows).map(|i| self.row_slice(i).to_vec()).collect()
+    }
+
+    /// Returns `true` if the matrix has as many rows as columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// assert!(Matrix::from_fn(3, 3, |i, j| i + j).is_square());
+    /// assert!(!Matrix::from_fn(2, 3, |i, j| i + j).is_square());
+    /// ```
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the dimensions of the matrix as `(rows, cols)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// assert_eq!(Matrix::zeros(2, 3).shape(), (2, 3));
+    /// ```
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns the number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// assert_eq!(Matrix::zeros(2, 3).nrows(), 2);
+    /// ```
+    pub fn nrows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// assert_eq!(Matrix::zeros(2, 3).ncols(), 3);
+    /// ```
+    pub fn ncols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns the element at row `i` and column `j`.
+    ///
+    /// Used by other modules of the crate that need read access to individual elements.
+    ///
+    /// # Panics
+    /// Panics if `i` or `j` is out of bounds.
+    pub(crate) fn at(&self, i: usize, j: usize) -> T {
+        self[(i, j)]
+    }
+
+    /// Consumes the matrix and returns its elements in row-major order, without copying.
+    #[cfg(feature = "ndarray")]
+    pub(crate) fn into_data(self) -> Vec<T> {
+        self.data
+    }
+}
+
+impl Matrix {
+    /// Creates an identity matrix of the given size.
+    ///
+    /// An identity matrix is a square matrix with ones on the diagonal and zeros elsewhere.
+    ///
+    /// # Arguments
+    /// - `size`: The size of the identity matrix (i.e., the number of rows and columns).
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let identity_matrix = Matrix::identity(3);
+    /// ```
+    pub fn identity(size: usize) -> Self {
+        let mut data = vec![0.0; size * size];
+        for i in 0..size {
+            data[i * size + i] = 1.0;
+        }
+        Matrix { rows: size, cols: size, data }
+    }
+
+    /// Creates a matrix of the given dimensions filled with zeros.
+    ///
+    /// # Arguments
+    /// - `rows`: The number of rows.
+    /// - `cols`: The number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let zeros = Matrix::zeros(2, 3);
+    /// assert_eq!(zeros.to_rows(), vec![vec![0.0; 3]; 2]);
+    /// ```
+    pub fn zeros(rows: usize, cols: usize) -> Self {
+        Matrix::from_flat(rows, cols, vec![0.0; rows * cols])
+    }
+
+    /// Creates a matrix of the given dimensions filled with ones.
+    ///
+    /// # Arguments
+    /// - `rows`: The number of rows.
+    /// - `cols`: The number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let ones = Matrix::ones(3, 1);
+    /// assert_eq!(ones.to_rows(), vec![vec![1.0]; 3]);
+    /// ```
+    pub fn ones(rows: usize, cols: usize) -> Self {
+        Matrix::from_flat(rows, cols, vec![1.0; rows * cols])
+    }
+
+    /// Creates the `2 × 2` matrix of a counter-clockwise rotation about the origin.
+    ///
+    /// # Arguments
+    /// - `angle_rad`: The rotation angle in radians.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let rotation = Matrix::rotation_2d(std::f64::consts::FRAC_PI_2);
+    /// let p = rotation.multiply(&Matrix::new(2, 1, vec![vec![1.0], vec![0.0]])).unwrap();
+    /// assert!(p.approx_eq(&Matrix::new(2, 1, vec![vec![0.0], vec![1.0]]), 1e-12));
+    /// ```
+    ///
+    /// # Notes
+    /// For a homogeneous `3 × 3` transform that can be combined with translations, see
+    /// [`Affine2::rotation`](crate::geometry::affine::Affine2::rotation).
+    pub fn rotation_2d(angle_rad: f64) -> Self {
+        let (sin, cos) = (sine(angle_rad), cosine(angle_rad));
+        Matrix::new(2, 2, vec![vec![cos, -sin], vec![sin, cos]])
+    }
+
+    /// Creates the `3 × 3` matrix of a rotation about an axis through the origin, using
+    /// Rodrigues' formula.
+    ///
+    /// The rotation is counter-clockwise when viewed from the tip of `axis` looking towards the
+    /// origin (the right-hand rule).
+    ///
+    /// # Arguments
+    /// - `axis`: The rotation axis. It does not need to be normalized.
+    /// - `angle_rad`: The rotation angle in radians.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The rotation matrix.
+    /// - `Err(HellError::DomainError)`: If `axis` is zero or not finite.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    /// use hell::geometry::Vec3;
+    ///
+    /// // A third of a turn about the diagonal cycles the coordinate axes.
+    /// let rotation = Matrix::rotation_3d_axis(Vec3::new(1.0, 1.0, 1.0), 2.0 * std::f64::consts::PI / 3.0).unwrap();
+    /// let cycle = Matrix::new(3, 3, vec![vec![0.0, 0.0, 1.0], vec![1.0, 0.0, 0.0], vec![0.0, 1.0, 0.0]]);
+    /// assert!(rotation.approx_eq(&cycle, 1e-12));
+    ///
+    /// assert!(Matrix::rotation_3d_axis(Vec3::ZERO, 1.0).is_err());
+    /// ```
+    pub fn rotation_3d_axis(axis: Vec3, angle_rad: f64) -> Result<Self, HellError> {
+        let Vec3 { x, y, z } = axis.normalize().ok_or(HellError::DomainError("Rotation axis must be a nonzero finite vector."))?;
+        let (sin, cos) = (sine(angle_rad), cosine(angle_rad));
+        let t = 1.0 - cos;
+        Ok(Matrix::new(3, 3, vec![
+            vec![cos + t * x * x, t * x * y - sin * z, t * x * z + sin * y],
+            vec![t * y * x + sin * z, cos + t * y * y, t * y * z - sin * x],
+            vec![t * z * x - sin * y, t * z * y + sin * x, cos + t * z * z],
+        ]))
+    }
+
+    /// Creates a diagonal scaling matrix with one factor per axis.
+    ///
+    /// # Arguments
+    /// - `factors`: The scale factor along each axis. The matrix is `n × n` for `n` factors.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let scaling = Matrix::scaling(&[2.0, 3.0]);
+    /// assert_eq!(scaling.to_rows(), vec![vec![2.0, 0.0], vec![0.0, 3.0]]);
+    /// ```
+    pub fn scaling(factors: &[f64]) -> Self {
+        let mut matrix = Matrix::zeros(factors.len(), factors.len());
+        for (i, &factor) in factors.iter().enumerate() {
+            matrix[(i, i)] = factor;
+        }
+        matrix
+    }
+
+    /// Creates a homogeneous translation matrix.
+    ///
+    /// For an `n`-dimensional offset the result is `(n + 1) × (n + 1)`: the identity with the
+    /// offset in the last column. It moves points written in homogeneous coordinates
+    /// `(x₁, …, xₙ, 1)` and leaves directions `(x₁, …, xₙ, 0)` unchanged.
+    ///
+    /// # Arguments
+    /// - `offset`: The translation along each axis.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let translation = Matrix::translation(&[1.0, 2.0]);
+    /// assert_eq!(translation.to_rows(), vec![
+    ///     vec![1.0, 0.0, 1.0],
+    ///     vec![0.0, 1.0, 2.0],
+    ///     vec![0.0, 0.0, 1.0],
+    /// ]);
+    ///
+    /// let p = translation.multiply(&Matrix::new(3, 1, vec![vec![5.0], vec![5.0], vec![1.0]])).unwrap();
+    /// assert_eq!(p.col(0), vec![6.0, 7.0, 1.0]);
+    /// ```
+    ///
+    /// # Notes
+    /// To combine a rotation or scaling with a translation, embed it in the upper-left corner of
+    /// an identity of the same size, or use the types in [`geometry::affine`](crate::geometry::affine).
+    pub fn translation(offset: &[f64]) -> Self {
+        let n = offset.len();
+        let mut matrix = Matrix::identity(n + 1);
+        for (i, &value) in offset.iter().enumerate() {
+            matrix[(i, n)] = value;
+        }
+        matrix
+    }
+
+    /// Computes the determinant of a square matrix.
+    ///
+    /// The matrix is reduced to upper-triangular form by Gaussian elimination with partial
+    /// pivoting; the determinant is then the product of the pivots, with its sign flipped once per
+    /// row swap. The determinant of a `0 × 0` matrix is `1`. No [`Tolerance`] is applied: only an
+    /// exactly zero pivot makes the result zero, so a nearly singular matrix has a small nonzero
+    /// determinant even where [`Matrix::rank`] reports it as rank-deficient.
+    ///
+    /// # Returns
+    /// - `Ok(f64)`: The determinant if the matrix is square.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// assert!((matrix.determinant().unwrap() + 2.0).abs() < 1e-12);
+    ///
+    /// let matrix = Matrix::new(3, 3, vec![vec![2.0, 0.0, 1.0], vec![1.0, 3.0, 2.0], vec![1.0, 1.0, 2.0]]);
+    /// assert!((matrix.determinant().unwrap() - 6.0).abs() < 1e-12);
+    ///
+    /// assert!(Matrix::new(1, 2, vec![vec![1.0, 2.0]]).determinant().is_err());
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time, so even a `500 × 500` determinant takes milliseconds. For large
+    /// matrices the determinant itself may overflow; see [`Matrix::log_determinant`]. To reuse
+    /// the elimination for solves as well, use [`Matrix::lu`] and [`Lu::determinant`].
+    pub fn determinant(&self) -> Result<f64, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Determinant requires a square matrix."));
+        }
+        let n = self.rows;
+        let mut a = self.to_rows();
+        let mut det = 1.0;
+        for col in 0..n {
+            let pivot_row = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+            if a[pivot_row][col] == 0.0 {
+                return Ok(0.0);
+            }
+            if pivot_row != col {
+                a.swap(pivot_row, col);
+                det = -det;
+            }
+            let pivot = a[col][col];
+            det *= pivot;
+            let (upper, lower) = a.split_at_mut(col + 1);
+            let pivot_values = &upper[col];
+            for row in lower {
+                let factor = row[col] / pivot;
+                for (value, &p) in row[col..].iter_mut().zip(&pivot_values[col..]) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        Ok(det)
+    }
+
+    /// Computes the sign and the natural logarithm of the absolute value of the determinant, as
+    /// `(sign, ln|det|)`.
+    ///
+    /// The determinant of a large matrix easily overflows or underflows an `f64` even when the
+    /// matrix is well conditioned: a `500 × 500` matrix with entries around `10` has a
+    /// determinant near `10^500`. The logarithm stays in range, which makes it the quantity to use
+    /// for likelihoods, volume changes and comparisons between large Jacobians.
+    ///
+    /// # Returns
+    /// - `Ok((sign, log_abs))`: The sign of the determinant (`1.0`, `-1.0`, or `0.0` for a
+    ///   singular matrix) and `ln|det|` (`f64::NEG_INFINITY` for a singular matrix).
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let big = Matrix::from_fn(400, 400, |i, j| if i == j { 10.0 } else { 1.0 / (1 + i + j) as f64 });
+    /// assert!(big.determinant().unwrap().is_infinite());
+    ///
+    /// let (sign, log_abs) = big.log_determinant().unwrap();
+    /// assert_eq!(sign, 1.0);
+    /// assert!((log_abs / 10.0_f64.ln() - 400.0).abs() < 1.0);
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time, like [`Matrix::determinant`].
+    pub fn log_determinant(&self) -> Result<(f64, f64), HellError> {
+        Ok(self.lu()?.log_determinant())
+    }
+
+    /// Solves the square linear system `A·x = b` by Gaussian elimination with partial pivoting.
+    ///
+    /// This is a shorthand for [`Matrix::solve_with_tolerance`] with [`Tolerance::DEFAULT`], so a
+    /// pivot at most `1e-10` times the largest entry counts as zero (see [`Tolerance::DEFAULT`]).
+    /// To solve several systems with the same matrix, factor it once with [`Matrix::lu`] instead.
+    ///
+    /// # Arguments
+    /// - `b`: The right-hand side, with one entry per row.
+    ///
+    /// # Returns
+    /// - `Ok(x)`: The solution vector.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square or `b` has the wrong
+    ///   length.
+    /// - `Err(HellError::ConvergenceFailure)`: If the matrix is singular, so the system has no
+    ///   unique solution.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// // 2x + y = 5, x - y = 1  =>  x = 2, y = 1
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0, -1.0]]);
+    /// let x = a.solve(&[5.0, 1.0]).unwrap();
+    /// assert!((x[0] - 2.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12);
+    ///
+    /// let singular = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![2.0, 4.0]]);
+    /// assert!(singular.solve(&[1.0, 2.0]).is_err());
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, HellError> {
+        self.solve_with_tolerance(b, Tolerance::DEFAULT)
+    }
+
+    /// Solves the square linear system `A·x = b`, treating a pivot as zero when it is negligible
+    /// under `tolerance` relative to the largest entry of the matrix.
+    ///
+    /// # Arguments
+    /// - `b`: The right-hand side, with one entry per row.
+    /// - `tolerance`: Decides when the matrix is considered singular.
+    ///
+    /// # Returns
+    /// - `Ok(x)`: The solution vector.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square or `b` has the wrong
+    ///   length.
+    /// - `Err(HellError::ConvergenceFailure)`: If the matrix is singular under `tolerance`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let nearly_singular = Matrix::new(2, 2, vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-8]]);
+    /// assert!(nearly_singular.solve(&[2.0, 2.0]).is_ok());
+    /// assert!(nearly_singular.solve_with_tolerance(&[2.0, 2.0], Tolerance::new(0.0, 1e-6)).is_err());
+    /// ```
+    pub fn solve_with_tolerance(&self, b: &[f64], tolerance: Tolerance) -> Result<Vec<f64>, HellError> {
+        if self.rows != self.cols || b.len() != self.rows {
+            return Err(HellError::DimensionMismatch("Matrix must be square and match the right-hand side."));
+        }
+        solve_linear_system(self.to_rows(), b.to_vec(), tolerance).ok_or(HellError::ConvergenceFailure("Matrix is singular."))
+    }
+
+    /// Solves the square linear system `A·x = b`, eliminating the rows below each pivot in
+    /// parallel.
+    ///
+    /// This is the parallel counterpart of [`Matrix::solve`]: it takes the same arguments, returns
+    /// the same result and reports the same errors, but splits each elimination step across the
+    /// rayon thread pool. It is only available with the `parallel` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::from_fn(60, 60, |i, j| if i == j { 100.0 } else { ((i * 7 + j * 3) % 11) as f64 });
+    /// let b: Vec<f64> = (0..60).map(|i| i as f64).collect();
+    /// assert_eq!(a.par_solve(&b), a.solve(&b));
+    /// # }
+    /// ```
+    ///
+    /// # Notes
+    /// Each step only has `n − k` rows to update, so this pays off for systems with at least a few
+    /// hundred unknowns.
+    #[cfg(feature = "parallel")]
+    pub fn par_solve(&self, b: &[f64]) -> Result<Vec<f64>, HellError> {
+        use rayon::prelude::*;
+
+        if self.rows != self.cols || b.len() != self.rows {
+            return Err(HellError::DimensionMismatch("Matrix must be square and match the right-hand side."));
+        }
+        let n = self.rows;
+        let (mut a, mut b) = (self.to_rows(), b.to_vec());
+        let scale = self.data.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+        for col in 0..n {
+            let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+            if Tolerance::DEFAULT.is_negligible(a[pivot][col], scale) {
+                return Err(HellError::ConvergenceFailure("Matrix is singular."));
+            }
+            a.swap(col, pivot);
+            b.swap(col, pivot);
+
+            let (upper, lower) = a.split_at_mut(col + 1);
+            let (b_upper, b_lower) = b.split_at_mut(col + 1);
+            let (pivot_row, b_pivot) = (&upper[col], b_upper[col]);
+            lower.par_iter_mut().zip(b_lower.par_iter_mut()).for_each(|(row, b_row)| {
+                let factor = row[col] / pivot_row[col];
+                if factor == 0.0 {
+                    return;
+                }
+                for (value, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                    *value -= factor * p;
+                }
+                *b_row -= factor * b_pivot;
+            });
+        }
+
+        let mut x = vec![0.0; n];
+        for i in (0..n).rev() {
+            let sum: f64 = a[i][i + 1..].iter().zip(&x[i + 1..]).map(|(a, x)| a * x).sum();
+            x[i] = (b[i] - sum) / a[i][i];
+        }
+        Ok(x)
+    }
+
+    /// Solves `A·x = b` for a triangular matrix `A` by forward or back substitution.
+    ///
+    /// Only the chosen triangle of the matrix, including the diagonal, is read; the other is
+    /// ignored. This makes it the natural second step after a factorization such as
+    /// [`Matrix::cholesky`], whose factors are triangular.
+    ///
+    /// # Arguments
+    /// - `b`: The right-hand side, with one entry per row.
+    /// - `triangle`: Which triangle of the matrix holds `A`.
+    ///
+    /// # Returns
+    /// - `Ok(x)`: The solution vector.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square or `b` has the wrong
+    ///   length.
+    /// - `Err(HellError::ConvergenceFailure)`: If a diagonal element is zero, so the matrix is
+    ///   singular.
+    ///
+    /// # Examples
+    /// Solving a symmetric positive-definite system with its Cholesky factor `L`, via `L·y = b`
+    /// and then `Lᵀ·x = y`:
+    /// ```
+    /// use hell::matrix::Triangle;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![4.0, 2.0], vec![2.0, 3.0]]);
+    /// let l = a.cholesky().unwrap();
+    /// let y = l.solve_triangular(&[6.0, 5.0], Triangle::Lower).unwrap();
+    /// let x = l.transpose().solve_triangular(&y, Triangle::Upper).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 1.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n²)` time.
+    pub fn solve_triangular(&self, b: &[f64], triangle: Triangle) -> Result<Vec<f64>, HellError> {
+        let n = self.rows;
+        if self.cols != n || b.len() != n {
+            return Err(HellError::DimensionMismatch("Matrix must be square and match the right-hand side."));
+        }
+        let mut x = vec![0.0; n];
+        let order: Box<dyn Iterator<Item = usize>> = match triangle {
+            Triangle::Lower => Box::new(0..n),
+            Triangle::Upper => Box::new((0..n).rev()),
+        };
+        for i in order {
+            let row = self.row_slice(i);
+            let known = match triangle {
+                Triangle::Lower => 0..i,
+                Triangle::Upper => i + 1..n,
+            };
+            let sum = f64::dot(&row[known.clone()], &x[known]);
+            if row[i] == 0.0 {
+                return Err(HellError::ConvergenceFailure("Matrix is singular."));
+            }
+            x[i] = (b[i] - sum) / row[i];
+        }
+        Ok(x)
+    }
+
+    /// Computes the reduced row echelon form of the matrix by Gauss–Jordan elimination.
+    ///
+    /// This is a shorthand for [`Matrix::rref_with_tolerance`] with [`Tolerance::DEFAULT`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 7.0]]);
+    /// assert_eq!(a.rref().to_rows(), vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 1.0]]);
+    /// ```
+    pub fn rref(&self) -> Matrix {
+        self.rref_with_tolerance(Tolerance::DEFAULT)
+    }
+
+    /// Computes the reduced row echelon form of the matrix by Gauss–Jordan elimination.
+    ///
+    /// Each pivot column is cleared above and below its pivot, and the pivot is scaled to one. The
+    /// largest candidate in a column is chosen as its pivot; a column whose remaining entries are
+    /// all negligible under `tolerance`, relative to the largest entry of the matrix, has no pivot
+    /// and those entries are set to exactly zero.
+    ///
+    /// # Arguments
+    /// - `tolerance`: Decides when an entry counts as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 2.0], vec![1.0, 1.0 + 1e-9]]);
+    /// assert_eq!(a.rref_with_tolerance(Tolerance::new(0.0, 1e-6)).to_rows(), vec![vec![1.0, 1.0], vec![0.0, 0.0]]);
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(rows · cols · min(rows, cols))` time.
+    pub fn rref_with_tolerance(&self, tolerance: Tolerance) -> Matrix {
+        Matrix::new(self.rows, self.cols, self.gauss_jordan(tolerance).0)
+    }
+
+    /// Returns the rank of the matrix: the number of linearly independent rows (or columns).
+    ///
+    /// This is a shorthand for [`Matrix::rank_with_tolerance`] with [`Tolerance::DEFAULT`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// assert_eq!(Matrix::identity(3).rank(), 3);
+    ///
+    /// let degenerate = Matrix::new(3, 2, vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![-1.0, -2.0]]);
+    /// assert_eq!(degenerate.rank(), 1);
+    /// ```
+    pub fn rank(&self) -> usize {
+        self.rank_with_tolerance(Tolerance::DEFAULT)
+    }
+
+    /// Returns the rank of the matrix: the number of pivots found by Gauss–Jordan elimination,
+    /// with entries negligible under `tolerance` treated as zero (see
+    /// [`Matrix::rref_with_tolerance`]).
+    ///
+    /// # Arguments
+    /// - `tolerance`: Decides when an entry counts as zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let nearly_singular = Matrix::new(2, 2, vec![vec![1.0, 1.0], vec![1.0, 1.0 + 1e-9]]);
+    /// assert_eq!(nearly_singular.rank(), 2);
+    /// assert_eq!(nearly_singular.rank_with_tolerance(Tolerance::new(0.0, 1e-6)), 1);
+    /// ```
+    pub fn rank_with_tolerance(&self, tolerance: Tolerance) -> usize {
+        self.gauss_jordan(tolerance).1
+    }
+
+    /// Runs Gauss–Jordan elimination and returns the reduced rows and the number of pivots.
+    fn gauss_jordan(&self, tolerance: Tolerance) -> (Vec<Vec<f64>>, usize) {
+        let mut a = self.to_rows();
+        let scale = a.iter().flatten().fold(0.0_f64, |m, v| m.max(v.abs()));
+        let mut pivot_row = 0;
+        for col in 0..self.cols {
+            if pivot_row == self.rows {
+                break;
+            }
+            let best = (pivot_row..self.rows).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs())).unwrap();
+            if tolerance.is_negligible(a[best][col], scale) {
+                for row in &mut a[pivot_row..] {
+                    row[col] = 0.0;
+                }
+                continue;
+            }
+            a.swap(pivot_row, best);
+            let pivot = a[pivot_row][col];
+            for value in &mut a[pivot_row][col..] {
+                *value /= pivot;
+            }
+            a[pivot_row][col] = 1.0;
+            let pivot_values = a[pivot_row].clone();
+            for (i, row) in a.iter_mut().enumerate() {
+                let factor = row[col];
+                if i == pivot_row || factor == 0.0 {
+                    continue;
+                }
+                for (value, &p) in row[col..].iter_mut().zip(&pivot_values[col..]) {
+                    *value -= factor * p;
+                }
+                row[col] = 0.0;
+            }
+            pivot_row += 1;
+        }
+        (a, pivot_row)
+    }
+
+    /// Computes the LU decomposition of a square matrix with partial pivoting.
+    ///
+    /// The result satisfies `P·A = L·U`, where `L` is unit lower-triangular, `U` is
+    /// upper-triangular, and `P` is the row permutation recorded in [`Lu::permutation`]. Once
+    /// computed, the factorization can solve any number of systems with the same matrix in `O(n²)`
+    /// each, see [`Lu::solve`].
+    ///
+    /// A singular matrix still has a decomposition; its `U` simply has a zero on the diagonal.
+    ///
+    /// # Returns
+    /// - `Ok(Lu)`: The factors if the matrix is square.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]);
+    /// let lu = a.lu().unwrap();
+    ///
+    /// // The larger entry 3 is chosen as the first pivot, so the rows are swapped.
+    /// assert_eq!(lu.permutation, vec![1, 0]);
+    /// assert_eq!(lu.l.to_rows(), vec![vec![1.0, 0.0], vec![1.0 / 3.0, 1.0]]);
+    /// assert_eq!(lu.u.to_rows()[0], vec![3.0, 4.0]);
+    ///
+    /// let x = lu.solve(&[5.0, 11.0]).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time.
+    pub fn lu(&self) -> Result<Lu, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("LU decomposition requires a square matrix."));
+        }
+        let n = self.rows;
+        let mut u = self.to_rows();
+        let mut l = vec![vec![0.0; n]; n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        for col in 0..n {
+            let pivot_row = (col..n).max_by(|&i, &j| u[i][col].abs().total_cmp(&u[j][col].abs())).unwrap();
+            if pivot_row != col {
+                u.swap(pivot_row, col);
+                l.swap(pivot_row, col);
+                permutation.swap(pivot_row, col);
+                swaps += 1;
+            }
+            l[col][col] = 1.0;
+            let pivot = u[col][col];
+            if pivot == 0.0 {
+                continue;
+            }
+            let (upper, lower) = u.split_at_mut(col + 1);
+            let pivot_values = &upper[col];
+            for (offset, row) in lower.iter_mut().enumerate() {
+                let factor = row[col] / pivot;
+                l[col + 1 + offset][col] = factor;
+                for (value, &p) in row[col..].iter_mut().zip(&pivot_values[col..]) {
+                    *value -= factor * p;
+                }
+            }
+        }
+        Ok(Lu { l: Matrix::new(n, n, l), u: Matrix::new(n, n, u), permutation, swaps })
+    }
+
+    /// Computes the LU decomposition of a square matrix with partial pivoting, eliminating the
+    /// rows below each pivot in parallel.
+    ///
+    /// This is the parallel counterpart of [`Matrix::lu`]: it returns the same factors and reports
+    /// the same errors, but splits each elimination step across the rayon thread pool. It is only
+    /// available with the `parallel` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::from_fn(50, 50, |i, j| ((i * 13 + j * 7) % 17) as f64 - 8.0);
+    /// assert_eq!(a.par_lu(), a.lu());
+    /// # }
+    /// ```
+    ///
+    /// # Notes
+    /// Each step only has `n − k` rows to update, so this pays off for matrices with at least a
+    /// few hundred rows.
+    #[cfg(feature = "parallel")]
+    pub fn par_lu(&self) -> Result<Lu, HellError> {
+        use rayon::prelude::*;
+
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("LU decomposition requires a square matrix."));
+        }
+        let n = self.rows;
+        let mut u = self.to_rows();
+        let mut l = vec![vec![0.0; n]; n];
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+        for col in 0..n {
+            let pivot_row = (col..n).max_by(|&i, &j| u[i][col].abs().total_cmp(&u[j][col].abs())).unwrap();
+            if pivot_row != col {
+                u.swap(pivot_row, col);
+                l.swap(pivot_row, col);
+                permutation.swap(pivot_row, col);
+                swaps += 1;
+            }
+            l[col][col] = 1.0;
+            let pivot = u[col][col];
+            if pivot == 0.0 {
+                continue;
+            }
+            let (upper, lower) = u.split_at_mut(col + 1);
+            let pivot_values = &upper[col];
+            lower.par_iter_mut().zip(l[col + 1..].par_iter_mut()).for_each(|(row, l_row)| {
+                let factor = row[col] / pivot;
+                l_row[col] = factor;
+                for (value, &p) in row[col..].iter_mut().zip(&pivot_values[col..]) {
+                    *value -= factor * p;
+                }
+            });
+        }
+        Ok(Lu { l: Matrix::new(n, n, l), u: Matrix::new(n, n, u), permutation, swaps })
+    }
+
+    /// Computes the Cholesky factorization of a symmetric positive-definite matrix.
+    ///
+    /// Returns the lower-triangular matrix `L` with a positive diagonal such that `A = L·Lᵀ`. For
+    /// matrices of this kind (covariance matrices, normal equations, stiffness matrices) it is
+    /// about twice as fast as [`Matrix::lu`] and needs no pivoting.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The lower-triangular factor `L`.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    /// - `Err(HellError::DomainError)`: If the matrix is not symmetric (within the default
+    ///   tolerance) or not positive definite.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(3, 3, vec![
+    ///     vec![4.0, 12.0, -16.0],
+    ///     vec![12.0, 37.0, -43.0],
+    ///     vec![-16.0, -43.0, 98.0],
+    /// ]);
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l.to_rows(), vec![
+    ///     vec![2.0, 0.0, 0.0],
+    ///     vec![6.0, 1.0, 0.0],
+    ///     vec![-8.0, 5.0, 3.0],
+    /// ]);
+    ///
+    /// let indefinite = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+    /// assert!(indefinite.cholesky().is_err());
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in about `n³ / 3` multiply-adds.
+    pub fn cholesky(&self) -> Result<Matrix, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Cholesky factorization requires a square matrix."));
+        }
+        let n = self.rows;
+        let tolerance = Tolerance::DEFAULT;
+        for i in 0..n {
+            for j in 0..i {
+                if !tolerance.approx_eq(self.at(i, j), self.at(j, i)) {
+                    return Err(HellError::DomainError("Cholesky factorization requires a symmetric matrix."));
+                }
+            }
+        }
+        let mut l = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..=i {
+                let sum: f64 = l[i][..j].iter().zip(&l[j][..j]).map(|(a, b)| a * b).sum();
+                if i == j {
+                    let d = self.at(i, i) - sum;
+                    if d <= 0.0 || d.is_nan() {
+                        return Err(HellError::DomainError("Matrix is not positive definite."));
+                    }
+                    l[i][i] = d.sqrt();
+                } else {
+                    l[i][j] = (self.at(i, j) - sum) / l[j][j];
+                }
+            }
+        }
+        Ok(Matrix::new(n, n, l))
+    }
+
+    /// Computes the Cholesky factorization of a symmetric positive-definite matrix, computing
+    /// each column of the factor in parallel.
+    ///
+    /// This is the parallel counterpart of [`Matrix::cholesky`]: it returns the same factor and
+    /// reports the same errors. The factor is built column by column; once the diagonal entry of a
+    /// column is known, the entries below it are independent and are spread across the rayon
+    /// thread pool. Each entry is computed with the same operations as in the serial version. It
+    /// is only available with the `parallel` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// # #[cfg(feature = "parallel")] {
+    /// use hell::Matrix;
+    ///
+    /// let b = Matrix::from_fn(40, 40, |i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0);
+    /// let a = &(&b.transpose() * &b) + &Matrix::identity(40);
+    /// assert_eq!(a.par_cholesky(), a.cholesky());
+    /// # }
+    /// ```
+    #[cfg(feature = "parallel")]
+    pub fn par_cholesky(&self) -> Result<Matrix, HellError> {
+        use rayon::prelude::*;
+
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Cholesky factorization requires a square matrix."));
+        }
+        let n = self.rows;
+        if !self.is_symmetric_with_tolerance(Tolerance::DEFAULT) {
+            return Err(HellError::DomainError("Cholesky factorization requires a symmetric matrix."));
+        }
+        let mut l = vec![vec![0.0; n]; n];
+        for j in 0..n {
+            let (upper, lower) = l.split_at_mut(j + 1);
+            let column = &mut upper[j];
+            let sum: f64 = column[..j].iter().map(|a| a * a).sum();
+            let d = self.at(j, j) - sum;
+            if d <= 0.0 || d.is_nan() {
+                return Err(HellError::DomainError("Matrix is not positive definite."));
+            }
+            column[j] = d.sqrt();
+            let column = &*column;
+            lower.par_iter_mut().enumerate().for_each(|(offset, row)| {
+                let i = j + 1 + offset;
+                let sum: f64 = row[..j].iter().zip(&column[..j]).map(|(a, b)| a * b).sum();
+                row[j] = (self.at(i, j) - sum) / column[j];
+            });
+        }
+        Ok(Matrix::new(n, n, l))
+    }
+
+    /// Computes the eigenvalues and eigenvectors of a symmetric matrix with the cyclic Jacobi
+    /// method.
+    ///
+    /// Each Jacobi rotation zeroes one off-diagonal pair; sweeping over all pairs repeatedly drives
+    /// the matrix to diagonal form, with the eigenvalues on the diagonal and the accumulated
+    /// rotations as the eigenvectors. The method is slower than tridiagonal QR for large matrices
+    /// but simple, robust, and very accurate, which suits the small dense matrices of PCA and
+    /// similar workloads.
+    ///
+    /// # Returns
+    /// - `Ok((values, vectors))`: The eigenvalues in ascending order, and a matrix whose column `k`
+    ///   is the unit eigenvector for `values[k]`. The eigenvectors are mutually orthogonal.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    /// - `Err(HellError::DomainError)`: If the matrix is not symmetric (within the default
+    ///   tolerance).
+    /// - `Err(HellError::ConvergenceFailure)`: If the off-diagonal part has not vanished after 100
+    ///   sweeps, which only happens for matrices containing NaN or infinite values.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0, 2.0]]);
+    /// let (values, vectors) = a.eigen_symmetric().unwrap();
+    /// assert!((values[0] - 1.0).abs() < 1e-12);
+    /// assert!((values[1] - 3.0).abs() < 1e-12);
+    ///
+    /// // A·v = λ·v for each eigenpair.
+    /// let v = vectors.to_rows();
+    /// for k in 0..2 {
+    ///     assert!((2.0 * v[0][k] + v[1][k] - values[k] * v[0][k]).abs() < 1e-12);
+    ///     assert!((v[0][k] + 2.0 * v[1][k] - values[k] * v[1][k]).abs() < 1e-12);
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    /// Each sweep costs `O(n³)`, and convergence is quadratic, typically within 6 to 10 sweeps.
+    pub fn eigen_symmetric(&self) -> Result<(Vec<f64>, Matrix), HellError> {
+        const MAX_SWEEPS: usize = 100;
+
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Eigen decomposition requires a square matrix."));
+        }
+        let n = self.rows;
+        let tolerance = Tolerance::DEFAULT;
+        for i in 0..n {
+            for j in 0..i {
+                if !tolerance.approx_eq(self.at(i, j), self.at(j, i)) {
+                    return Err(HellError::DomainError("Jacobi eigen solver requires a symmetric matrix."));
+                }
+            }
+        }
+        let mut a = self.to_rows();
+        let mut v = Matrix::identity(n).to_rows();
+        let norm: f64 = a.iter().flatten().map(|x| x * x).sum();
+
+        let mut converged = false;
+        for _ in 0..MAX_SWEEPS {
+            let off: f64 = a
+                .iter()
+                .enumerate()
+                .map(|(i, row)| row.iter().enumerate().filter(|&(j, _)| j != i).map(|(_, x)| x * x).sum::<f64>())
+                .sum();
+            if off == 0.0 || tolerance.is_negligible(off.sqrt(), norm.sqrt()) {
+                converged = true;
+                break;
+            }
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[p][q] == 0.0 {
+                        continue;
+                    }
+                    let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                    let c = 1.0 / (t * t + 1.0).sqrt();
+                    let s = t * c;
+                    for row in a.iter_mut().chain(v.iter_mut()) {
+                        let (kp, kq) = (row[p], row[q]);
+                        row[p] = c * kp - s * kq;
+                        row[q] = s * kp + c * kq;
+                    }
+                    let (upper, lower) = a.split_at_mut(q);
+                    for (pk, qk) in upper[p].iter_mut().zip(lower[0].iter_mut()) {
+                        (*pk, *qk) = (c * *pk - s * *qk, s * *pk + c * *qk);
+                    }
+                    a[p][q] = 0.0;
+                    a[q][p] = 0.0;
+                }
+            }
+        }
+        if !converged {
+            return Err(HellError::ConvergenceFailure("Jacobi eigen solver did not converge."));
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&i, &j| a[i][i].total_cmp(&a[j][j]));
+        let values = order.iter().map(|&k| a[k][k]).collect();
+        let vectors = v.iter().map(|row| order.iter().map(|&k| row[k]).collect()).collect();
+        Ok((values, Matrix::new(n, n, vectors)))
+    }
+
+    /// Computes all eigenvalues of a general square matrix with the shifted QR algorithm.
+    ///
+    /// The matrix is first reduced to upper Hessenberg form by stabilized elementary similarity
+    /// transforms. Francis double-shift QR steps then deflate it into `1 × 1` and `2 × 2`
+    /// diagonal blocks, which yield the real eigenvalues and the complex conjugate pairs. For
+    /// symmetric matrices, [`Matrix::eigen_symmetric`] is more accurate and also returns the
+    /// eigenvectors.
+    ///
+    /// # Returns
+    /// - `Ok(values)`: The `n` eigenvalues, repeated according to multiplicity and sorted by real
+    ///   part, then by imaginary part. Real eigenvalues have an imaginary part of exactly zero.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    /// - `Err(HellError::ConvergenceFailure)`: If an eigenvalue has not deflated after 30 QR
+    ///   steps.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::complex::Complex;
+    /// use hell::Matrix;
+    ///
+    /// // A rotation by 90° has eigenvalues ±i.
+    /// let rotation = Matrix::new(2, 2, vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+    /// assert_eq!(rotation.eigenvalues().unwrap(), vec![Complex::new(0.0, -1.0), Complex::new(0.0, 1.0)]);
+    ///
+    /// // The companion matrix of (x - 1)(x - 2)(x - 3).
+    /// let companion = Matrix::new(3, 3, vec![
+    ///     vec![6.0, -11.0, 6.0],
+    ///     vec![1.0, 0.0, 0.0],
+    ///     vec![0.0, 1.0, 0.0],
+    /// ]);
+    /// let values = companion.eigenvalues().unwrap();
+    /// for (value, expected) in values.iter().zip([1.0, 2.0, 3.0]) {
+    ///     assert!((value.re - expected).abs() < 1e-9 && value.im == 0.0);
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    /// The reduction costs `O(n³)` and each QR step `O(n²)`; about two steps per eigenvalue are
+    /// typical.
+    ///
+    /// # References
+    /// - Press et al., *Numerical Recipes*, §11.5–11.6 (`elmhes` and `hqr`).
+    pub fn eigenvalues(&self) -> Result<Vec<Complex>, HellError> {
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Eigenvalues require a square matrix."));
+        }
+        let mut values = hessenberg_qr(reduce_to_hessenberg(self.to_rows()))
+            .ok_or(HellError::ConvergenceFailure("QR iteration did not converge."))?;
+        values.sort_by(|a, b| a.re.total_cmp(&b.re).then(a.im.total_cmp(&b.im)));
+        Ok(values)
+    }
+
+    /// Computes the matrix exponential `e^A` of a square matrix.
+    ///
+    /// The solution of the linear ODE `x'(t) = A·x(t)` is `x(t) = e^(A·t)·x(0)`, so this gives the
+    /// exact propagator of a linear system over a time step.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The exponential `I + A + A²/2! + A³/3! + …`.
+    /// - `Err(HellError::DimensionMismatch)`: If the matrix is not square.
+    /// - `Err(HellError::DomainError)`: If the matrix has infinite or NaN entries.
+    /// - `Err(HellError::ConvergenceFailure)`: If the denominator of the Padé approximant is
+    ///   numerically singular.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// // A rotation generator exponentiates to a rotation by one radian.
+    /// let generator = Matrix::new(2, 2, vec![vec![0.0, -1.0], vec![1.0, 0.0]]);
+    /// let rotation = generator.expm().unwrap();
+    /// assert!((rotation[(0, 0)] - 1f64.cos()).abs() < 1e-14);
+    /// assert!((rotation[(1, 0)] - 1f64.sin()).abs() < 1e-14);
+    ///
+    /// // Diagonal matrices exponentiate element-wise.
+    /// let diagonal = Matrix::new(2, 2, vec![vec![10.0, 0.0], vec![0.0, -2.0]]);
+    /// let exp = diagonal.expm().unwrap();
+    /// assert!((exp[(0, 0)] / 10f64.exp() - 1.0).abs() < 1e-13);
+    /// assert!((exp[(1, 1)] / (-2f64).exp() - 1.0).abs() < 1e-13);
+    /// ```
+    ///
+    /// # Notes
+    /// Uses scaling and squaring with a diagonal Padé approximant of degree 6 (Golub & Van Loan,
+    /// *Matrix Computations*, Algorithm 9.3.1): `A` is scaled by `2^-s` until its infinity norm is
+    /// at most 1/2, the approximant is evaluated there, and the result is squared `s` times.
+    ///
+    /// # Performance
+    /// Runs in `O(n³ · (6 + s))` time, where `s` grows with the logarithm of the norm of `A`.
+    pub fn expm(&self) -> Result<Matrix, HellError> {
+        const DEGREE: u32 = 6;
+        if self.rows != self.cols {
+            return Err(HellError::DimensionMismatch("Matrix exponential requires a square matrix."));
+        }
+        let n = self.rows;
+        let norm = (0..n).map(|i| self.row_slice(i).iter().map(|v| v.abs()).sum::<f64>()).fold(0.0, f64::max);
+        if !norm.is_finite() {
+            return Err(HellError::DomainError("Matrix exponential requires finite entries."));
+        }
+        let squarings = if norm > 0.5 { (norm.log2().floor() as i32 + 2).max(0) as u32 } else { 0 };
+        let a = self.scale(0.5f64.powi(squarings as i32));
+
+        let identity = Matrix::identity(n);
+        let mut c = 0.5;
+        let mut power = a.clone();
+        let mut numerator = &identity + &(&a * c);
+        let mut denominator = &identity - &(&a * c);
+        for k in 2..=DEGREE {
+            c *= f64::from(DEGREE - k + 1) / f64::from(k * (2 * DEGREE - k + 1));
+            power = &a * &power;
+            let term = &power * c;
+            numerator = &numerator + &term;
+            denominator = if k % 2 == 0 { &denominator + &term } else { &denominator - &term };
+        }
+
+        let lu = denominator.lu()?;
+        let mut columns = Vec::with_capacity(n);
+        for j in 0..n {
+            columns.push(lu.solve(&numerator.col(j))?);
+        }
+        let mut result = Matrix::from_fn(n, n, |i, j| columns[j][i]);
+        for _ in 0..squarings {
+            result = &result * &result;
+        }
+        Ok(result)
+    }
+
+    /// Returns whether two matrices have the same shape and every pair of corresponding elements
+    /// differs by at most `epsilon`.
+    ///
+    /// Use this instead of `==` to compare computed results, which are almost never exact.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to compare with.
+    /// - `epsilon`: The largest allowed absolute difference per element.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![4.0, 2.0], vec![2.0, 3.0]]);
+    /// let l = a.cholesky().unwrap();
+    /// let roundtrip = &l * &l.transpose();
+    /// assert!(roundtrip.approx_eq(&a, 1e-12));
+    /// assert!(!roundtrip.approx_eq(&Matrix::identity(2), 1e-12));
+    /// ```
+    pub fn approx_eq(&self, other: &Matrix, epsilon: f64) -> bool {
+        self.approx_eq_with_tolerance(other, Tolerance::new(epsilon, 0.0))
+    }
+
+    /// Returns whether two matrices have the same shape and every pair of corresponding elements
+    /// is equal within the given tolerance.
+    ///
+    /// Unlike [`Matrix::approx_eq`], this can also allow a relative error, which suits matrices
+    /// whose elements span several orders of magnitude.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to compare with.
+    /// - `tolerance`: The tolerance applied to each pair of elements by [`Tolerance::approx_eq`].
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(1, 2, vec![vec![1e-3, 1e9]]);
+    /// let b = Matrix::new(1, 2, vec![vec![1e-3, 1e9 + 1.0]]);
+    /// assert!(a.approx_eq_with_tolerance(&b, Tolerance::new(0.0, 1e-8)));
+    /// assert!(!a.approx_eq(&b, 1e-6));
+    /// ```
+    pub fn approx_eq_with_tolerance(&self, other: &Matrix, tolerance: Tolerance) -> bool {
+        self.shape() == other.shape() && self.data.iter().zip(&other.data).all(|(&a, &b)| tolerance.approx_eq(a, b))
+    }
+
+    /// Returns `true` if the matrix is square and equal to its transpose, with each pair of
+    /// mirrored entries differing by at most `epsilon`.
+    ///
+    /// This is a shorthand for [`Matrix::is_symmetric_with_tolerance`] with a purely absolute
+    /// tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0 + 1e-12, 3.0]]);
+    /// assert!(a.is_symmetric(1e-9));
+    /// assert!(!a.is_symmetric(0.0));
+    /// ```
+    pub fn is_symmetric(&self, epsilon: f64) -> bool {
+        self.is_symmetric_with_tolerance(Tolerance::new(epsilon, 0.0))
+    }
+
+    /// Returns `true` if the matrix is square and equal to its transpose, with each pair of
+    /// mirrored entries equal within the given tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2e9, 1e9], vec![1e9 + 0.01, 3e9]]);
+    /// assert!(a.is_symmetric_with_tolerance(Tolerance::DEFAULT));
+    /// assert!(!a.is_symmetric(1e-6));
+    /// ```
+    pub fn is_symmetric_with_tolerance(&self, tolerance: Tolerance) -> bool {
+        self.is_square() && (0..self.rows).all(|i| (0..i).all(|j| tolerance.approx_eq(self.at(i, j), self.at(j, i))))
+    }
+
+    /// Returns `true` if the matrix is square and every entry off the diagonal is at most
+    /// `epsilon` in absolute value.
+    ///
+    /// This is a shorthand for [`Matrix::is_diagonal_with_tolerance`] with a purely absolute
+    /// tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, 1e-12], vec![0.0, 3.0]]);
+    /// assert!(a.is_diagonal(1e-9));
+    /// assert!(!a.is_diagonal(0.0));
+    /// ```
+    pub fn is_diagonal(&self, epsilon: f64) -> bool {
+        self.is_diagonal_with_tolerance(Tolerance::new(epsilon, 0.0))
+    }
+
+    /// Returns `true` if the matrix is square and every entry off the diagonal is zero within the
+    /// given tolerance: at most its absolute part, or negligible (by [`Tolerance::is_negligible`])
+    /// next to the largest entry of the matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2e9, 1e-3], vec![0.0, 3e9]]);
+    /// assert!(a.is_diagonal_with_tolerance(Tolerance::DEFAULT));
+    /// assert!(!a.is_diagonal(1e-6));
+    /// ```
+    pub fn is_diagonal_with_tolerance(&self, tolerance: Tolerance) -> bool {
+        let scale = self.data.iter().fold(0.0_f64, |m, v| m.max(v.abs()));
+        self.is_square() && self.enumerate_cells().all(|((i, j), &x)| i == j || x.abs() <= tolerance.absolute || tolerance.is_negligible(x, scale))
+    }
+
+    /// Returns `true` if the matrix is square and its columns are orthonormal, that is, if
+    /// `Aᵀ·A` differs from the identity by at most `epsilon` in every entry.
+    ///
+    /// This is a shorthand for [`Matrix::is_orthogonal_with_tolerance`] with a purely absolute
+    /// tolerance.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let (s, c) = 0.3_f64.sin_cos();
+    /// let rotation = Matrix::new(2, 2, vec![vec![c, -s], vec![s, c]]);
+    /// assert!(rotation.is_orthogonal(1e-12));
+    /// assert!(!rotation.scale(2.0).is_orthogonal(1e-12));
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time, since it forms `Aᵀ·A`.
+    pub fn is_orthogonal(&self, epsilon: f64) -> bool {
+        self.is_orthogonal_with_tolerance(Tolerance::new(epsilon, 0.0))
+    }
+
+    /// Returns `true` if the matrix is square and `Aᵀ·A` equals the identity within the given
+    /// tolerance in every entry.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    /// use hell::Matrix;
+    ///
+    /// let (s, c) = 0.3_f64.sin_cos();
+    /// let rotation = Matrix::new(2, 2, vec![vec![c, -s], vec![s, c]]);
+    /// assert!(rotation.is_orthogonal_with_tolerance(Tolerance::DEFAULT));
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n³)` time, since it forms `Aᵀ·A`.
+    pub fn is_orthogonal_with_tolerance(&self, tolerance: Tolerance) -> bool {
+        self.is_square() && self.transpose().multiply(self).is_ok_and(|product| product.approx_eq_with_tolerance(&Matrix::identity(self.rows), tolerance))
+    }
+
+    /// Returns `true` if the matrix is symmetric (within the default tolerance) and positive
+    /// definite, that is, if `xᵀ·A·x > 0` for every nonzero vector `x`.
+    ///
+    /// This is exactly the condition under which [`Matrix::cholesky`] succeeds, and it is
+    /// tested by attempting that factorization.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::new(2, 2, vec![vec![2.0, -1.0], vec![-1.0, 2.0]]);
+    /// assert!(a.is_positive_definite());
+    ///
+    /// let indefinite = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![2.0, 1.0]]);
+    /// assert!(!indefinite.is_positive_definite());
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in about `n³ / 3` multiply-adds.
+    pub fn is_positive_definite(&self) -> bool {
+        self.cholesky().is_ok()
+    }
+
+    /// Multiplies two matrices with Strassen's algorithm, falling back to the cache-blocked
+    /// [`Matrix::multiply`] for blocks whose dimensions are all at most `threshold`.
+    ///
+    /// Strassen's algorithm splits each operand into four blocks and forms the product from seven
+    /// block multiplications instead of eight, bringing the cost down from `O(n³)` to about
+    /// `O(n^2.81)`. Below some size the extra additions outweigh the saved multiplication, which
+    /// is what `threshold` controls; values of a few hundred are typical, and the best choice
+    /// depends on the machine. Odd dimensions are padded with a zero row or column at each level.
+    ///
+    /// # Arguments
+    /// - `other`: The matrix to multiply with the current matrix.
+    /// - `threshold`: The size at or below which blocks are multiplied directly with the blocked
+    ///   kernel of [`Matrix::multiply`].
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The product.
+    /// - `Err(HellError::DimensionMismatch)`: If the number of columns of `self` differs from the
+    ///   number of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::matrix::STRASSEN_THRESHOLD;
+    /// use hell::Matrix;
+    ///
+    /// let a = Matrix::from_fn(100, 80, |i, j| ((i * 7 + j * 3) % 10) as f64);
+    /// let b = Matrix::from_fn(80, 90, |i, j| ((i + 2 * j) % 5) as f64 - 2.0);
+    /// let fast = a.multiply_strassen(&b, 16).unwrap();
+    /// assert!(fast.approx_eq(&a.multiply(&b).unwrap(), 1e-9));
+    ///
+    /// // Above the default threshold, `multiply` takes this path by itself.
+    /// let n = STRASSEN_THRESHOLD + 2;
+    /// let a = Matrix::from_fn(n, n, |i, j| ((i * 7 + j * 3) % 10) as f64);
+    /// assert_eq!(a.multiply(&a), a.multiply_strassen(&a, STRASSEN_THRESHOLD));
+    /// ```
+    ///
+    /// # Notes
+    /// The seven products combine blocks with subtractions, so rounding errors are bounded in norm
+    /// rather than element by element, and small elements of the product can carry larger relative
+    /// errors than with the blocked kernel. [`Matrix::multiply`] only switches to Strassen on its
+    /// own above [`STRASSEN_THRESHOLD`], where the speedup is worth it.
+    pub fn multiply_strassen(&self, other: &Matrix, threshold: usize) -> Result<Matrix, HellError> {
+        if self.cols != other.rows {
+            return Err(HellError::DimensionMismatch("Matrices dimensions do not match for multiplication."));
+        }
+        let (m, k, n) = (self.rows, self.cols, other.cols);
+        if m.min(k).min(n) <= threshold.max(1) {
+            return Ok(self.multiply_blocked(other));
+        }
+
+        let (a, b) = (self.pad_to_even(), other.pad_to_even());
+        let (hm, hk, hn) = (a.rows / 2, a.cols / 2, b.cols / 2);
+        let a11 = a.submatrix(..hm, ..hk);
+        let a12 = a.submatrix(..hm, hk..);
+        let a21 = a.submatrix(hm.., ..hk);
+        let a22 = a.submatrix(hm.., hk..);
+        let b11 = b.submatrix(..hk, ..hn);
+        let b12 = b.submatrix(..hk, hn..);
+        let b21 = b.submatrix(hk.., ..hn);
+        let b22 = b.submatrix(hk.., hn..);
+
+        let product = |x: &Matrix, y: &Matrix| x.multiply_strassen(y, threshold);
+        let m1 = product(&(&a11 + &a22), &(&b11 + &b22))?;
+        let m2 = product(&(&a21 + &a22), &b11)?;
+        let m3 = product(&a11, &(&b12 - &b22))?;
+        let m4 = product(&a22, &(&b21 - &b11))?;
+        let m5 = product(&(&a11 + &a12), &b22)?;
+        let m6 = product(&(&a21 - &a11), &(&b11 + &b12))?;
+        let m7 = product(&(&a12 - &a22), &(&b21 + &b22))?;
+
+        let c11 = &(&(&m1 + &m4) - &m5) + &m7;
+        let c12 = &m3 + &m5;
+        let c21 = &m2 + &m4;
+        let c22 = &(&(&m1 - &m2) + &m3) + &m6;
+        let c = c11.hstack(&c12)?.vstack(&c21.hstack(&c22)?)?;
+        Ok(c.submatrix(..m, ..n))
+    }
+
+    /// Returns the matrix with a zero row and column appended where needed to make both
+    /// dimensions even.
+    fn pad_to_even(&self) -> Cow<'_, Matrix> {
+        let (rows, cols) = (self.rows, self.cols);
+        if rows.is_multiple_of(2) && cols.is_multiple_of(2) {
+            return Cow::Borrowed(self);
+        }
+        Cow::Owned(Matrix::from_fn(rows + rows % 2, cols + cols % 2, |i, j| if i < rows && j < cols { self[(i, j)] } else { 0.0 }))
+    }
+}
+
+/// Asserts that two `f64` matrices are approximately equal, printing both on failure.
+///
+/// With two arguments, elements are compared using [`Tolerance::DEFAULT`]. A third argument gives
+/// the largest allowed absolute difference per element, as in [`Matrix::approx_eq`]. Matrices of
+/// different shapes never compare equal.
+///
+/// # Examples
+/// ```
+/// use hell::{assert_matrix_eq, Matrix};
+///
+/// let a = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0, 3.0]]);
+/// let lu = a.lu().unwrap();
+/// assert_matrix_eq!(&lu.l * &lu.u, a);
+/// assert_matrix_eq!(a.scale(1.0 + 1e-9), a, 1e-8);
+/// ```
+///
+/// ```should_panic
+/// use hell::{assert_matrix_eq, Matrix};
+///
+/// assert_matrix_eq!(Matrix::identity(2), Matrix::zeros(2, 2));
+/// ```
+#[macro_export]
+macro_rules! assert_matrix_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::Matrix::approx_eq_with_tolerance(left, right, $crate::tolerance::Tolerance::DEFAULT) {
+                    panic!("assertion `left ≈ right` failed\n  left: {:?}\n right: {:?}", left, right);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $epsilon:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                if !$crate::Matrix::approx_eq(left, right, $epsilon) {
+                    panic!("assertion `left ≈ right` failed (epsilon = {:?})\n  left: {:?}\n right: {:?}", $epsilon, left, right);
+                }
+            }
+        }
+    };
+}
+
+/// Adds two matrices, as [`Matrix::add`].
+///
+/// # Panics
+/// Panics if the dimensions do not match.
+impl<T: Scalar> Add for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn add(self, other: &Matrix<T>) -> Matrix<T> {
+        Matrix::add(self, other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Subtracts two matrices, as [`Matrix::sub`].
+///
+/// # Panics
+/// Panics if the dimensions do not match.
+impl<T: Scalar> Sub for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, other: &Matrix<T>) -> Matrix<T> {
+        Matrix::sub(self, other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+/// Multiplies two matrices, as [`Matrix::multiply`].
+///
+/// # Panics
+/// Panics if the number of columns of the left matrix differs from the number of rows of the
+/// right one.
+impl<T: Scalar> Mul for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, other: &Matrix<T>) -> Matrix<T> {
+        self.multiply(other).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Scalar> Mul<T> for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, k: T) -> Matrix<T> {
+        self.scale(k)
+    }
+}
+
+impl Mul<&Matrix> for f64 {
+    type Output = Matrix;
+
+    fn mul(self, matrix: &Matrix) -> Matrix {
+        matrix.scale(self)
+    }
+}
+
+impl<T: Scalar + Neg<Output = T>> Neg for &Matrix<T> {
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Matrix<T> {
+        Matrix::neg(self)
+    }
+}
+
+/// Reads the element at `(row, column)`.
+///
+/// # Panics
+/// Panics if the index is out of bounds.
+impl<T> Index<(usize, usize)> for Matrix<T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &self.data[i * self.cols + j]
+    }
+}
+
+/// Writes the element at `(row, column)`.
+///
+/// # Panics
+/// Panics if the index is out of bounds.
+impl<T> IndexMut<(usize, usize)> for Matrix<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &mut self.data[i * self.cols + j]
+    }
+}
+
+/// Formats the matrix one row per line, with each column right-aligned to its widest element.
+///
+/// A precision in the format string, as in `{:.2}`, is applied to every element.
+///
+/// # Examples
+/// ```
+/// use hell::Matrix;
+///
+/// let matrix = Matrix::new(2, 3, vec![vec![1.0, -2.5, 3.0], vec![10.0, 0.25, -6.0]]);
+/// assert_eq!(matrix.to_string(), "[  1  -2.5   3 ]\n[ 10  0.25  -6 ]");
+/// assert_eq!(format!("{:.1}", matrix), "[  1.0  -2.5   3.0 ]\n[ 10.0   0.2  -6.0 ]");
+/// ```
+impl<T: fmt::Display> fmt::Display for Matrix<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cells: Vec<String> = match f.precision() {
+            Some(precision) => self.data.iter().map(|v| format!("{:.*}", precision, v)).collect(),
+            None => self.data.iter().map(|v| v.to_string()).collect(),
+        };
+        let mut widths = vec![0; self.cols];
+        for (k, cell) in cells.iter().enumerate() {
+            let width = &mut widths[k % self.cols];
+            *width = (*width).max(cell.chars().count());
+        }
+        for (i, row) in cells.chunks(self.cols.max(1)).take(self.rows).enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[ ")?;
+            for (j, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                let separator = if j == 0 { "" } else { "  " };
+                write!(f, "{}{:>width$}", separator, cell, width = width)?;
+            }
+            write!(f, " ]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a range argument into concrete bounds within `0..len`.
+///
+/// # Panics
+/// Panics if the range is decreasing or extends past `len`.
+fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    assert!(start <= end && end <= len, "Matrix range out of bounds.");
+    start..end
+}
+
+/// A read-only view of a rectangular block of a [`Matrix`], as returned by [`Matrix::view`].
+///
+/// A view borrows the matrix it was taken from and copies nothing; rows of a view are slices into
+/// the original storage. Use [`MatrixView::to_matrix`] to copy the block out.
+///
+/// # Examples
+/// ```
+/// use hell::Matrix;
+///
+/// let matrix = Matrix::from_fn(4, 4, |i, j| (4 * i + j) as f64);
+/// let center = matrix.view(1..3, 1..3);
+/// assert_eq!(center.to_matrix().to_rows(), vec![vec![5.0, 6.0], vec![9.0, 10.0]]);
+///
+/// // Views of views stay relative to the view.
+/// assert_eq!(center.view(1.., 1..)[(0, 0)], 10.0);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T = f64> {
+    matrix: &'a Matrix<T>,
+    row_start: usize,
+    col_start: usize,
+    rows: usize,
+    cols: usize,
+}
+
+impl<'a, T: Scalar> MatrixView<'a, T> {
+    /// Returns the dimensions of the view as `(rows, cols)`.
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Returns row `i` of the view as a slice of the underlying matrix.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> &'a [T] {
+        assert!(i < self.rows, "Row index out of bounds.");
+        &self.matrix.row_slice(self.row_start + i)[self.col_start..self.col_start + self.cols]
+    }
+
+    /// Returns a copy of column `j` of the view.
+    ///
+    /// # Panics
+    /// Panics if `j` is out of bounds.
+    pub fn col(&self, j: usize) -> Vec<T> {
+        assert!(j < self.cols, "Column index out of bounds.");
+        (0..self.rows).map(|i| self.row(i)[j]).collect()
+    }
+
+    /// Returns a view of a block of this view, with ranges relative to the view.
+    ///
+    /// # Panics
+    /// Panics if either range is decreasing or extends past the end of the view.
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'a, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let rows = resolve_range(rows, self.rows);
+        let cols = resolve_range(cols, self.cols);
+        MatrixView {
+            matrix: self.matrix,
+            row_start: self.row_start + rows.start,
+            col_start: self.col_start + cols.start,
+            rows: rows.len(),
+            cols: cols.len(),
+        }
+    }
+
+    /// Copies the viewed block into a new matrix.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let data = (0..self.rows).flat_map(|i| self.row(i).iter().copied()).collect();
+        Matrix::from_row_major(self.rows, self.cols, data)
+    }
+}
+
+/// Reads the element at `(row, column)` of the view.
+///
+/// # Panics
+/// Panics if the index is out of bounds.
+impl<T: Scalar> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &self.matrix[(self.row_start + i, self.col_start + j)]
+    }
+}
+
+/// Selects the lower or upper triangle of a square matrix, as used by
+/// [`Matrix::solve_triangular`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Triangle {
+    /// The elements on and below the diagonal.
+    Lower,
+    /// The elements on and above the diagonal.
+    Upper,
+}
+
+/// The LU decomposition `P·A = L·U` of a square matrix, as returned by [`Matrix::lu`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Lu {
+    /// The unit lower-triangular factor.
+    pub l: Matrix,
+    /// The upper-triangular factor.
+    pub u: Matrix,
+    /// The row permutation: row `i` of `P·A` is row `permutation[i]` of `A`.
+    pub permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl Lu {
+    /// Solves `A·x = b` using the factorization, by forward and back substitution.
+    ///
+    /// # Arguments
+    /// - `b`: The right-hand side, with one entry per row of `A`.
+    ///
+    /// # Returns
+    /// - `Ok(x)`: The solution vector.
+    /// - `Err(HellError::DimensionMismatch)`: If `b` has the wrong length.
+    /// - `Err(HellError::ConvergenceFailure)`: If `A` is singular, so the system has no unique
+    ///   solution.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let lu = Matrix::new(2, 2, vec![vec![2.0, 1.0], vec![1.0, 3.0]]).lu().unwrap();
+    /// for b in [[3.0, 4.0], [1.0, -2.0]] {
+    ///     let x = lu.solve(&b).unwrap();
+    ///     assert!((2.0 * x[0] + x[1] - b[0]).abs() < 1e-12);
+    ///     assert!((x[0] + 3.0 * x[1] - b[1]).abs() < 1e-12);
+    /// }
+    /// ```
+    ///
+    /// # Performance
+    /// Runs in `O(n²)` time.
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, HellError> {
+        let n = self.permutation.len();
+        if b.len() != n {
+            return Err(HellError::DimensionMismatch("Right-hand side length does not match the matrix."));
+        }
+        let mut y: Vec<f64> = self.permutation.iter().map(|&i| b[i]).collect();
+        for i in 0..n {
+            let sum: f64 = (0..i).map(|j| self.l.at(i, j) * y[j]).sum();
+            y[i] -= sum;
+        }
+        for i in (0..n).rev() {
+            let pivot = self.u.at(i, i);
+            if pivot == 0.0 {
+                return Err(HellError::ConvergenceFailure("Matrix is singular."));
+            }
+            let sum: f64 = (i + 1..n).map(|j| self.u.at(i, j) * y[j]).sum();
+            y[i] = (y[i] - sum) / pivot;
+        }
+        Ok(y)
+    }
+
+    /// Returns the determinant of `A`: the product of the diagonal of `U`, negated if the
+    /// permutation is odd.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let lu = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]).lu().unwrap();
+    /// assert!((lu.determinant() + 2.0).abs() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> f64 {
+        let product: f64 = (0..self.permutation.len()).map(|i| self.u.at(i, i)).product();
+        if self.swaps.is_multiple_of(2) {
+            product
+        } else {
+            -product
+        }
+    }
+
+    /// Returns the sign and the natural logarithm of the absolute value of the determinant of
+    /// `A`, as `(sign, ln|det|)`, so that `det = sign · exp(ln|det|)`.
+    ///
+    /// For a singular matrix the result is `(0.0, f64::NEG_INFINITY)`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let lu = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.0]]).lu().unwrap();
+    /// let (sign, log_abs) = lu.log_determinant();
+    /// assert_eq!(sign, -1.0);
+    /// assert!((log_abs - 2.0_f64.ln()).abs() < 1e-12);
+    /// ```
+    pub fn log_determinant(&self) -> (f64, f64) {
+        let mut sign = if self.swaps.is_multiple_of(2) { 1.0 } else { -1.0 };
+        let mut log_abs = 0.0;
+        for i in 0..self.permutation.len() {
+            let pivot = self.u.at(i, i);
+            if pivot == 0.0 {
+                return (0.0, f64::NEG_INFINITY);
+            }
+            sign *= pivot.signum();
+            log_abs += pivot.abs().ln();
+        }
+        (sign, log_abs)
+    }
+}
+
+/// Reduces a square matrix to upper Hessenberg form by Gaussian elimination with pivoting,
+/// applied as a similarity transform so that the eigenvalues are unchanged.
+fn reduce_to_hessenberg(mut a: Vec<Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = a.len();
+    for m in 1..n.saturating_sub(1) {
+        let pivot_row = (m..n).max_by(|&i, &j| a[i][m - 1].abs().total_cmp(&a[j][m - 1].abs())).unwrap();
+        let x = a[pivot_row][m - 1];
+        if pivot_row != m {
+            a.swap(pivot_row, m);
+            for row in a.iter_mut() {
+                row.swap(pivot_row, m);
+            }
+        }
+        if x == 0.0 {
+            continue;
+        }
+        for i in m + 1..n {
+            let y = a[i][m - 1] / x;
+            if y == 0.0 {
+                continue;
+            }
+            a[i][m - 1] = 0.0;
+            let (upper, lower) = a.split_at_mut(i);
+            for (value, &pivot_value) in lower[0][m..].iter_mut().zip(&upper[m][m..]) {
+                *value -= y * pivot_value;
+            }
+            for row in a.iter_mut() {
+                row[m] += y * row[i];
+            }
+        }
+    }
+    a
+}
+
+/// Finds all eigenvalues of an upper Hessenberg matrix with Francis double-shift QR steps.
+///
+/// Returns `None` if some eigenvalue needs more than 30 steps to deflate.
+fn hessenberg_qr(mut a: Vec<Vec<f64>>) -> Option<Vec<Complex>> {
+    let n = a.len();
+    let mut values = Vec::with_capacity(n);
+    let norm: f64 = (0..n).map(|i| a[i][i.saturating_sub(1)..].iter().map(|v| v.abs()).sum::<f64>()).sum();
+    // Total of the exceptional shifts applied so far.
+    let mut shift = 0.0;
+    // The active block is rows and columns 0..end.
+    let mut end = n;
+    while end > 0 {
+        let mut iterations = 0;
+        loop {
+            let last = end - 1;
+            // Look for a negligible subdiagonal entry to split the active block at.
+            let mut l = last;
+            while l > 0 {
+                let mut s = a[l - 1][l - 1].abs() + a[l][l].abs();
+                if s == 0.0 {
+                    s = norm;
+                }
+                if a[l][l - 1].abs() + s == s {
+                    a[l][l - 1] = 0.0;
+                    break;
+                }
+                l -= 1;
+            }
+            let x = a[last][last];
+            if l == last {
+                // A 1 × 1 block has deflated.
+                values.push(Complex::new(x + shift, 0.0));
+                end -= 1;
+                break;
+            }
+            let y = a[last - 1][last - 1];
+            let w = a[last][last - 1] * a[last - 1][last];
+            if l == last - 1 {
+                // A 2 × 2 block has deflated: solve its characteristic quadratic.
+                let p = 0.5 * (y - x);
+                let q = p * p + w;
+                let z = q.abs().sqrt();
+                let x = x + shift;
+                if q >= 0.0 {
+                    let z = p + z.copysign(p);
+                    values.push(Complex::new(x + z, 0.0));
+                    values.push(Complex::new(if z != 0.0 { x - w / z } else { x + z }, 0.0));
+                } else {
+                    values.push(Complex::new(x + p, z));
+                    values.push(Complex::new(x + p, -z));
+                }
+                end -= 2;
+                break;
+            }
+            if iterations == 30 {
+                return None;
+            }
+            let (mut x, mut y, mut w) = (x, y, w);
+            if iterations == 10 || iterations == 20 {
+                // Exceptional shift to break out of a cycle.
+                shift += x;
+                for (i, row) in a.iter_mut().enumerate().take(end) {
+                    row[i] -= x;
+                }
+                let s = a[last][last - 1].abs() + a[last - 1][last - 2].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+            iterations += 1;
+
+            // Find where to start the double-shift step: two consecutive small subdiagonals.
+            let mut m = last - 2;
+            let (mut p, mut q, mut r);
+            loop {
+                let z = a[m][m];
+                let r0 = x - z;
+                let s0 = y - z;
+                p = (r0 * s0 - w) / a[m + 1][m] + a[m][m + 1];
+                q = a[m + 1][m + 1] - z - r0 - s0;
+                r = a[m + 2][m + 1];
+                let s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                let u = a[m][m - 1].abs() * (q.abs() + r.abs());
+                let v = p.abs() * (a[m - 1][m - 1].abs() + z.abs() + a[m + 1][m + 1].abs());
+                if u + v == v {
+                    break;
+                }
+                m -= 1;
+            }
+            for i in m + 2..end {
+                a[i][i - 2] = 0.0;
+                if i != m + 2 {
+                    a[i][i - 3] = 0.0;
+                }
+            }
+
+            // Chase the bulge down the block with Householder reflections.
+            for k in m..last {
+                if k != m {
+                    p = a[k][k - 1];
+                    q = a[k + 1][k - 1];
+                    r = if k != last - 1 { a[k + 2][k - 1] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x != 0.0 {
+                        p /= x;
+                        q /= x;
+                        r /= x;
+                    }
+                }
+                let s = (p * p + q * q + r * r).sqrt().copysign(p);
+                if s == 0.0 {
+                    continue;
+                }
+                if k == m {
+                    if l != m {
+                        a[k][k - 1] = -a[k][k - 1];
+                    }
+                } else {
+                    a[k][k - 1] = -s * x;
+                }
+                p += s;
+                x = p / s;
+                y = q / s;
+                let z = r / s;
+                q /= p;
+                r /= p;
+                let (upper, lower) = a.split_at_mut(k + 1);
+                let (middle, lower) = lower.split_at_mut(1);
+                let (row_k, row_k1) = (&mut upper[k], &mut middle[0]);
+                for j in k..end {
+                    let mut p = row_k[j] + q * row_k1[j];
+                    if k != last - 1 {
+                        p += r * lower[0][j];
+                        lower[0][j] -= p * z;
+                    }
+                    row_k1[j] -= p * y;
+                    row_k[j] -= p * x;
+                }
+                for row in a.iter_mut().take(last.min(k + 3) + 1).skip(l) {
+                    let mut p = x * row[k] + y * row[k + 1];
+                    if k != last - 1 {
+                        p += z * row[k + 2];
+                        row[k + 2] -= p * r;
+                    }
+                    row[k + 1] -= p * q;
+                    row[k] -= p;
+                }
+            }
+        }
+    }
+    Some(values)
+}
+
+/// Solves the square linear system `a · x = b` by Gaussian elimination with partial pivoting.
+///
+/// This is the shared elimination kernel used by other modules of the crate. It consumes its
+/// inputs, which are overwritten during elimination. A pivot is treated as zero when it is
+/// negligible under `tolerance` relative to the largest entry of `a`.
+///
+/// # Returns
+/// - `Some(x)`: the solution vector.
+/// - `None`: if the dimensions are inconsistent or the system is singular.
+pub(crate) fn solve_linear_system(mut a: Vec<Vec<f64>>, mut b: Vec<f64>, tolerance: Tolerance) -> Option<Vec<f64>> {
+    let n = b.len();
+    if a.len() != n || a.iter().any(|row| row.len() != n) {
+        return None;
+    }
+    let scale = a.iter().flatten().fold(0.0_f64, |m, v| m.max(v.abs()));
+
+    for col in 0..n {
+        let pivot = (col..n).max_by(|&i, &j| a[i][col].abs().total_cmp(&a[j][col].abs()))?;
+        if tolerance.is_negligible(a[pivot][col], scale) {
+            return None;
+        }
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            let factor = row[col] / pivot_row[col];
+            if factor == 0.0 {
+                continue;
+            }
+            for (value, &p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= factor * p;
+            }
+            b[col + 1 + offset] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for i in (0..n).rev() {
+        let sum: f64 = a[i][i + 1..].iter().zip(&x[i + 1..]).map(|(a, x)| a * x).sum();
+        x[i] = (b[i] - sum) / a[i][i];
+    }
+    Some(x)
+}
+
+/// Solves the square linear system `a · x = b` exactly, by Gaussian elimination over the rationals.
+///
+/// Because every operation is carried out on arbitrary-precision fractions there is no rounding
+/// error at all: the result is the exact solution, and a system is reported as singular only when
+/// it truly is. It is only available with the `bignum` feature.
+///
+/// # Arguments
+/// - `a`: The coefficient matrix as a list of rows. It must be square.
+/// - `b`: The right-hand side, with one entry per row of `a`.
+///
+/// # Returns
+/// - `Ok(x)`: The exact solution vector.
+/// - `Err(HellError::DimensionMismatch)`: If `a` is not square or `b` has the wrong length.
+/// - `Err(HellError::ConvergenceFailure)`: If the system is singular and has no unique solution.
+///
+/// # Examples
+/// ```
+/// # #[cfg(feature = "bignum")] {
+/// use hell::matrix::solve_rational;
+/// use num_rational::BigRational;
+///
+/// let r = |n: i64, d: i64| BigRational::new(n.into(), d.into());
+///
+/// // x / 3 + y = 1, x - y = 1/3  =>  x = 1, y = 2/3
+/// let a = vec![vec![r(1, 3), r(1, 1)], vec![r(1, 1), r(-1, 1)]];
+/// let b = vec![r(1, 1), r(1, 3)];
+/// assert_eq!(solve_rational(a, b), Ok(vec![r(1, 1), r(2, 3)]));
+/// # }
+/// ```
+///
+/// # Performance
+/// Elimination takes `O(n³)` operations, but the numerators and denominators can grow with each
+/// step, so this is much slower than floating-point elimination and best suited to small systems.
+#[cfg(feature = "bignum")]
+pub fn solve_rational(
+    mut a: Vec<Vec<num_rational::BigRational>>,
+    mut b: Vec<num_rational::BigRational>,
+) -> Result<Vec<num_rational::BigRational>, HellError> {
+    use num_traits::Zero;
+
+    let n = b.len();
+    if a.len() != n || a.iter().any(|row| row.len() != n) {
+        return Err(HellError::DimensionMismatch("Coefficient matrix must be square and match the right-hand side."));
+    }
+
+    for col in 0..n {
+        let pivot = (col..n)
+            .find(|&i| !a[i][col].is_zero())
+            .ok_or(HellError::ConvergenceFailure("Linear system is singular."))?;
+        a.swap(col, pivot);
+        b.swap(col, pivot);
+
+        let (upper, lower) = a.split_at_mut(col + 1);
+        let pivot_row = &upper[col];
+        for (offset, row) in lower.iter_mut().enumerate() {
+            if row[col].is_zero() {
+                continue;
+            }
+            let factor = &row[col] / &pivot_row[col];
+            for (value, p) in row[col..].iter_mut().zip(&pivot_row[col..]) {
+                *value -= &factor * p;
+            }
+            let update = &factor * &b[col];
+            b[col + 1 + offset] -= update;
+        }
+    }
+
+    let mut x = vec![num_rational::BigRational::zero(); n];
+    for i in (0..n).rev() {
+        let sum = a[i][i + 1..]
+            .iter()
+            .zip(&x[i + 1..])
+            .fold(num_rational::BigRational::zero(), |acc, (a, x)| acc + a * x);
+        x[i] = (&b[i] - sum) / &a[i][i];
+    }
+    Ok(x)
 }
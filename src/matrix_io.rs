@@ -0,0 +1,358 @@
+//! Reading and writing [`Matrix`] in the NumPy `.npy` and Matrix Market `.mtx` file formats.
+//!
+//! The functions here convert between matrices and the bytes or text of a file, and leave the
+//! file system to the caller, so they work the same on disk, over the network, or in memory.
+
+use crate::error::HellError;
+use crate::matrix::Matrix;
+
+/// The magic string at the start of every `.npy` file.
+const NPY_MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The alignment of the data section of a `.npy` file written by [`Matrix::to_npy`].
+const NPY_ALIGNMENT: usize = 64;
+
+impl Matrix {
+    /// Encodes the matrix as the contents of a NumPy `.npy` file.
+    ///
+    /// The array is written in format version 1.0 as a two-dimensional C-order array of
+    /// little-endian `f64` values, which `numpy.load` reads back as a `float64` array of shape
+    /// `(rows, cols)`.
+    ///
+    /// # Returns
+    /// The bytes of the file, ready to be written with `std::fs::write`.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// let bytes = matrix.to_npy();
+    /// assert!(bytes.starts_with(b"\x93NUMPY"));
+    /// assert_eq!(Matrix::from_npy(&bytes).unwrap(), matrix);
+    /// ```
+    pub fn to_npy(&self) -> Vec<u8> {
+        let (rows, cols) = self.shape();
+        let mut header = format!("{{'descr': '<f8', 'fortran_order': False, 'shape': ({}, {}), }}", rows, cols);
+        // The magic string, version and length field take 10 bytes, and the header ends in '\n'.
+        let unpadded = NPY_MAGIC.len() + 4 + header.len() + 1;
+        header.push_str(&" ".repeat(unpadded.next_multiple_of(NPY_ALIGNMENT) - unpadded));
+        header.push('\n');
+
+        let mut bytes = Vec::with_capacity(NPY_MAGIC.len() + 4 + header.len() + 8 * rows * cols);
+        bytes.extend_from_slice(NPY_MAGIC);
+        bytes.extend_from_slice(&[1, 0]);
+        bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        bytes.extend_from_slice(header.as_bytes());
+        for i in 0..rows {
+            for value in self.row(i) {
+                bytes.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        bytes
+    }
+
+    /// Decodes a matrix from the contents of a NumPy `.npy` file.
+    ///
+    /// Files of format versions 1.0 to 3.0 are accepted, in C or Fortran order, with elements of
+    /// any byte order stored as floats (`f4`, `f8`) or integers (`i1` to `i8`, `u1` to `u8`).
+    /// Elements are converted to `f64`. A two-dimensional array of shape `(rows, cols)` becomes a
+    /// `rows × cols` matrix, a one-dimensional array of length `n` becomes a `1 × n` row, and a
+    /// zero-dimensional array becomes a `1 × 1` matrix.
+    ///
+    /// # Arguments
+    /// - `bytes`: The contents of the file, for example from `std::fs::read`.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The decoded matrix.
+    /// - `Err(HellError::ParseError)`: If the bytes are not a valid `.npy` file, or the element
+    ///   type is not supported (such as complex numbers or Python objects).
+    /// - `Err(HellError::DimensionMismatch)`: If the array has more than two dimensions, or the
+    ///   data section does not match its shape.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// // numpy.save of numpy.array([[1, 2], [3, 4]], dtype=numpy.int32).T
+    /// let mut bytes = b"\x93NUMPY\x01\x00\x76\x00".to_vec();
+    /// let header = "{'descr': '<i4', 'fortran_order': True, 'shape': (2, 2), }";
+    /// bytes.extend_from_slice(format!("{:<117}\n", header).as_bytes());
+    /// for value in [1i32, 2, 3, 4] {
+    ///     bytes.extend_from_slice(&value.to_le_bytes());
+    /// }
+    ///
+    /// let matrix = Matrix::from_npy(&bytes).unwrap();
+    /// assert_eq!(matrix.to_rows(), vec![vec![1.0, 3.0], vec![2.0, 4.0]]);
+    ///
+    /// assert!(Matrix::from_npy(b"not a numpy file").is_err());
+    /// ```
+    pub fn from_npy(bytes: &[u8]) -> Result<Matrix, HellError> {
+        let rest = bytes.strip_prefix(NPY_MAGIC).ok_or(HellError::ParseError("Missing NumPy magic string."))?;
+        let (header_len, rest) = match rest {
+            [1, _, a, b, rest @ ..] => (u16::from_le_bytes([*a, *b]) as usize, rest),
+            [2 | 3, _, a, b, c, d, rest @ ..] => (u32::from_le_bytes([*a, *b, *c, *d]) as usize, rest),
+            _ => return Err(HellError::ParseError("Unsupported NumPy format version.")),
+        };
+        if rest.len() < header_len {
+            return Err(HellError::ParseError("Truncated NumPy header."));
+        }
+        let (header, data) = rest.split_at(header_len);
+        let header = std::str::from_utf8(header).map_err(|_| HellError::ParseError("NumPy header is not valid text."))?;
+
+        let descr = npy_header_value(header, "descr")?;
+        let element = NpyElement::parse(descr.trim_matches(|c| c == '\'' || c == '"'))?;
+        let fortran_order = match npy_header_value(header, "fortran_order")? {
+            "True" => true,
+            "False" => false,
+            _ => return Err(HellError::ParseError("Invalid fortran_order in NumPy header.")),
+        };
+        let shape = npy_header_value(header, "shape")?
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .ok_or(HellError::ParseError("Invalid shape in NumPy header."))?
+            .split(',')
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+            .map(|s| s.parse::<usize>().map_err(|_| HellError::ParseError("Invalid shape in NumPy header.")))
+            .collect::<Result<Vec<_>, _>>()?;
+        let (rows, cols) = match shape[..] {
+            [] => (1, 1),
+            [n] => (1, n),
+            [rows, cols] => (rows, cols),
+            _ => return Err(HellError::DimensionMismatch("Only arrays with at most two dimensions can be read as a matrix.")),
+        };
+
+        let len = rows.checked_mul(cols).ok_or(HellError::DimensionMismatch("NumPy array is too large."))?;
+        if data.len() != len.saturating_mul(element.size) {
+            return Err(HellError::DimensionMismatch("NumPy data length does not match the array shape."));
+        }
+        let values: Vec<f64> = data.chunks_exact(element.size).map(|chunk| element.decode(chunk)).collect();
+        if fortran_order {
+            Ok(Matrix::from_flat(cols, rows, values).transpose())
+        } else {
+            Ok(Matrix::from_flat(rows, cols, values))
+        }
+    }
+
+    /// Encodes the matrix in the Matrix Market exchange format.
+    ///
+    /// The matrix is written as a dense `array real general` matrix, which lists the elements in
+    /// column-major order. `scipy.io.mmread` reads it back as a NumPy array. Values are printed
+    /// with the shortest representation that parses back to the same `f64`.
+    ///
+    /// # Returns
+    /// The text of the `.mtx` file.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let matrix = Matrix::new(2, 2, vec![vec![1.0, 2.0], vec![3.0, 4.5]]);
+    /// assert_eq!(
+    ///     matrix.to_matrix_market(),
+    ///     "%%MatrixMarket matrix array real general\n2 2\n1\n3\n2\n4.5\n"
+    /// );
+    /// ```
+    pub fn to_matrix_market(&self) -> String {
+        let (rows, cols) = self.shape();
+        let mut text = format!("%%MatrixMarket matrix array real general\n{} {}\n", rows, cols);
+        for j in 0..cols {
+            for i in 0..rows {
+                text.push_str(&format!("{}\n", self[(i, j)]));
+            }
+        }
+        text
+    }
+
+    /// Decodes a matrix in the Matrix Market exchange format.
+    ///
+    /// Both the sparse `coordinate` and the dense `array` formats are accepted, with `real`,
+    /// `integer` or `pattern` fields (pattern entries are read as `1.0`) and `general`,
+    /// `symmetric` or `skew-symmetric` symmetry. Entries missing from a coordinate file are zero,
+    /// and repeated coordinate entries are added together.
+    ///
+    /// # Arguments
+    /// - `text`: The contents of the `.mtx` file.
+    ///
+    /// # Returns
+    /// - `Ok(Matrix)`: The decoded matrix.
+    /// - `Err(HellError::ParseError)`: If the text is not a valid Matrix Market file, or uses an
+    ///   unsupported variant such as `complex` fields.
+    /// - `Err(HellError::DimensionMismatch)`: If the number of entries or an entry index does not
+    ///   match the declared size.
+    ///
+    /// # Examples
+    /// ```
+    /// use hell::Matrix;
+    ///
+    /// let text = "\
+    /// %%MatrixMarket matrix coordinate real symmetric
+    /// % A 3x3 symmetric matrix with four stored entries.
+    /// 3 3 4
+    /// 1 1 2.0
+    /// 2 1 -1.0
+    /// 2 2 2.0
+    /// 3 3 5e-1
+    /// ";
+    /// let matrix = Matrix::from_matrix_market(text).unwrap();
+    /// assert_eq!(matrix.to_rows(), vec![
+    ///     vec![2.0, -1.0, 0.0],
+    ///     vec![-1.0, 2.0, 0.0],
+    ///     vec![0.0, 0.0, 0.5],
+    /// ]);
+    ///
+    /// let dense = Matrix::new(2, 3, vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+    /// assert_eq!(Matrix::from_matrix_market(&dense.to_matrix_market()).unwrap(), dense);
+    /// ```
+    pub fn from_matrix_market(text: &str) -> Result<Matrix, HellError> {
+        let mut lines = text.lines();
+        let banner: Vec<String> = lines
+            .next()
+            .ok_or(HellError::ParseError("Empty Matrix Market file."))?
+            .split_whitespace()
+            .map(str::to_ascii_lowercase)
+            .collect();
+        let (coordinate, field, symmetry) = match &banner[..] {
+            [magic, object, format, field, symmetry] if magic == "%%matrixmarket" && object == "matrix" => {
+                let coordinate = match format.as_str() {
+                    "coordinate" => true,
+                    "array" => false,
+                    _ => return Err(HellError::ParseError("Unknown Matrix Market format.")),
+                };
+                (coordinate, field.as_str(), symmetry.as_str())
+            }
+            _ => return Err(HellError::ParseError("Invalid Matrix Market banner.")),
+        };
+        match field {
+            "real" | "integer" => {}
+            "pattern" if coordinate => {}
+            _ => return Err(HellError::ParseError("Unsupported Matrix Market field type.")),
+        }
+        let sign = match symmetry {
+            "general" => None,
+            "symmetric" => Some(1.0),
+            "skew-symmetric" => Some(-1.0),
+            _ => return Err(HellError::ParseError("Unsupported Matrix Market symmetry.")),
+        };
+
+        let mut tokens = lines.filter(|line| !line.trim_start().starts_with('%')).flat_map(str::split_whitespace);
+        let rows = mtx_index(tokens.next())?;
+        let cols = mtx_index(tokens.next())?;
+        let mut matrix = Matrix::zeros(rows, cols);
+        if coordinate {
+            let entries = mtx_index(tokens.next())?;
+            for _ in 0..entries {
+                let i = mtx_index(tokens.next())?;
+                let j = mtx_index(tokens.next())?;
+                let value = if field == "pattern" { 1.0 } else { mtx_value(tokens.next())? };
+                if !(1..=rows).contains(&i) || !(1..=cols).contains(&j) {
+                    return Err(HellError::DimensionMismatch("Matrix Market entry index is out of bounds."));
+                }
+                let (i, j) = (i - 1, j - 1);
+                matrix[(i, j)] += value;
+                if let Some(sign) = sign {
+                    if i != j {
+                        matrix[(j, i)] += sign * value;
+                    }
+                }
+            }
+        } else {
+            for j in 0..cols {
+                // Symmetric arrays store only the lower triangle, and skew-symmetric arrays only
+                // the part strictly below the diagonal.
+                let first = match sign {
+                    None => 0,
+                    Some(s) if s > 0.0 => j,
+                    Some(_) => j + 1,
+                };
+                for i in first..rows {
+                    let value = mtx_value(tokens.next())?;
+                    matrix[(i, j)] = value;
+                    if let Some(sign) = sign {
+                        matrix[(j, i)] = sign * value;
+                    }
+                }
+            }
+        }
+        if tokens.next().is_some() {
+            return Err(HellError::DimensionMismatch("Matrix Market file has more entries than declared."));
+        }
+        Ok(matrix)
+    }
+}
+
+/// The element type of a `.npy` array.
+struct NpyElement {
+    kind: char,
+    size: usize,
+    big_endian: bool,
+}
+
+impl NpyElement {
+    /// Parses a NumPy type descriptor such as `<f8`.
+    fn parse(descr: &str) -> Result<Self, HellError> {
+        let mut chars = descr.chars();
+        let big_endian = match chars.next() {
+            Some('>') => true,
+            Some('<' | '|' | '=') => false,
+            _ => return Err(HellError::ParseError("Invalid NumPy type descriptor.")),
+        };
+        let kind = chars.next().ok_or(HellError::ParseError("Invalid NumPy type descriptor."))?;
+        let size = chars.as_str().parse().map_err(|_| HellError::ParseError("Invalid NumPy type descriptor."))?;
+        match (kind, size) {
+            ('f', 4 | 8) | ('i' | 'u', 1 | 2 | 4 | 8) => Ok(NpyElement { kind, size, big_endian }),
+            _ => Err(HellError::ParseError("Unsupported NumPy element type.")),
+        }
+    }
+
+    /// Converts one element, given as `size` bytes, to `f64`.
+    fn decode(&self, chunk: &[u8]) -> f64 {
+        let mut buf = [0; 8];
+        if self.big_endian {
+            buf[..self.size].copy_from_slice(chunk);
+            buf[..self.size].reverse();
+        } else {
+            buf[..self.size].copy_from_slice(chunk);
+        }
+        // Sign-extend signed integers so that the full 8-byte buffer holds the same value.
+        if self.kind == 'i' && buf[self.size - 1] & 0x80 != 0 {
+            buf[self.size..].fill(0xff);
+        }
+        match (self.kind, self.size) {
+            ('f', 4) => f32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]) as f64,
+            ('f', _) => f64::from_le_bytes(buf),
+            ('i', _) => i64::from_le_bytes(buf) as f64,
+            _ => u64::from_le_bytes(buf) as f64,
+        }
+    }
+}
+
+/// Returns the text of the value stored under `key` in a `.npy` header dictionary.
+fn npy_header_value<'a>(header: &'a str, key: &str) -> Result<&'a str, HellError> {
+    let missing = HellError::ParseError("NumPy header is missing a required key.");
+    let start = header.find(&format!("'{}'", key)).or_else(|| header.find(&format!("\"{}\"", key))).ok_or(missing)?;
+    let value = header[start + key.len() + 2..].trim_start().strip_prefix(':').ok_or(missing)?.trim_start();
+    let end = if value.starts_with('(') {
+        value.find(')').map(|i| i + 1)
+    } else {
+        value.find([',', '}'])
+    };
+    Ok(value[..end.ok_or(missing)?].trim())
+}
+
+/// Parses a size or index token of a Matrix Market file.
+fn mtx_index(token: Option<&str>) -> Result<usize, HellError> {
+    token
+        .ok_or(HellError::ParseError("Unexpected end of Matrix Market file."))?
+        .parse()
+        .map_err(|_| HellError::ParseError("Invalid integer in Matrix Market file."))
+}
+
+/// Parses an element value token of a Matrix Market file.
+fn mtx_value(token: Option<&str>) -> Result<f64, HellError> {
+    token
+        .ok_or(HellError::DimensionMismatch("Matrix Market file has fewer entries than declared."))?
+        .parse()
+        .map_err(|_| HellError::ParseError("Invalid number in Matrix Market file."))
+}
@@ -0,0 +1,1065 @@
+use crate::rational::Rational;
+
+/// Computes the greatest common divisor of `a` and `b` together with Bézout coefficients.
+///
+/// The extended Euclidean algorithm finds integers `x` and `y` such that `a·x + b·y = gcd(a, b)`.
+/// These coefficients are the key ingredient for modular inverses and for solving systems of
+/// congruences.
+///
+/// # Arguments
+///
+/// * `a` - The first integer.
+/// * `b` - The second integer.
+///
+/// # Returns
+///
+/// * A tuple `(g, x, y)` where `g` is the non-negative GCD of `a` and `b` and `a·x + b·y = g`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::extended_gcd;
+///
+/// let (g, x, y) = extended_gcd(240, 46);
+/// assert_eq!(g, 2);
+/// assert_eq!(240 * x + 46 * y, 2);
+/// ```
+///
+/// # References
+///
+/// * [Extended Euclidean algorithm - Wikipedia](https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm)
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_i128(a as i128, b as i128);
+    (g as i64, x as i64, y as i64)
+}
+
+/// Computes the inverse of `a` modulo `m`, if it exists.
+///
+/// The inverse is the unique `x` in `[0, m)` with `a·x ≡ 1 (mod m)`. It exists exactly when `a`
+/// and `m` are coprime.
+///
+/// # Arguments
+///
+/// * `a` - The number to invert.
+/// * `m` - The modulus. Must be positive.
+///
+/// # Returns
+///
+/// * `Some(x)` if the inverse exists, and `None` if `gcd(a, m) != 1` or `m` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::mod_inverse;
+///
+/// assert_eq!(mod_inverse(3, 11), Some(4)); // 3 * 4 = 12 ≡ 1 (mod 11)
+/// assert_eq!(mod_inverse(6, 9), None);
+/// ```
+pub fn mod_inverse(a: u64, m: u64) -> Option<u64> {
+    if m == 0 {
+        return None;
+    }
+    let (g, x, _) = extended_gcd_i128(a as i128, m as i128);
+    if g != 1 {
+        return None;
+    }
+    Some(x.rem_euclid(m as i128) as u64)
+}
+
+/// Solves a system of simultaneous congruences with the Chinese Remainder Theorem.
+///
+/// Given pairs `(r_i, m_i)`, finds every `x` with `x ≡ r_i (mod m_i)` for all `i`. The moduli do
+/// not need to be pairwise coprime: congruences are merged one at a time, and the system is
+/// reported as unsolvable if two of them contradict each other.
+///
+/// # Arguments
+///
+/// * `residues` - The congruences as `(residue, modulus)` pairs. Every modulus must be positive;
+///   residues may be larger than their modulus.
+///
+/// # Returns
+///
+/// * `Some((x, m))` where `m` is the least common multiple of the moduli and `x` in `[0, m)` is the
+///   unique solution modulo `m`. An empty system returns `Some((0, 1))`.
+/// * `None` if the congruences are inconsistent, a modulus is zero, or `m` does not fit in a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::crt;
+///
+/// // x ≡ 2 (mod 3), x ≡ 3 (mod 5), x ≡ 2 (mod 7)
+/// assert_eq!(crt(&[(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+///
+/// // Non-coprime moduli: x ≡ 2 (mod 4), x ≡ 4 (mod 6)
+/// assert_eq!(crt(&[(2, 4), (4, 6)]), Some((10, 12)));
+///
+/// // Contradictory: x cannot be both even and odd.
+/// assert_eq!(crt(&[(0, 2), (1, 4)]), None);
+/// ```
+///
+/// # References
+///
+/// * [Chinese remainder theorem - Wikipedia](https://en.wikipedia.org/wiki/Chinese_remainder_theorem)
+pub fn crt(residues: &[(u64, u64)]) -> Option<(u64, u64)> {
+    let (mut x, mut m): (i128, i128) = (0, 1);
+    for &(r, n) in residues {
+        if n == 0 {
+            return None;
+        }
+        let (r, n) = ((r % n) as i128, n as i128);
+        // Solve x + m·k ≡ r (mod n) for k.
+        let (g, inv, _) = extended_gcd_i128(m, n);
+        let diff = r - x;
+        if diff % g != 0 {
+            return None;
+        }
+        let step = n / g;
+        // Both factors are reduced into [0, step) first, so the product fits in a u128.
+        let (a, b) = ((diff / g).rem_euclid(step) as u128, inv.rem_euclid(step) as u128);
+        let k = (a * b % step as u128) as i128;
+        m = m.checked_mul(step).filter(|&m| m <= u64::MAX as i128)?;
+        x += (m / step) * k;
+    }
+    Some((x as u64, m as u64))
+}
+
+/// Computes `base^exp mod modulus` by binary exponentiation.
+///
+/// Intermediate products are computed in 128-bit arithmetic, so any `u64` modulus works without
+/// overflow.
+///
+/// # Arguments
+///
+/// * `base` - The base.
+/// * `exp` - The exponent.
+/// * `modulus` - The modulus. Must be positive.
+///
+/// # Returns
+///
+/// * `base^exp mod modulus`. Any number modulo `1` is `0`.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::mod_pow;
+///
+/// assert_eq!(mod_pow(4, 13, 497), 445);
+/// assert_eq!(mod_pow(2, 0, 7), 1);
+/// assert_eq!(mod_pow(u64::MAX, u64::MAX, u64::MAX - 1), 1);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(log exp)` multiplications.
+pub fn mod_pow(base: u64, exp: u64, modulus: u64) -> u64 {
+    assert!(modulus != 0, "Modulus must be positive.");
+    let m = modulus as u128;
+    let mut result = 1 % m;
+    let mut base = base as u128 % m;
+    let mut exp = exp;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % m;
+        }
+        base = base * base % m;
+        exp >>= 1;
+    }
+    result as u64
+}
+
+/// Solves the discrete logarithm problem `base^x ≡ target (mod modulus)` with the baby-step
+/// giant-step algorithm.
+///
+/// Writing `x = i·s + j` with `s = ⌈√(modulus)⌉`, the algorithm tabulates the "baby steps"
+/// `base^j` and then walks "giant steps" `target·base^(-s·i)` until one lands in the table.
+///
+/// # Arguments
+///
+/// * `base` - The base of the logarithm. Must not be divisible by `modulus`.
+/// * `target` - The value whose logarithm is sought.
+/// * `modulus` - A prime modulus.
+///
+/// # Returns
+///
+/// * `Some(x)` with the smallest non-negative `x` such that `base^x ≡ target (mod modulus)`.
+/// * `None` if no such `x` exists, or if `base` is not invertible modulo `modulus`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::{discrete_log, mod_pow};
+///
+/// assert_eq!(discrete_log(2, 9, 11), Some(6)); // 2^6 = 64 ≡ 9 (mod 11)
+///
+/// let p = 1_000_000_007;
+/// let x = discrete_log(5, 123_456_789, p).unwrap();
+/// assert_eq!(mod_pow(5, x, p), 123_456_789);
+///
+/// assert_eq!(discrete_log(4, 2, 7), Some(2));
+/// assert_eq!(discrete_log(2, 3, 7), None); // 3 is not a power of 2 modulo 7.
+/// ```
+///
+/// # Notes
+///
+/// The modulus must be prime for the result to be meaningful: the algorithm relies on `base`
+/// being invertible and on the order of `base` dividing `modulus - 1`.
+///
+/// # Performance
+///
+/// Runs in `O(√modulus)` time and memory.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    if modulus == 1 {
+        return Some(0);
+    }
+    let m = modulus as u128;
+    let target = target % modulus;
+    let steps = (modulus as f64).sqrt().ceil() as u64;
+
+    let mut baby_steps = std::collections::HashMap::with_capacity(steps as usize);
+    let mut value = 1u128;
+    for j in 0..steps {
+        baby_steps.entry(value as u64).or_insert(j);
+        value = value * (base % modulus) as u128 % m;
+    }
+
+    // Each giant step multiplies by base^(-steps).
+    let inverse = mod_inverse(base, modulus)?;
+    let factor = mod_pow(inverse, steps, modulus) as u128;
+    let mut gamma = target as u128;
+    for i in 0..steps {
+        if let Some(&j) = baby_steps.get(&(gamma as u64)) {
+            return Some(i * steps + j);
+        }
+        gamma = gamma * factor % m;
+    }
+    None
+}
+
+/// Computes the Legendre symbol `(a / p)` for an odd prime `p`.
+///
+/// The symbol is `1` if `a` is a non-zero quadratic residue modulo `p` (a perfect square modulo
+/// `p`), `-1` if it is a non-residue, and `0` if `p` divides `a`. It is evaluated with Euler's
+/// criterion, `a^((p-1)/2) mod p`.
+///
+/// # Arguments
+///
+/// * `a` - The number to test.
+/// * `p` - An odd prime.
+///
+/// # Returns
+///
+/// * `1`, `-1` or `0` as described above.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 3 or even. Primality itself is not checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::legendre_symbol;
+///
+/// assert_eq!(legendre_symbol(2, 7), 1); // 3^2 = 9 ≡ 2 (mod 7)
+/// assert_eq!(legendre_symbol(3, 7), -1);
+/// assert_eq!(legendre_symbol(14, 7), 0);
+/// ```
+pub fn legendre_symbol(a: u64, p: u64) -> i32 {
+    assert!(p > 2 && p % 2 == 1, "Modulus must be an odd prime.");
+    match mod_pow(a, (p - 1) / 2, p) {
+        0 => 0,
+        1 => 1,
+        _ => -1,
+    }
+}
+
+/// Computes the Jacobi symbol `(a / n)` for an odd positive `n`.
+///
+/// The Jacobi symbol generalises the Legendre symbol to composite moduli: it is the product of
+/// the Legendre symbols of `a` over the prime factors of `n`. It is computed with the law of
+/// quadratic reciprocity, without factoring `n`.
+///
+/// # Arguments
+///
+/// * `a` - The number to test.
+/// * `n` - An odd positive modulus.
+///
+/// # Returns
+///
+/// * `1`, `-1`, or `0` if `a` and `n` share a factor.
+///
+/// # Panics
+///
+/// Panics if `n` is even.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::jacobi_symbol;
+///
+/// assert_eq!(jacobi_symbol(1001, 9907), -1);
+/// assert_eq!(jacobi_symbol(19, 45), 1);
+/// assert_eq!(jacobi_symbol(6, 15), 0);
+/// ```
+///
+/// # Notes
+///
+/// A Jacobi symbol of `1` does not imply that `a` is a quadratic residue modulo a composite `n`;
+/// a value of `-1` does imply that it is not.
+pub fn jacobi_symbol(a: u64, n: u64) -> i32 {
+    assert!(n % 2 == 1, "Modulus must be odd and positive.");
+    let (mut a, mut n) = (a % n, n);
+    let mut result = 1;
+    while a != 0 {
+        while a % 2 == 0 {
+            a /= 2;
+            if n % 8 == 3 || n % 8 == 5 {
+                result = -result;
+            }
+        }
+        std::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Computes a square root of `a` modulo an odd prime `p` with the Tonelli–Shanks algorithm.
+///
+/// # Arguments
+///
+/// * `a` - The number whose square root is sought.
+/// * `p` - An odd prime modulus.
+///
+/// # Returns
+///
+/// * `Some(r)` with `r^2 ≡ a (mod p)` and `r ≤ p - r`, so that the other root is `p - r`.
+/// * `None` if `a` is not a quadratic residue modulo `p`.
+///
+/// # Panics
+///
+/// Panics if `p` is less than 3 or even. Primality itself is not checked.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::mod_sqrt;
+///
+/// assert_eq!(mod_sqrt(10, 13), Some(6)); // 6^2 = 36 ≡ 10 (mod 13)
+/// assert_eq!(mod_sqrt(5, 13), None);
+///
+/// let p = 1_000_000_009;
+/// let r = mod_sqrt(2, p).unwrap();
+/// assert_eq!(r as u128 * r as u128 % p as u128, 2);
+/// ```
+///
+/// # References
+///
+/// * [Tonelli–Shanks algorithm - Wikipedia](https://en.wikipedia.org/wiki/Tonelli%E2%80%93Shanks_algorithm)
+pub fn mod_sqrt(a: u64, p: u64) -> Option<u64> {
+    let a = a % p;
+    match legendre_symbol(a, p) {
+        0 => return Some(0),
+        -1 => return None,
+        _ => {}
+    }
+    let mul = |x: u64, y: u64| (x as u128 * y as u128 % p as u128) as u64;
+
+    // Write p - 1 = q * 2^s with q odd.
+    let s = (p - 1).trailing_zeros();
+    let q = (p - 1) >> s;
+    let z = (2..p).find(|&z| legendre_symbol(z, p) == -1)?;
+
+    let mut m = s;
+    let mut c = mod_pow(z, q, p);
+    let mut t = mod_pow(a, q, p);
+    let mut r = mod_pow(a, q.div_ceil(2), p);
+    while t != 1 {
+        // Find the least i with t^(2^i) = 1.
+        let mut i = 0;
+        let mut t2 = t;
+        while t2 != 1 {
+            t2 = mul(t2, t2);
+            i += 1;
+        }
+        let b = mod_pow(c, 1 << (m - i - 1), p);
+        m = i;
+        c = mul(b, b);
+        t = mul(t, c);
+        r = mul(r, b);
+    }
+    Some(r.min(p - r))
+}
+
+/// Computes the continued fraction expansion `[a0; a1, a2, ...]` of a real number.
+///
+/// The expansion is built by repeatedly splitting off the integer part and inverting the
+/// remainder: `x = a0 + 1 / (a1 + 1 / (a2 + ...))`. It stops after `max_terms` terms, or as soon
+/// as the convergent it describes equals `x` to within floating-point rounding, which is when a
+/// rational input has been represented exactly. All terms after the first are positive.
+///
+/// # Arguments
+///
+/// * `x` - The number to expand. Must be finite.
+/// * `max_terms` - The maximum number of terms to return.
+///
+/// # Returns
+///
+/// * The terms `[a0, a1, ...]`. `a0` is `floor(x)` and may be negative.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::continued_fraction;
+///
+/// assert_eq!(continued_fraction(0.75, 10), vec![0, 1, 3]);
+/// assert_eq!(continued_fraction(-2.5, 10), vec![-3, 2]);
+/// assert_eq!(continued_fraction(std::f64::consts::PI, 5), vec![3, 7, 15, 1, 292]);
+///
+/// // Small fractional parts are kept, however large the integer part.
+/// assert_eq!(continued_fraction(1e11 + 0.5, 10), vec![100_000_000_000, 2]);
+/// assert_eq!(continued_fraction(3.0000000001, 10), vec![3, 9_999_999_172]);
+/// ```
+///
+/// # Notes
+///
+/// Floating-point rounding makes terms unreliable once they describe the number beyond its 15 or
+/// so significant digits, so long expansions of irrational numbers should be taken with care.
+pub fn continued_fraction(x: f64, max_terms: usize) -> Vec<i64> {
+    let mut terms = Vec::new();
+    let target = x;
+    let mut x = x;
+    // The last two convergents p/q, tracked in floating point so they cannot overflow.
+    let (mut p0, mut q0, mut p1, mut q1) = (1.0, 0.0, 0.0, 1.0);
+    while terms.len() < max_terms && x.is_finite() {
+        let a = x.floor();
+        if a.abs() >= i64::MAX as f64 {
+            break;
+        }
+        terms.push(a as i64);
+        (p0, q0, p1, q1) = (a * p0 + p1, a * q0 + q1, p0, q0);
+        // Stop once the remainder is exactly zero or the convergent reproduces `x` to within its
+        // own rounding error. The test is on the convergent, not on the remainder relative to the
+        // integer part, so small fractional parts of large numbers are kept.
+        let remainder = x - a;
+        if remainder == 0.0 || (p0 / q0 - target).abs() <= f64::EPSILON * target.abs() {
+            break;
+        }
+        x = 1.0 / remainder;
+    }
+    terms
+}
+
+/// Computes the periodic continued fraction expansion of `√n`.
+///
+/// For every non-square `n`, `√n = [a0; a1, ..., ar, a1, ..., ar, ...]` where the block
+/// `a1, ..., ar` repeats forever and always ends with `2·a0`. The terms are computed exactly in
+/// integer arithmetic.
+///
+/// # Arguments
+///
+/// * `n` - The number whose square root is expanded.
+///
+/// # Returns
+///
+/// * A tuple `(a0, period)` where `a0 = floor(√n)` and `period` is the repeating block. If `n` is
+///   a perfect square the period is empty.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::continued_fraction_sqrt;
+///
+/// assert_eq!(continued_fraction_sqrt(2), (1, vec![2]));
+/// assert_eq!(continued_fraction_sqrt(14), (3, vec![1, 2, 1, 6]));
+/// assert_eq!(continued_fraction_sqrt(16), (4, vec![]));
+/// ```
+pub fn continued_fraction_sqrt(n: u64) -> (u64, Vec<u64>) {
+    let a0 = n.isqrt();
+    let mut period = Vec::new();
+    if a0 * a0 == n {
+        return (a0, period);
+    }
+    let (mut m, mut q, mut a) = (0u64, 1u64, a0);
+    while a != 2 * a0 {
+        m = q * a - m;
+        q = (n - m * m) / q;
+        a = (a0 + m) / q;
+        period.push(a);
+    }
+    (a0, period)
+}
+
+/// Computes the convergents of a continued fraction.
+///
+/// The `k`-th convergent is the fraction obtained by truncating the expansion after `k + 1`
+/// terms. Convergents are the best rational approximations of the number the expansion
+/// represents, alternating above and below it.
+///
+/// # Arguments
+///
+/// * `terms` - The terms `[a0, a1, ...]`, as returned by [`continued_fraction`]. All terms after
+///   the first should be positive.
+///
+/// # Returns
+///
+/// * One [`Rational`] per term. The list stops early if a numerator or denominator would
+///   overflow an `i64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::{continued_fraction, convergents};
+/// use hell::rational::Rational;
+///
+/// let terms = continued_fraction(std::f64::consts::PI, 4);
+/// assert_eq!(
+///     convergents(&terms),
+///     vec![Rational::new(3, 1), Rational::new(22, 7), Rational::new(333, 106), Rational::new(355, 113)]
+/// );
+/// ```
+pub fn convergents(terms: &[i64]) -> Vec<Rational> {
+    // h_{-1} = 1, h_{-2} = 0, k_{-1} = 0, k_{-2} = 1.
+    let (mut h_prev, mut h) = (0i64, 1i64);
+    let (mut k_prev, mut k) = (1i64, 0i64);
+    let mut result = Vec::with_capacity(terms.len());
+    for &a in terms {
+        let next = a
+            .checked_mul(h)
+            .and_then(|v| v.checked_add(h_prev))
+            .zip(a.checked_mul(k).and_then(|v| v.checked_add(k_prev)));
+        let Some((h_next, k_next)) = next else { break };
+        (h_prev, h) = (h, h_next);
+        (k_prev, k) = (k, k_next);
+        result.push(Rational::new(h, k));
+    }
+    result
+}
+
+/// Returns all positive divisors of `n` in increasing order.
+///
+/// # Arguments
+///
+/// * `n` - A positive integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::divisors;
+///
+/// assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+/// assert_eq!(divisors(1), vec![1]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero, which is divisible by every integer.
+///
+/// # Performance
+///
+/// Trial division up to `√n`, so `O(√n)` time.
+pub fn divisors(n: u64) -> Vec<u64> {
+    assert!(n != 0, "Zero has infinitely many divisors.");
+    let mut small = Vec::new();
+    let mut large = Vec::new();
+    let mut d = 1;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            small.push(d);
+            if d != n / d {
+                large.push(n / d);
+            }
+        }
+        d += 1;
+    }
+    small.extend(large.into_iter().rev());
+    small
+}
+
+/// Returns `σ(n)`, the sum of all positive divisors of `n` including `n` itself.
+///
+/// # Arguments
+///
+/// * `n` - A positive integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::divisor_sum;
+///
+/// assert_eq!(divisor_sum(12), 28); // 1 + 2 + 3 + 4 + 6 + 12
+/// assert_eq!(divisor_sum(13), 14);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+///
+/// # Performance
+///
+/// Trial division up to `√n`, so `O(√n)` time.
+pub fn divisor_sum(n: u64) -> u64 {
+    assert!(n != 0, "Zero has infinitely many divisors.");
+    let mut sum = 0;
+    let mut d = 1;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            sum += d;
+            if d != n / d {
+                sum += n / d;
+            }
+        }
+        d += 1;
+    }
+    sum
+}
+
+/// The classification of a positive integer by the sum of its proper divisors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NumberClass {
+    /// The proper divisors sum to less than the number (for example 8: 1 + 2 + 4 = 7).
+    Deficient,
+    /// The proper divisors sum to exactly the number (for example 6: 1 + 2 + 3 = 6).
+    Perfect,
+    /// The proper divisors sum to more than the number (for example 12: 1 + 2 + 3 + 4 + 6 = 16).
+    Abundant,
+}
+
+/// Classifies `n` as deficient, perfect, or abundant.
+///
+/// The classification compares `n` with the sum of its proper divisors (all divisors except `n`
+/// itself), `σ(n) − n`.
+///
+/// # Arguments
+///
+/// * `n` - A positive integer.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::{classify_number, NumberClass};
+///
+/// assert_eq!(classify_number(28), NumberClass::Perfect);
+/// assert_eq!(classify_number(12), NumberClass::Abundant);
+/// assert_eq!(classify_number(1), NumberClass::Deficient);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero.
+pub fn classify_number(n: u64) -> NumberClass {
+    match (divisor_sum(n) - n).cmp(&n) {
+        std::cmp::Ordering::Less => NumberClass::Deficient,
+        std::cmp::Ordering::Equal => NumberClass::Perfect,
+        std::cmp::Ordering::Greater => NumberClass::Abundant,
+    }
+}
+
+/// Returns `true` if `a` and `b` are an amicable pair.
+///
+/// Two different numbers are amicable when the proper divisors of each sum to the other. A perfect
+/// number is not amicable with itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::number_theory::are_amicable;
+///
+/// assert!(are_amicable(220, 284));
+/// assert!(are_amicable(1184, 1210));
+/// assert!(!are_amicable(6, 6));
+/// assert!(!are_amicable(220, 221));
+/// ```
+///
+/// # Panics
+///
+/// Panics if either number is zero.
+pub fn are_amicable(a: u64, b: u64) -> bool {
+    a != b && divisor_sum(a) - a == b && divisor_sum(b) - b == a
+}
+
+/// Counts the primes less than or equal to `limit`, written `π(limit)`.
+///
+/// Uses the Lucy_Hedgehog variant of the Legendre/Meissel method. It tracks the number of
+/// integers in `2..=v` that survive sieving by the primes found so far, but only for the roughly
+/// `2√limit` distinct values `v = limit / i`, which is all the recurrence ever needs. The primes
+/// themselves are never generated or stored.
+///
+/// # Arguments
+///
+/// * `limit` - The inclusive upper bound.
+///
+/// # Returns
+///
+/// * The number of primes `p ≤ limit`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::number_theory::prime_count;
+///
+/// assert_eq!(prime_count(1), 0);
+/// assert_eq!(prime_count(10), 4);
+/// assert_eq!(prime_count(1_000), 168);
+/// assert_eq!(prime_count(10_000_000), 664_579);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(limit^(3/4))` time and `O(√limit)` memory.
+///
+/// # References
+///
+/// * [Prime-counting function - Wikipedia](https://en.wikipedia.org/wiki/Prime-counting_function)
+pub fn prime_count(limit: u64) -> u64 {
+    if limit < 2 {
+        return 0;
+    }
+    let root = limit.isqrt();
+    // small[v] holds the count for v ≤ root, large[i] the count for limit / i.
+    let mut small: Vec<u64> = (0..=root).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..=root).map(|i| limit.checked_div(i).map_or(0, |q| q - 1)).collect();
+    for p in 2..=root {
+        if small[p as usize] == small[p as usize - 1] {
+            continue;
+        }
+        let below_p = small[p as usize - 1];
+        let p_squared = p * p;
+        for i in 1..=root.min(limit / p_squared) {
+            let d = i * p;
+            let count_at_quotient = if d <= root { large[d as usize] } else { small[(limit / d) as usize] };
+            large[i as usize] -= count_at_quotient - below_p;
+        }
+        for v in (p_squared..=root).rev() {
+            small[v as usize] -= small[(v / p) as usize] - below_p;
+        }
+    }
+    large[1]
+}
+
+/// Counts the primes less than or equal to `limit`, updating the counts for each prime in
+/// parallel.
+///
+/// This is the parallel counterpart of [`prime_count`] and takes the same argument. For each
+/// sieving prime, the new counts are computed across the rayon thread pool from the previous ones
+/// and then written back, so the result is exactly that of [`prime_count`]. It is only available
+/// with the `parallel` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parallel")] {
+/// use hell::number_theory::{par_prime_count, prime_count};
+///
+/// assert_eq!(par_prime_count(10_000_000), 664_579);
+/// assert_eq!(par_prime_count(123_456_789), prime_count(123_456_789));
+/// # }
+/// ```
+///
+/// # Notes
+///
+/// The work per prime shrinks quickly, so only limits of about `10^10` and above keep many
+/// threads busy.
+#[cfg(feature = "parallel")]
+pub fn par_prime_count(limit: u64) -> u64 {
+    use rayon::prelude::*;
+
+    /// The fewest counts a rayon task updates, so that small updates stay on one thread.
+    const MIN_TASK: usize = 1 << 12;
+
+    if limit < 2 {
+        return 0;
+    }
+    let root = limit.isqrt();
+    let mut small: Vec<u64> = (0..=root).map(|v| v.saturating_sub(1)).collect();
+    let mut large: Vec<u64> = (0..=root).map(|i| limit.checked_div(i).map_or(0, |q| q - 1)).collect();
+    for p in 2..=root {
+        if small[p as usize] == small[p as usize - 1] {
+            continue;
+        }
+        let below_p = small[p as usize - 1];
+        let p_squared = p * p;
+        // Both updates read only entries the serial loops have not yet overwritten, so computing
+        // them from the old values and writing them back afterwards gives the same counts.
+        let last = root.min(limit / p_squared) as usize;
+        let updated: Vec<u64> = (1..last + 1)
+            .into_par_iter()
+            .with_min_len(MIN_TASK)
+            .map(|i| {
+                let d = i as u64 * p;
+                let count_at_quotient = if d <= root { large[d as usize] } else { small[(limit / d) as usize] };
+                large[i] - (count_at_quotient - below_p)
+            })
+            .collect();
+        large[1..=last].copy_from_slice(&updated);
+        if p_squared <= root {
+            let updated: Vec<u64> = (p_squared as usize..root as usize + 1)
+                .into_par_iter()
+                .with_min_len(MIN_TASK)
+                .map(|v| small[v] - (small[v / p as usize] - below_p))
+                .collect();
+            small[p_squared as usize..].copy_from_slice(&updated);
+        }
+    }
+    large[1]
+}
+
+/// Returns the `n`-th prime, counting from `nth_prime(1) = 2`.
+///
+/// Upper and lower bounds from the prime number theorem bracket the answer, [`prime_count`]
+/// narrows the bracket by bisection, and a sieve of the final small interval finds the prime
+/// itself.
+///
+/// # Arguments
+///
+/// * `n` - The one-based index of the prime.
+///
+/// # Returns
+///
+/// * `Some(p)` with the `n`-th prime, or `None` if `n` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use hell::number_theory::nth_prime;
+///
+/// assert_eq!(nth_prime(0), None);
+/// assert_eq!(nth_prime(1), Some(2));
+/// assert_eq!(nth_prime(6), Some(13));
+/// assert_eq!(nth_prime(10_000), Some(104_729));
+/// assert_eq!(nth_prime(1_000_000), Some(15_485_863));
+/// ```
+///
+/// # Performance
+///
+/// Dominated by a few dozen calls to [`prime_count`], so roughly `O(p^(3/4) log p)` time and
+/// `O(√p)` memory for the answer `p`.
+pub fn nth_prime(n: u64) -> Option<u64> {
+    nth_prime_with(n, prime_count)
+}
+
+/// Returns the `n`-th prime, counting primes with [`par_prime_count`].
+///
+/// This is the parallel counterpart of [`nth_prime`] and takes the same argument. It is only
+/// available with the `parallel` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parallel")] {
+/// use hell::number_theory::par_nth_prime;
+///
+/// assert_eq!(par_nth_prime(0), None);
+/// assert_eq!(par_nth_prime(1_000_000), Some(15_485_863));
+/// # }
+/// ```
+#[cfg(feature = "parallel")]
+pub fn par_nth_prime(n: u64) -> Option<u64> {
+    nth_prime_with(n, par_prime_count)
+}
+
+/// Finds the `n`-th prime, using `prime_count` to narrow the bracket around it.
+fn nth_prime_with(n: u64, prime_count: fn(u64) -> u64) -> Option<u64> {
+    const WINDOW: u64 = 1 << 16;
+
+    if n == 0 {
+        return None;
+    }
+    // Rosser's theorem and its refinements: n(ln n + ln ln n − 1) < p_n < n(ln n + ln ln n) for n ≥ 6.
+    let (mut lo, mut hi) = if n < 6 {
+        (0, 13)
+    } else {
+        let x = n as f64;
+        let estimate = x * (x.ln() + x.ln().ln());
+        ((estimate - x) as u64, estimate as u64 + 1)
+    };
+    // Invariant: prime_count(lo) < n ≤ prime_count(hi).
+    let mut count_lo = prime_count(lo);
+    while hi - lo > WINDOW {
+        let mid = lo + (hi - lo) / 2;
+        let count_mid = prime_count(mid);
+        if count_mid < n {
+            (lo, count_lo) = (mid, count_mid);
+        } else {
+            hi = mid;
+        }
+    }
+
+    // Sieve (lo, hi] with the primes up to √hi.
+    let root = hi.isqrt();
+    let mut is_composite_small = vec![false; root as usize + 1];
+    let mut is_composite = vec![false; (hi - lo) as usize];
+    for p in 2..=root {
+        if is_composite_small[p as usize] {
+            continue;
+        }
+        for multiple in (p * p..=root).step_by(p as usize) {
+            is_composite_small[multiple as usize] = true;
+        }
+        let first = (p * p).max((lo / p + 1) * p);
+        for multiple in (first..=hi).step_by(p as usize) {
+            is_composite[(multiple - lo - 1) as usize] = true;
+        }
+    }
+    let mut count = count_lo;
+    for (offset, &composite) in is_composite.iter().enumerate() {
+        let candidate = lo + 1 + offset as u64;
+        if !composite && candidate >= 2 {
+            count += 1;
+            if count == n {
+                return Some(candidate);
+            }
+        }
+    }
+    unreachable!("The bracket always contains the n-th prime.")
+}
+
+/// Finds the fundamental solution of Pell's equation `x² − d·y² = 1`.
+///
+/// The fundamental solution is the smallest solution in positive integers. It is read off the
+/// convergents of the continued fraction expansion of `√d`: if the period of the expansion has
+/// length `r`, the solution is the convergent with index `r − 1` when `r` is even and `2r − 1`
+/// when `r` is odd. Solutions can be astronomically large even for small `d` (for `d = 61`,
+/// `x` has ten digits), so they are returned as arbitrary-precision integers. This function is
+/// only available with the `bignum` feature.
+///
+/// # Arguments
+///
+/// * `d` - A positive integer that is not a perfect square.
+///
+/// # Returns
+///
+/// * `Some((x, y))` with the fundamental solution, or `None` if `d` is zero or a perfect square
+///   (in which case only the trivial solution `(1, 0)` exists).
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "bignum")] {
+/// use hell::number_theory::solve_pell;
+///
+/// let (x, y) = solve_pell(2).unwrap();
+/// assert_eq!((x.to_string(), y.to_string()), ("3".to_string(), "2".to_string()));
+///
+/// let (x, y) = solve_pell(61).unwrap();
+/// assert_eq!(x.to_string(), "1766319049");
+/// assert_eq!(y.to_string(), "226153980");
+///
+/// assert!(solve_pell(16).is_none());
+/// # }
+/// ```
+///
+/// # References
+///
+/// * [Pell's equation - Wikipedia](https://en.wikipedia.org/wiki/Pell%27s_equation)
+#[cfg(feature = "bignum")]
+pub fn solve_pell(d: u64) -> Option<(num_bigint::BigUint, num_bigint::BigUint)> {
+    use num_bigint::BigUint;
+
+    let (a0, period) = continued_fraction_sqrt(d);
+    if period.is_empty() {
+        return None;
+    }
+    let r = period.len();
+    let last = if r % 2 == 0 { r - 1 } else { 2 * r - 1 };
+
+    // Convergents h_n / k_n, seeded with h_{-1} = 1, h_{-2} = 0, k_{-1} = 0, k_{-2} = 1.
+    let (mut h_prev, mut h) = (BigUint::from(1u32), BigUint::from(a0));
+    let (mut k_prev, mut k) = (BigUint::from(0u32), BigUint::from(1u32));
+    for &a in period.iter().cycle().take(last) {
+        let h_next = &h * a + &h_prev;
+        let k_next = &k * a + &k_prev;
+        (h_prev, h) = (h, h_next);
+        (k_prev, k) = (k, k_next);
+    }
+    Some((h, k))
+}
+
+/// Returns an iterator over all positive solutions of Pell's equation `x² − d·y² = 1`, in
+/// increasing order, starting with the fundamental solution.
+///
+/// Further solutions are generated from the fundamental solution `(x₁, y₁)` by the recurrence
+/// `x' + y'√d = (x + y√d)(x₁ + y₁√d)`. This function is only available with the `bignum` feature.
+///
+/// # Arguments
+///
+/// * `d` - A positive integer that is not a perfect square.
+///
+/// # Returns
+///
+/// * `Some(iterator)`, or `None` if `d` is zero or a perfect square. The iterator never ends.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(feature = "bignum")] {
+/// use hell::number_theory::pell_solutions;
+///
+/// let solutions: Vec<String> = pell_solutions(2)
+///     .unwrap()
+///     .take(4)
+///     .map(|(x, y)| format!("({}, {})", x, y))
+///     .collect();
+/// assert_eq!(solutions, ["(3, 2)", "(17, 12)", "(99, 70)", "(577, 408)"]);
+/// # }
+/// ```
+#[cfg(feature = "bignum")]
+pub fn pell_solutions(d: u64) -> Option<PellSolutions> {
+    let (x1, y1) = solve_pell(d)?;
+    Some(PellSolutions { d, x1: x1.clone(), y1: y1.clone(), x: x1, y: y1 })
+}
+
+/// An endless iterator over the solutions of Pell's equation, created by [`pell_solutions`].
+///
+/// This type is only available with the `bignum` feature.
+#[cfg(feature = "bignum")]
+#[derive(Debug, Clone)]
+pub struct PellSolutions {
+    d: u64,
+    x1: num_bigint::BigUint,
+    y1: num_bigint::BigUint,
+    x: num_bigint::BigUint,
+    y: num_bigint::BigUint,
+}
+
+#[cfg(feature = "bignum")]
+impl Iterator for PellSolutions {
+    type Item = (num_bigint::BigUint, num_bigint::BigUint);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let x_next = &self.x1 * &self.x + &self.y1 * &self.y * self.d;
+        let y_next = &self.x1 * &self.y + &self.y1 * &self.x;
+        let x = std::mem::replace(&mut self.x, x_next);
+        let y = std::mem::replace(&mut self.y, y_next);
+        Some((x, y))
+    }
+}
+
+/// Extended Euclidean algorithm on `i128`, so that callers can work with products of `u64` values
+/// without overflow.
+fn extended_gcd_i128(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_s, mut s) = (1, 0);
+    let (mut old_t, mut t) = (0, 1);
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+        (old_t, t) = (t, old_t - q * t);
+    }
+    if old_r < 0 {
+        (-old_r, -old_s, -old_t)
+    } else {
+        (old_r, old_s, old_t)
+    }
+}
@@ -0,0 +1,282 @@
+//! Numerical solution of initial value problems for ordinary differential equations.
+//!
+//! [`solve_rk45`] integrates a system `y' = f(t, y)` with the adaptive Dormand–Prince method and
+//! returns an [`OdeSolution`] that can be evaluated anywhere in the time span.
+
+use crate::error::HellError;
+
+/// The nodes of the Dormand–Prince stages, as fractions of the step.
+const NODES: [f64; 7] = [0.0, 1.0 / 5.0, 3.0 / 10.0, 4.0 / 5.0, 8.0 / 9.0, 1.0, 1.0];
+
+/// The coefficients of the Dormand–Prince stages. Row `s` combines the first `s + 1` stage
+/// derivatives into the input of stage `s + 2`; the last row gives the fifth-order solution.
+const STAGES: [[f64; 6]; 6] = [
+    [1.0 / 5.0, 0.0, 0.0, 0.0, 0.0, 0.0],
+    [3.0 / 40.0, 9.0 / 40.0, 0.0, 0.0, 0.0, 0.0],
+    [44.0 / 45.0, -56.0 / 15.0, 32.0 / 9.0, 0.0, 0.0, 0.0],
+    [19372.0 / 6561.0, -25360.0 / 2187.0, 64448.0 / 6561.0, -212.0 / 729.0, 0.0, 0.0],
+    [9017.0 / 3168.0, -355.0 / 33.0, 46732.0 / 5247.0, 49.0 / 176.0, -5103.0 / 18656.0, 0.0],
+    [35.0 / 384.0, 0.0, 500.0 / 1113.0, 125.0 / 192.0, -2187.0 / 6784.0, 11.0 / 84.0],
+];
+
+/// The difference between the fifth- and fourth-order weights, which gives the local error
+/// estimate.
+const ERROR_WEIGHTS: [f64; 7] = [71.0 / 57600.0, 0.0, -71.0 / 16695.0, 71.0 / 1920.0, -17253.0 / 339200.0, 22.0 / 525.0, -1.0 / 40.0];
+
+/// The coefficients of the fourth-order continuous extension: within a step, the solution at
+/// `t + θh` is `y + h · Σ kᵢ · (Σⱼ DENSE[i][j] θ^(j+1))`.
+const DENSE: [[f64; 4]; 7] = [
+    [1.0, -8048581381.0 / 2820520608.0, 8663915743.0 / 2820520608.0, -12715105075.0 / 11282082432.0],
+    [0.0, 0.0, 0.0, 0.0],
+    [0.0, 131558114200.0 / 32700410799.0, -68118460800.0 / 10900136933.0, 87487479700.0 / 32700410799.0],
+    [0.0, -1754552775.0 / 470086768.0, 14199869525.0 / 1410260304.0, -10690763975.0 / 1880347072.0],
+    [0.0, 127303824393.0 / 49829197408.0, -318862633887.0 / 49829197408.0, 701980252875.0 / 199316789632.0],
+    [0.0, -282668133.0 / 205662961.0, 2019193451.0 / 616988883.0, -1453857185.0 / 822651844.0],
+    [0.0, 40617522.0 / 29380423.0, -110615467.0 / 29380423.0, 69997945.0 / 29380423.0],
+];
+
+/// The fraction of the optimal step size actually used, to make rejections less likely.
+const SAFETY: f64 = 0.9;
+
+/// The smallest factor by which a step may shrink.
+const MIN_FACTOR: f64 = 0.2;
+
+/// The largest factor by which a step may grow.
+const MAX_FACTOR: f64 = 10.0;
+
+/// The solution of an initial value problem computed by [`solve_rk45`].
+///
+/// It holds the state at every accepted step, and between steps it evaluates the method's
+/// fourth-order continuous extension, so the solution can be sampled at any time in the span
+/// without extra evaluations of the derivative and without limiting the step size.
+///
+/// # Examples
+///
+/// ```
+/// use hell::ode::solve_rk45;
+///
+/// // y' = -y, y(0) = 1, so y(t) = e^(-t).
+/// let solution = solve_rk45(|_, y| vec![-y[0]], &[1.0], (0.0, 5.0), 1e-8, 1e-10).unwrap();
+///
+/// for t in [0.5, 1.7, 3.14, 5.0] {
+///     let y = solution.interpolate(t).unwrap();
+///     assert!((y[0] - (-t).exp()).abs() < 1e-8);
+/// }
+/// assert!(solution.interpolate(6.0).is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct OdeSolution {
+    times: Vec<f64>,
+    states: Vec<Vec<f64>>,
+    /// For each step, its signed length and the per-component coefficients of the continuous
+    /// extension.
+    steps: Vec<(f64, Vec<[f64; 4]>)>,
+}
+
+impl OdeSolution {
+    /// Returns the times of the accepted steps, starting with the initial time and ending with
+    /// the final time.
+    pub fn times(&self) -> &[f64] {
+        &self.times
+    }
+
+    /// Returns the state at each time in [`OdeSolution::times`].
+    pub fn states(&self) -> &[Vec<f64>] {
+        &self.states
+    }
+
+    /// Returns the state at the end of the time span.
+    pub fn final_state(&self) -> &[f64] {
+        self.states.last().expect("A solution always contains the initial state.")
+    }
+
+    /// Evaluates the solution at time `t`, interpolating between steps.
+    ///
+    /// # Arguments
+    ///
+    /// * `t` - The time at which to evaluate the solution.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(state)` if `t` lies within the time span.
+    /// * `None` if `t` lies outside it, or is NaN.
+    pub fn interpolate(&self, t: f64) -> Option<Vec<f64>> {
+        let (first, last) = (self.times[0], *self.times.last()?);
+        if !(first.min(last)..=first.max(last)).contains(&t) {
+            return None;
+        }
+        if self.steps.is_empty() {
+            return Some(self.states[0].clone());
+        }
+        let forward = last >= first;
+        let i = self.times[1..].partition_point(|&end| if forward { end < t } else { end > t }).min(self.steps.len() - 1);
+        let (h, coefficients) = &self.steps[i];
+        let theta = (t - self.times[i]) / h;
+        let state = self.states[i]
+            .iter()
+            .zip(coefficients)
+            .map(|(&y, q)| y + h * theta * (q[0] + theta * (q[1] + theta * (q[2] + theta * q[3]))))
+            .collect();
+        Some(state)
+    }
+}
+
+/// Solves the initial value problem `y' = f(t, y)`, `y(t₀) = y₀` with the adaptive
+/// Dormand–Prince Runge–Kutta method of order 5(4).
+///
+/// Each step computes a fifth-order solution together with an embedded fourth-order one, and
+/// their difference estimates the local error. The step size is adjusted so that this error
+/// stays within `atol + rtol·|y|` in each component (in the root-mean-square sense): steps are
+/// rejected and retried when the error is too large, and grow where the solution is smooth. The
+/// initial step size is chosen automatically.
+///
+/// # Arguments
+///
+/// * `f` - The right-hand side, mapping the time and the state to the derivative of the state.
+///   It must return a vector of the same length as the state.
+/// * `y0` - The initial state.
+/// * `t_span` - The initial and final times `(t₀, t₁)`. If `t₁ < t₀` the problem is integrated
+///   backwards in time.
+/// * `rtol` - The relative tolerance, for example `1e-6`.
+/// * `atol` - The absolute tolerance, which matters for components near zero.
+///
+/// # Returns
+///
+/// * `Ok(solution)` with the states at the accepted steps and dense output between them.
+/// * `Err(HellError::DomainError)` if a time is not finite, or a tolerance is negative or NaN, or
+///   both tolerances are zero.
+/// * `Err(HellError::DimensionMismatch)` if `f` returns a vector of the wrong length.
+/// * `Err(HellError::ConvergenceFailure)` if the step size has to shrink to the rounding level of
+///   `t`, which happens when the solution blows up in finite time or `f` returns non-finite
+///   values.
+///
+/// # Examples
+///
+/// ```
+/// use hell::ode::solve_rk45;
+/// use std::f64::consts::PI;
+///
+/// // The harmonic oscillator x'' = -x, written as the system (x, v)' = (v, -x).
+/// let solution = solve_rk45(|_, y| vec![y[1], -y[0]], &[1.0, 0.0], (0.0, 2.0 * PI), 1e-9, 1e-12).unwrap();
+///
+/// // After one period the oscillator is back where it started.
+/// let end = solution.final_state();
+/// assert!((end[0] - 1.0).abs() < 1e-7 && end[1].abs() < 1e-7);
+///
+/// // A quarter period in, x = cos(π/2) = 0 and v = -sin(π/2) = -1.
+/// let quarter = solution.interpolate(PI / 2.0).unwrap();
+/// assert!(quarter[0].abs() < 1e-7 && (quarter[1] + 1.0).abs() < 1e-7);
+/// ```
+///
+/// # Notes
+///
+/// * The method is explicit. On mildly stiff problems the step-size control keeps it stable at the
+///   cost of small steps; on severely stiff problems, with time scales differing by many orders of
+///   magnitude, the steps become so small that an implicit method is the better tool.
+/// * Every step costs six evaluations of `f`, because the last stage of one step is reused as the
+///   first stage of the next.
+///
+/// # References
+///
+/// * J. R. Dormand and P. J. Prince, "A family of embedded Runge-Kutta formulae", *Journal of
+///   Computational and Applied Mathematics* 6(1), 1980.
+/// * E. Hairer, S. P. Nørsett and G. Wanner, *Solving Ordinary Differential Equations I*,
+///   Springer, 1993, section II.4 (initial step size) and II.6 (dense output).
+pub fn solve_rk45<F>(f: F, y0: &[f64], t_span: (f64, f64), rtol: f64, atol: f64) -> Result<OdeSolution, HellError>
+where
+    F: Fn(f64, &[f64]) -> Vec<f64>,
+{
+    let (t0, t_end) = t_span;
+    if !t0.is_finite() || !t_end.is_finite() {
+        return Err(HellError::DomainError("Time span must be finite."));
+    }
+    if rtol.is_nan() || atol.is_nan() || rtol < 0.0 || atol < 0.0 || (rtol == 0.0 && atol == 0.0) {
+        return Err(HellError::DomainError("Tolerances must be non-negative and not both zero."));
+    }
+    let n = y0.len();
+    let derivative = |t: f64, y: &[f64]| {
+        let dy = f(t, y);
+        if dy.len() == n {
+            Ok(dy)
+        } else {
+            Err(HellError::DimensionMismatch("Derivative length does not match the state."))
+        }
+    };
+    let scale = |a: f64, b: f64| atol + rtol * a.abs().max(b.abs());
+
+    let mut solution = OdeSolution { times: vec![t0], states: vec![y0.to_vec()], steps: Vec::new() };
+    if t0 == t_end {
+        return Ok(solution);
+    }
+    let direction = (t_end - t0).signum();
+    let mut t = t0;
+    let mut y = y0.to_vec();
+    let mut k_first = derivative(t, &y)?;
+
+    // Initial step size, following Hairer, Nørsett and Wanner.
+    let d0 = rms(y.iter().map(|&v| v / scale(v, v)));
+    let d1 = rms(y.iter().zip(&k_first).map(|(&v, &dv)| dv / scale(v, v)));
+    let h0 = if d0 < 1e-5 || d1 < 1e-5 { 1e-6 } else { 0.01 * d0 / d1 };
+    let y1: Vec<f64> = y.iter().zip(&k_first).map(|(&v, &dv)| v + direction * h0 * dv).collect();
+    let k1 = derivative(t + direction * h0, &y1)?;
+    let d2 = rms(y.iter().zip(k1.iter().zip(&k_first)).map(|(&v, (&a, &b))| (a - b) / scale(v, v))) / h0;
+    let h1 = if d1.max(d2) <= 1e-15 { (h0 * 1e-3).max(1e-6) } else { (0.01 / d1.max(d2)).powf(0.2) };
+    let mut h = (100.0 * h0).min(h1).min((t_end - t0).abs());
+
+    let mut rejected = false;
+    loop {
+        let min_step = 10.0 * (t.next_up() - t).abs();
+        // Also catches a NaN step size, produced by a non-finite derivative.
+        if h.is_nan() || h < min_step {
+            return Err(HellError::ConvergenceFailure("Step size became too small; the solution may blow up or be extremely stiff."));
+        }
+        let last = h >= (t_end - t).abs();
+        if last {
+            h = (t_end - t).abs();
+        }
+        let step = direction * h;
+
+        let mut k = Vec::with_capacity(7);
+        k.push(k_first.clone());
+        let mut y_new = Vec::new();
+        for (s, row) in STAGES.iter().enumerate() {
+            let input: Vec<f64> = (0..n).map(|i| y[i] + step * k.iter().zip(row).map(|(kj, &a)| a * kj[i]).sum::<f64>()).collect();
+            k.push(derivative(t + step * NODES[s + 1], &input)?);
+            y_new = input;
+        }
+        let error = rms((0..n).map(|i| step * k.iter().zip(&ERROR_WEIGHTS).map(|(kj, &e)| e * kj[i]).sum::<f64>() / scale(y[i], y_new[i])));
+
+        if error <= 1.0 {
+            let coefficients = (0..n).map(|i| std::array::from_fn(|m| k.iter().zip(&DENSE).map(|(kj, d)| d[m] * kj[i]).sum())).collect();
+            t = if last { t_end } else { t + step };
+            solution.times.push(t);
+            solution.states.push(y_new.clone());
+            solution.steps.push((step, coefficients));
+            if last {
+                return Ok(solution);
+            }
+            let mut factor = if error == 0.0 { MAX_FACTOR } else { (SAFETY * error.powf(-0.2)).min(MAX_FACTOR) };
+            if rejected {
+                factor = factor.min(1.0);
+            }
+            h *= factor;
+            y = y_new;
+            k_first = k.pop().expect("Seven stages were computed.");
+            rejected = false;
+        } else {
+            // A NaN error also lands here and shrinks the step as much as allowed.
+            h *= (SAFETY * error.powf(-0.2)).max(MIN_FACTOR);
+            rejected = true;
+        }
+    }
+}
+
+/// Returns the root-mean-square of the values, or zero if there are none.
+fn rms(values: impl Iterator<Item = f64>) -> f64 {
+    let (sum, count) = values.fold((0.0, 0usize), |(sum, count), v| (sum + v * v, count + 1));
+    if count == 0 {
+        0.0
+    } else {
+        (sum / count as f64).sqrt()
+    }
+}
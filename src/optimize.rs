@@ -0,0 +1,142 @@
+use crate::rng::Rng;
+
+/// How the temperature of a [`simulated_annealing`] run falls over time.
+///
+/// `t0` is [`AnnealingConfig::initial_temperature`] and `k` is the zero-based iteration number.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CoolingSchedule {
+    /// `t = t0 · alpha^k`, with `0 < alpha < 1`. The usual choice.
+    Exponential(f64),
+    /// `t = t0 · (1 − k / iterations)`, reaching zero on the last iteration.
+    Linear,
+    /// `t = t0 / ln(k + e)`. Cools very slowly, which helps on extremely rugged landscapes.
+    Logarithmic,
+}
+
+/// Settings for [`simulated_annealing`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::optimize::{AnnealingConfig, CoolingSchedule};
+///
+/// let config = AnnealingConfig {
+///     cooling: CoolingSchedule::Linear,
+///     seed: 7,
+///     ..AnnealingConfig::default()
+/// };
+/// assert_eq!(config.iterations, 10_000);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnnealingConfig {
+    /// The starting temperature. Should be on the scale of typical uphill moves in the objective.
+    pub initial_temperature: f64,
+    /// How the temperature decreases.
+    pub cooling: CoolingSchedule,
+    /// The number of candidate moves to try.
+    pub iterations: usize,
+    /// The seed for the [`Rng`] that drives the neighbor function and the acceptance test.
+    pub seed: u64,
+}
+
+impl AnnealingConfig {
+    /// The default settings: temperature `1.0`, exponential cooling by `0.999`, 10,000 iterations,
+    /// seed `0`.
+    pub const DEFAULT: AnnealingConfig = AnnealingConfig {
+        initial_temperature: 1.0,
+        cooling: CoolingSchedule::Exponential(0.999),
+        iterations: 10_000,
+        seed: 0,
+    };
+
+    /// Returns the temperature at iteration `k`.
+    fn temperature(&self, k: usize) -> f64 {
+        let t0 = self.initial_temperature;
+        match self.cooling {
+            CoolingSchedule::Exponential(alpha) => t0 * alpha.powi(k as i32),
+            CoolingSchedule::Linear => t0 * (1.0 - k as f64 / self.iterations as f64),
+            CoolingSchedule::Logarithmic => t0 / (k as f64 + std::f64::consts::E).ln(),
+        }
+    }
+}
+
+impl Default for AnnealingConfig {
+    fn default() -> Self {
+        AnnealingConfig::DEFAULT
+    }
+}
+
+/// Minimizes `objective` by simulated annealing.
+///
+/// Starting from `x0`, each iteration asks `neighbor` for a candidate near the current state. A
+/// better candidate is always accepted; a worse one is accepted with probability
+/// `exp(−Δ / t)`, where `Δ` is how much worse it is and `t` the current temperature. Early on,
+/// while `t` is high, this lets the search climb out of local minima; as `t` falls it settles into
+/// the best basin it has found. The state can be any type, so the same routine works for
+/// continuous parameters, permutations, or other discrete structures.
+///
+/// # Arguments
+///
+/// * `objective` - The function to minimize.
+/// * `neighbor` - Produces a random candidate near the given state, drawing randomness from the
+///   supplied [`Rng`].
+/// * `x0` - The starting state.
+/// * `config` - The temperature schedule, iteration count, and seed.
+///
+/// # Returns
+///
+/// * The best state visited and its objective value. This is not necessarily the final state.
+///
+/// # Examples
+///
+/// ```
+/// use hell::optimize::{simulated_annealing, AnnealingConfig};
+///
+/// // The Rastrigin function has a local minimum near every integer; the global one is at 0.
+/// let rastrigin = |x: &f64| x * x + 10.0 * (1.0 - (2.0 * std::f64::consts::PI * x).cos());
+/// let step = |x: &f64, rng: &mut hell::rng::Rng| x + rng.normal(0.0, 0.5);
+///
+/// let config = AnnealingConfig { initial_temperature: 10.0, seed: 1, ..AnnealingConfig::default() };
+/// let (best, value) = simulated_annealing(rastrigin, step, 4.3, config);
+/// assert!(best.abs() < 0.05);
+/// assert!(value < 0.5);
+/// ```
+///
+/// # Notes
+///
+/// The result depends on the seed and is reproducible for a fixed seed. Annealing gives no
+/// guarantee of finding the global minimum; more iterations and slower cooling improve the odds.
+///
+/// # Performance
+///
+/// Calls `objective` and `neighbor` once per iteration.
+pub fn simulated_annealing<T, F, N>(objective: F, mut neighbor: N, x0: T, config: AnnealingConfig) -> (T, f64)
+where
+    T: Clone,
+    F: Fn(&T) -> f64,
+    N: FnMut(&T, &mut Rng) -> T,
+{
+    let mut rng = Rng::new(config.seed);
+    let mut current_value = objective(&x0);
+    let mut current = x0;
+    let mut best = current.clone();
+    let mut best_value = current_value;
+
+    for k in 0..config.iterations {
+        let temperature = config.temperature(k);
+        let candidate = neighbor(&current, &mut rng);
+        let candidate_value = objective(&candidate);
+        let delta = candidate_value - current_value;
+        let accept = delta <= 0.0 || (temperature > 0.0 && rng.next_f64() < (-delta / temperature).exp());
+        if accept {
+            current = candidate;
+            current_value = candidate_value;
+            if current_value < best_value {
+                best = current.clone();
+                best_value = current_value;
+            }
+        }
+    }
+
+    (best, best_value)
+}
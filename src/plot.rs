@@ -0,0 +1,104 @@
+/// Samples a function at `n` evenly spaced points over `[a, b]`, endpoints included.
+///
+/// # Arguments
+///
+/// * `func` - The function to sample.
+/// * `a` - The first sample position.
+/// * `b` - The last sample position.
+/// * `n` - The number of samples. With `n = 1` only `a` is sampled; `n = 0` gives an empty table.
+///
+/// # Returns
+///
+/// * A `Vec` of `(x, func(x))` pairs in order of increasing index.
+///
+/// # Examples
+///
+/// ```
+/// use hell::plot::tabulate;
+///
+/// let table = tabulate(|x| x * x, 0.0, 2.0, 5);
+/// assert_eq!(table, vec![(0.0, 0.0), (0.5, 0.25), (1.0, 1.0), (1.5, 2.25), (2.0, 4.0)]);
+/// ```
+pub fn tabulate<F>(func: F, a: f64, b: f64, n: usize) -> Vec<(f64, f64)>
+where
+    F: Fn(f64) -> f64,
+{
+    let step = if n > 1 { (b - a) / (n - 1) as f64 } else { 0.0 };
+    (0..n)
+        .map(|i| {
+            // Pin the last sample to `b` exactly instead of accumulating rounding error.
+            let x = if i + 1 == n && n > 1 { b } else { a + i as f64 * step };
+            (x, func(x))
+        })
+        .collect()
+}
+
+/// Renders a quick text plot of a function, suitable for printing to a terminal.
+///
+/// The function is sampled once per column across `x_range`, and each sample is drawn as a `*`
+/// in the row nearest its value. The vertical range is fitted to the finite samples. If `y = 0`
+/// lies within that range, the x-axis is drawn with `-`. Non-finite samples leave their column
+/// blank.
+///
+/// # Arguments
+///
+/// * `func` - The function to plot.
+/// * `x_range` - The horizontal range `(start, end)`.
+/// * `width` - The number of columns (samples).
+/// * `height` - The number of rows.
+///
+/// # Returns
+///
+/// * The plot as `height` lines of `width` characters, each ending in a newline. Returns an
+///   empty string if `width` or `height` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use hell::plot::ascii_plot;
+///
+/// let plot = ascii_plot(|x| x, (-1.0, 1.0), 5, 5);
+/// assert_eq!(plot, "    *\n   * \n--*--\n *   \n*    \n");
+///
+/// println!("{}", ascii_plot(f64::sin, (0.0, 6.28), 60, 15));
+/// ```
+pub fn ascii_plot<F>(func: F, x_range: (f64, f64), width: usize, height: usize) -> String
+where
+    F: Fn(f64) -> f64,
+{
+    if width == 0 || height == 0 {
+        return String::new();
+    }
+    let samples = tabulate(func, x_range.0, x_range.1, width);
+    let finite = samples.iter().map(|&(_, y)| y).filter(|y| y.is_finite());
+    let (y_min, y_max) = finite.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), y| (lo.min(y), hi.max(y)));
+
+    let mut grid = vec![vec![' '; width]; height];
+    if y_min.is_finite() {
+        // Maps a value to a row index, with row 0 at the top.
+        let row_of = |y: f64| {
+            if y_max == y_min {
+                height / 2
+            } else {
+                let t = (y - y_min) / (y_max - y_min);
+                height - 1 - (t * (height - 1) as f64).round() as usize
+            }
+        };
+        if y_min <= 0.0 && 0.0 <= y_max {
+            let axis = row_of(0.0);
+            grid[axis].iter_mut().for_each(|c| *c = '-');
+        }
+        for (col, &(_, y)) in samples.iter().enumerate() {
+            if y.is_finite() {
+                grid[row_of(y)][col] = '*';
+            }
+        }
+    }
+
+    let mut out = String::with_capacity((width + 1) * height);
+    for row in grid {
+        out.extend(row);
+        out.push('\n');
+    }
+    out
+}
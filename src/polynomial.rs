@@ -0,0 +1,271 @@
+use crate::error::HellError;
+use crate::fft::convolve;
+use crate::matrix::solve_linear_system;
+use crate::tolerance::Tolerance;
+use std::ops::{Add, Mul};
+
+/// A polynomial with `f64` coefficients, stored in ascending order of degree.
+///
+/// `coefficients[i]` is the coefficient of `x^i`, so `[1.0, 0.0, 3.0]` represents `1 + 3x^2`.
+/// Trailing zero coefficients are trimmed on construction, so the zero polynomial has no
+/// coefficients at all.
+///
+/// Multiplication uses [`convolve`], so products of two high-degree polynomials are computed with
+/// the FFT in `O(n log n)` time, at the cost of rounding error in the last few bits.
+///
+/// # Examples
+///
+/// ```
+/// use hell::polynomial::Polynomial;
+///
+/// let p = Polynomial::new(vec![1.0, 0.0, 3.0]); // 1 + 3x^2
+/// assert_eq!(p.evaluate(2.0), 13.0);
+/// assert_eq!(p.degree(), Some(2));
+/// assert_eq!(p.derivative(), Polynomial::new(vec![0.0, 6.0]));
+///
+/// let q = Polynomial::new(vec![-1.0, 1.0]); // x - 1
+/// assert_eq!(&p * &q, Polynomial::new(vec![-1.0, 1.0, -3.0, 3.0]));
+/// assert_eq!(&p + &q, Polynomial::new(vec![0.0, 1.0, 3.0]));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(from = "PolynomialFields"))]
+pub struct Polynomial {
+    coefficients: Vec<f64>,
+}
+
+/// The serialized form of a [`Polynomial`], normalized on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct PolynomialFields {
+    coefficients: Vec<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl From<PolynomialFields> for Polynomial {
+    fn from(fields: PolynomialFields) -> Self {
+        Polynomial::new(fields.coefficients)
+    }
+}
+
+impl Polynomial {
+    /// Creates a polynomial from its coefficients in ascending order of degree.
+    pub fn new(mut coefficients: Vec<f64>) -> Self {
+        while coefficients.last() == Some(&0.0) {
+            coefficients.pop();
+        }
+        Polynomial { coefficients }
+    }
+
+    /// Returns the coefficients in ascending order of degree.
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    /// Returns the degree of the polynomial, or `None` for the zero polynomial.
+    pub fn degree(&self) -> Option<usize> {
+        self.coefficients.len().checked_sub(1)
+    }
+
+    /// Evaluates the polynomial at `x` using Horner's method.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.coefficients.iter().rev().fold(0.0, |acc, &c| acc * x + c)
+    }
+
+    /// Returns the derivative of the polynomial.
+    pub fn derivative(&self) -> Polynomial {
+        Polynomial::new(
+            self.coefficients
+                .iter()
+                .enumerate()
+                .skip(1)
+                .map(|(i, &c)| c * i as f64)
+                .collect(),
+        )
+    }
+
+    /// Renders the polynomial as LaTeX math markup in the variable `x`, highest degree first.
+    ///
+    /// Zero terms are omitted, unit coefficients are suppressed, and negative coefficients are
+    /// written as subtractions. The zero polynomial renders as `0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::polynomial::Polynomial;
+    ///
+    /// let p = Polynomial::new(vec![1.0, -1.0, 0.0, 3.5]);
+    /// assert_eq!(p.to_latex(), "3.5x^{3} - x + 1");
+    /// assert_eq!(Polynomial::new(vec![]).to_latex(), "0");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        let mut out = String::new();
+        for (power, &c) in self.coefficients.iter().enumerate().rev() {
+            if c == 0.0 {
+                continue;
+            }
+            let magnitude = c.abs();
+            if out.is_empty() {
+                if c < 0.0 {
+                    out.push('-');
+                }
+            } else {
+                out.push_str(if c < 0.0 { " - " } else { " + " });
+            }
+            if magnitude != 1.0 || power == 0 {
+                out.push_str(&magnitude.to_string());
+            }
+            match power {
+                0 => {}
+                1 => out.push('x'),
+                _ => out.push_str(&format!("x^{{{}}}", power)),
+            }
+        }
+        if out.is_empty() {
+            out.push('0');
+        }
+        out
+    }
+}
+
+impl Add for &Polynomial {
+    type Output = Polynomial;
+
+    fn add(self, other: &Polynomial) -> Polynomial {
+        let len = self.coefficients.len().max(other.coefficients.len());
+        let coefficient = |p: &Polynomial, i: usize| p.coefficients.get(i).copied().unwrap_or(0.0);
+        Polynomial::new((0..len).map(|i| coefficient(self, i) + coefficient(other, i)).collect())
+    }
+}
+
+impl Mul for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, other: &Polynomial) -> Polynomial {
+        Polynomial::new(convolve(&self.coefficients, &other.coefficients))
+    }
+}
+
+/// The result of a least-squares polynomial fit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PolynomialFit {
+    /// The fitted polynomial.
+    pub polynomial: Polynomial,
+    /// The residuals `y_i - p(x_i)` for each sample, in input order.
+    pub residuals: Vec<f64>,
+    /// The sum of squared residuals.
+    pub sum_squared_residuals: f64,
+    /// The coefficient of determination `R^2`. It is `1` for a perfect fit; if all `y` values are
+    /// equal it is defined as `1` when the fit is exact and `0` otherwise.
+    pub r_squared: f64,
+}
+
+/// Fits a polynomial of the given degree to samples using linear least squares.
+///
+/// The fit minimises the sum of squared residuals `Σ (y_i - p(x_i))^2` by solving the normal
+/// equations `(VᵀV) c = Vᵀy`, where `V` is the Vandermonde matrix of the `x` values.
+///
+/// # Arguments
+///
+/// * `xs` - The sample `x` values.
+/// * `ys` - The sample `y` values. Must have the same length as `xs`.
+/// * `degree` - The degree of the polynomial to fit.
+///
+/// # Returns
+///
+/// * `Ok(PolynomialFit)`: the fitted polynomial together with its residual statistics.
+/// * `Err(HellError::DimensionMismatch)`: if the inputs have different lengths or there are
+///   fewer than `degree + 1` samples.
+/// * `Err(HellError::ConvergenceFailure)`: if the samples do not determine a unique fit (for
+///   example because too many `x` values coincide).
+///
+/// # Examples
+///
+/// ```
+/// use hell::polynomial::fit_polynomial;
+///
+/// // Noise-free samples of y = 2 - x + 0.5x^2 are recovered exactly.
+/// let xs = [-2.0, -1.0, 0.0, 1.0, 2.0, 3.0];
+/// let ys: Vec<f64> = xs.iter().map(|&x| 2.0 - x + 0.5 * x * x).collect();
+///
+/// let fit = fit_polynomial(&xs, &ys, 2).unwrap();
+/// let c = fit.polynomial.coefficients();
+/// assert!((c[0] - 2.0).abs() < 1e-9);
+/// assert!((c[1] + 1.0).abs() < 1e-9);
+/// assert!((c[2] - 0.5).abs() < 1e-9);
+/// assert!(fit.sum_squared_residuals < 1e-18);
+/// assert!((fit.r_squared - 1.0).abs() < 1e-12);
+/// ```
+///
+/// # Limitations
+///
+/// The normal equations square the condition number of the problem, so very high degrees or
+/// widely spread `x` values lose accuracy. Rescaling `x` to roughly `[-1, 1]` before fitting
+/// helps considerably. Systems that are numerically singular under [`Tolerance::DEFAULT`] are
+/// reported as `ConvergenceFailure`; use [`fit_polynomial_with_tolerance`] to adjust the cut-off.
+pub fn fit_polynomial(xs: &[f64], ys: &[f64], degree: usize) -> Result<PolynomialFit, HellError> {
+    fit_polynomial_with_tolerance(xs, ys, degree, Tolerance::DEFAULT)
+}
+
+/// Fits a polynomial of the given degree to samples using linear least squares, with an explicit
+/// tolerance for deciding when the normal equations are singular.
+///
+/// This behaves exactly like [`fit_polynomial`], except that a pivot of the normal equations is
+/// treated as zero when it is negligible under `tolerance` relative to their largest entry.
+///
+/// # Examples
+///
+/// ```
+/// use hell::polynomial::fit_polynomial_with_tolerance;
+/// use hell::tolerance::Tolerance;
+///
+/// let xs = [0.0, 1.0, 2.0, 3.0];
+/// let ys = [1.0, 3.0, 5.0, 7.0];
+/// let fit = fit_polynomial_with_tolerance(&xs, &ys, 1, Tolerance::new(0.0, 1e-14)).unwrap();
+/// assert!((fit.polynomial.evaluate(4.0) - 9.0).abs() < 1e-9);
+/// ```
+pub fn fit_polynomial_with_tolerance(
+    xs: &[f64],
+    ys: &[f64],
+    degree: usize,
+    tolerance: Tolerance,
+) -> Result<PolynomialFit, HellError> {
+    if xs.len() != ys.len() {
+        return Err(HellError::DimensionMismatch("Sample arrays have different lengths."));
+    }
+    let terms = degree + 1;
+    if xs.len() < terms {
+        return Err(HellError::DimensionMismatch("Not enough samples for the requested degree."));
+    }
+
+    // Power sums Σ x^k for k = 0..2*degree fill the Hankel-structured normal matrix VᵀV.
+    let mut power_sums = vec![0.0; 2 * degree + 1];
+    let mut rhs = vec![0.0; terms];
+    for (&x, &y) in xs.iter().zip(ys) {
+        let mut xk = 1.0;
+        for (k, sum) in power_sums.iter_mut().enumerate() {
+            *sum += xk;
+            if k < terms {
+                rhs[k] += xk * y;
+            }
+            xk *= x;
+        }
+    }
+    let normal: Vec<Vec<f64>> = (0..terms).map(|i| power_sums[i..i + terms].to_vec()).collect();
+
+    let coefficients = solve_linear_system(normal, rhs, tolerance).ok_or(HellError::ConvergenceFailure("Samples do not determine a unique fit."))?;
+    let polynomial = Polynomial::new(coefficients);
+
+    let residuals: Vec<f64> = xs.iter().zip(ys).map(|(&x, &y)| y - polynomial.evaluate(x)).collect();
+    let sum_squared_residuals: f64 = residuals.iter().map(|r| r * r).sum();
+    let mean = ys.iter().sum::<f64>() / ys.len() as f64;
+    let total: f64 = ys.iter().map(|y| (y - mean) * (y - mean)).sum();
+    let r_squared = if total > 0.0 {
+        1.0 - sum_squared_residuals / total
+    } else if sum_squared_residuals == 0.0 {
+        1.0
+    } else {
+        0.0
+    };
+
+    Ok(PolynomialFit { polynomial, residuals, sum_squared_residuals, r_squared })
+}
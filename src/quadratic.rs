@@ -1,3 +1,6 @@
+use crate::error::HellError;
+use crate::tolerance::Tolerance;
+
 /// Solves a quadratic equation of the form `ax^2 + bx + c = 0`.
 ///
 /// This function computes the roots of a quadratic equation using the quadratic formula:
@@ -15,22 +18,23 @@
 ///
 /// # Returns
 ///
-/// * `Option<(f64, f64)>` - Returns `Some((root1, root2))` where `root1` and `root2` are the 
-///   two real roots of the quadratic equation. If the discriminant is negative, indicating that 
-///   the roots are complex (not real), the function returns `None`.
+/// * `Result<(f64, f64), HellError>` - Returns `Ok((root1, root2))` where `root1` and `root2` are
+///   the two real roots of the quadratic equation. If `a` is zero, or the discriminant is negative
+///   (indicating that the roots are complex, not real), the function returns
+///   `Err(HellError::DomainError)`.
 ///
 /// # Examples
 ///
 /// ```rust
-/// assert_eq!(solve_quadratic(1.0, -3.0, 2.0), Some((2.0, 1.0)));
-/// assert_eq!(solve_quadratic(1.0, 2.0, 5.0), None); // No real roots
+/// use hell::quadratic::solve_quadratic;
+///
+/// assert_eq!(solve_quadratic(1.0, -3.0, 2.0), Ok((2.0, 1.0)));
+/// assert!(solve_quadratic(1.0, 2.0, 5.0).is_err()); // No real roots
 /// ```
 ///
-/// # Panics
+/// # Errors
 ///
-/// The function does not explicitly panic, but providing `a = 0.0` will result in a division by 
-/// zero, which will cause a runtime panic. It is assumed that the user will provide valid input 
-/// where `a` is non-zero.
+/// Providing `a = 0.0` returns a domain error instead of dividing by zero.
 ///
 /// # Performance
 ///
@@ -47,18 +51,56 @@
 ///
 /// The function assumes that `a`, `b`, and `c` are finite real numbers (`f64`). The accuracy of the 
 /// results may be limited by the precision of floating-point arithmetic, especially for very small 
-/// or very large values of `a`, `b`, or `c`. Additionally, the function does not handle the case 
+/// or very large values of `a`, `b`, or `c`. Additionally, the function does not solve the case
 /// where `a = 0.0`, which would reduce the equation to a linear equation (`bx + c = 0`).
-pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Option<(f64, f64)> {
-    let discriminant = b * b - 4.0 * a * c;
+///
+/// A discriminant that is negative but negligible under [`Tolerance::DEFAULT`] (as happens for
+/// double roots computed from rounded coefficients) is treated as zero, so such equations report
+/// their double root. Use [`solve_quadratic_with_tolerance`] to adjust this cut-off.
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Result<(f64, f64), HellError> {
+    solve_quadratic_with_tolerance(a, b, c, Tolerance::DEFAULT)
+}
+
+/// Solves a quadratic equation of the form `ax^2 + bx + c = 0`, with an explicit tolerance for
+/// classifying the roots.
+///
+/// This behaves exactly like [`solve_quadratic`], except that the discriminant `b^2 - 4ac` is
+/// treated as zero when it is negligible under `tolerance` relative to the larger of `b^2` and
+/// `|4ac|`. Such equations are reported as having a double root instead of no real roots.
+///
+/// # Examples
+///
+/// ```rust
+/// use hell::quadratic::solve_quadratic_with_tolerance;
+/// use hell::tolerance::Tolerance;
+///
+/// // 7(x - 1.3)^2 = 7x^2 - 18.2x + 11.83, but the rounded discriminant is slightly negative.
+/// let (a, b, c) = (7.0, -18.2, 11.83);
+/// assert!(b * b - 4.0 * a * c < 0.0);
+///
+/// let (r1, r2) = solve_quadratic_with_tolerance(a, b, c, Tolerance::DEFAULT).unwrap();
+/// assert!((r1 - 1.3).abs() < 1e-12 && (r2 - 1.3).abs() < 1e-12);
+///
+/// let exact = Tolerance::new(0.0, 0.0);
+/// assert!(solve_quadratic_with_tolerance(a, b, c, exact).is_err());
+/// ```
+pub fn solve_quadratic_with_tolerance(a: f64, b: f64, c: f64, tolerance: Tolerance) -> Result<(f64, f64), HellError> {
+    if a == 0.0 {
+        return Err(HellError::DomainError("Coefficient a must be non-zero."));
+    }
+
+    let mut discriminant = b * b - 4.0 * a * c;
     
     if discriminant < 0.0 {
-        return None; // No real roots
+        if !tolerance.is_negligible(discriminant, (b * b).max((4.0 * a * c).abs())) {
+            return Err(HellError::DomainError("Equation has no real roots."));
+        }
+        discriminant = 0.0;
     }
     
     let sqrt_discriminant = discriminant.sqrt();
     let root1 = (-b + sqrt_discriminant) / (2.0 * a);
     let root2 = (-b - sqrt_discriminant) / (2.0 * a);
     
-    Some((root1, root2))
+    Ok((root1, root2))
 }
@@ -0,0 +1,417 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+#[cfg(feature = "serde")]
+use crate::error::HellError;
+use crate::number_theory::continued_fraction;
+
+/// An exact fraction `numerator / denominator` of 64-bit integers.
+///
+/// A `Rational` is always kept in lowest terms with a positive denominator, so two fractions are
+/// equal exactly when their numerators and denominators are. Fractions are ordered by value.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rational::Rational;
+///
+/// let half = Rational::new(2, 4);
+/// assert_eq!(half, Rational::new(1, 2));
+/// assert_eq!(half.to_string(), "1/2");
+/// assert_eq!(Rational::new(3, -6).numerator(), -1);
+/// assert!(Rational::new(1, 3) < half);
+/// assert_eq!(Rational::from(7).to_string(), "7");
+/// ```
+///
+/// With the `serde` feature, fractions serialize as their numerator and denominator and are
+/// reduced when deserialized:
+///
+/// ```
+/// # #[cfg(feature = "serde")] {
+/// use hell::rational::Rational;
+///
+/// let half: Rational = serde_json::from_str(r#"{"numerator":-2,"denominator":-4}"#).unwrap();
+/// assert_eq!(half, Rational::new(1, 2));
+/// assert!(serde_json::from_str::<Rational>(r#"{"numerator":1,"denominator":0}"#).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(try_from = "RationalFields"))]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// Creates the fraction `numerator / denominator`, reduced to lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero, or if the reduced fraction cannot be represented (which
+    /// only happens when negating `i64::MIN`).
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        assert!(denominator != 0, "Denominator must be non-zero.");
+        Rational::reduced(numerator, denominator).expect("Rational overflow.")
+    }
+
+    /// Reduces `numerator / denominator` to lowest terms with a positive denominator, or returns
+    /// `None` if that overflows. The denominator must be non-zero.
+    fn reduced(numerator: i64, denominator: i64) -> Option<Self> {
+        let g = gcd_u64(numerator.unsigned_abs(), denominator.unsigned_abs()) as i64;
+        let (mut numerator, mut denominator) = (numerator / g, denominator / g);
+        if denominator < 0 {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+        Some(Rational { numerator, denominator })
+    }
+
+    /// Returns the numerator. Its sign is the sign of the fraction.
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// Returns the denominator, which is always positive.
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Finds the fraction closest to `x` whose denominator is at most `max_denominator`.
+    ///
+    /// The candidates are the convergents of the continued fraction expansion of `x` and the
+    /// semiconvergents between them, which together contain the best rational approximation for
+    /// every denominator bound. This turns noisy decimals such as `0.333333` or measured ratios
+    /// into clean fractions.
+    ///
+    /// # Arguments
+    ///
+    /// * `x` - The number to approximate.
+    /// * `max_denominator` - The largest denominator allowed. Must be at least 1.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(fraction)`, or `None` if `x` is not finite or its integer part does not fit in an
+    ///   `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rational::Rational;
+    ///
+    /// assert_eq!(Rational::approximate(0.333333, 100), Some(Rational::new(1, 3)));
+    /// assert_eq!(Rational::approximate(std::f64::consts::PI, 1000), Some(Rational::new(355, 113)));
+    /// assert_eq!(Rational::approximate(std::f64::consts::PI, 100), Some(Rational::new(311, 99)));
+    /// assert_eq!(Rational::approximate(-1.75, 10), Some(Rational::new(-7, 4)));
+    /// assert_eq!(Rational::approximate(0.9999, 5), Some(Rational::from(1)));
+    ///
+    /// // Fractional parts survive a large integer part.
+    /// assert_eq!(Rational::approximate(1e11 + 0.5, 10), Some(Rational::new(200_000_000_001, 2)));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_denominator` is zero.
+    pub fn approximate(x: f64, max_denominator: i64) -> Option<Rational> {
+        assert!(max_denominator >= 1, "Maximum denominator must be at least 1.");
+        let terms = continued_fraction(x, 64);
+        if terms.is_empty() {
+            return None;
+        }
+        // Convergents (p0/q0, p1/q1), seeded with the conventional 0/1 and 1/0.
+        let (mut p0, mut q0, mut p1, mut q1) = (0i64, 1i64, 1i64, 0i64);
+        for &a in &terms {
+            let q2 = match a.checked_mul(q1).and_then(|v| v.checked_add(q0)) {
+                Some(q2) if q2 <= max_denominator => q2,
+                _ => {
+                    // The next convergent is out of reach; the best candidate is either the last
+                    // convergent or the largest semiconvergent that still fits.
+                    let k = (max_denominator - q0) / q1;
+                    let last = Rational::new(p1, q1);
+                    let semi = k.checked_mul(p1).and_then(|v| v.checked_add(p0)).map(|p| Rational::new(p, q0 + k * q1));
+                    return Some(match semi {
+                        Some(semi) if (semi.to_f64() - x).abs() < (last.to_f64() - x).abs() => semi,
+                        _ => last,
+                    });
+                }
+            };
+            let p2 = a.checked_mul(p1)?.checked_add(p0)?;
+            (p0, q0, p1, q1) = (p1, q1, p2, q2);
+        }
+        Some(Rational::new(p1, q1))
+    }
+
+    /// Returns the nearest `f64` to the fraction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rational::Rational;
+    ///
+    /// assert_eq!(Rational::new(3, 4).to_f64(), 0.75);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+
+    /// Reduces a fraction computed in 128-bit intermediates back to a `Rational`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `denominator` is zero or the reduced fraction does not fit in `i64`.
+    fn from_wide(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "Denominator must be non-zero.");
+        let (mut a, mut b) = (numerator.unsigned_abs(), denominator.unsigned_abs());
+        while b != 0 {
+            (a, b) = (b, a % b);
+        }
+        let g = a as i128 * denominator.signum();
+        let narrow = |v: i128| i64::try_from(v).expect("Rational overflow.");
+        Rational { numerator: narrow(numerator / g), denominator: narrow(denominator / g) }
+    }
+}
+
+/// Exact arithmetic on fractions. Intermediate products are computed in 128 bits, so the
+/// operators only panic if the reduced result does not fit in `i64`, or on division by zero.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rational::Rational;
+///
+/// let a = Rational::new(1, 2);
+/// let b = Rational::new(1, 3);
+/// assert_eq!(a + b, Rational::new(5, 6));
+/// assert_eq!(a - b, Rational::new(1, 6));
+/// assert_eq!(a * b, Rational::new(1, 6));
+/// assert_eq!(a / b, Rational::new(3, 2));
+/// assert_eq!(-a, Rational::new(-1, 2));
+/// ```
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, other: Rational) -> Rational {
+        Rational::from_wide(
+            self.numerator as i128 * other.denominator as i128 + other.numerator as i128 * self.denominator as i128,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, other: Rational) -> Rational {
+        Rational::from_wide(
+            self.numerator as i128 * other.denominator as i128 - other.numerator as i128 * self.denominator as i128,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, other: Rational) -> Rational {
+        Rational::from_wide(
+            self.numerator as i128 * other.numerator as i128,
+            self.denominator as i128 * other.denominator as i128,
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, other: Rational) -> Rational {
+        assert!(other.numerator != 0, "Division by zero.");
+        Rational::from_wide(
+            self.numerator as i128 * other.denominator as i128,
+            self.denominator as i128 * other.numerator as i128,
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational::new(self.numerator.checked_neg().expect("Rational overflow."), self.denominator)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational { numerator: n, denominator: 1 }
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Denominators are positive, so cross-multiplying preserves the order.
+        (self.numerator as i128 * other.denominator as i128).cmp(&(other.numerator as i128 * self.denominator as i128))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// The serialized form of a [`Rational`], validated and reduced on deserialization.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RationalFields {
+    numerator: i64,
+    denominator: i64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RationalFields> for Rational {
+    type Error = HellError;
+
+    fn try_from(fields: RationalFields) -> Result<Self, HellError> {
+        if fields.denominator == 0 {
+            return Err(HellError::DomainError("Denominator must be non-zero."));
+        }
+        Rational::reduced(fields.numerator, fields.denominator).ok_or(HellError::Overflow("Rational overflow."))
+    }
+}
+
+impl fmt::Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Euclid's algorithm on `u64`, for the magnitudes of `i64` values.
+fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// Returns an iterator over the Farey sequence of order `n`.
+///
+/// The Farey sequence of order `n` lists, in increasing order, every fraction in `[0, 1]` whose
+/// denominator is at most `n`, in lowest terms. Consecutive terms `a/b < c/d` always satisfy
+/// `bc − ad = 1`, which lets each term be computed from the previous two without any search.
+///
+/// # Arguments
+///
+/// * `n` - The order of the sequence. Must be at least 1.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rational::farey_sequence;
+///
+/// let terms: Vec<String> = farey_sequence(4).map(|r| r.to_string()).collect();
+/// assert_eq!(terms, ["0", "1/4", "1/3", "1/2", "2/3", "3/4", "1"]);
+/// assert_eq!(farey_sequence(100).count(), 3045);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `n` is zero or does not fit in an `i64`.
+pub fn farey_sequence(n: u64) -> FareySequence {
+    assert!(n != 0, "Order must be at least 1.");
+    let n = i64::try_from(n).expect("Order must fit in an i64.");
+    FareySequence { n, current: Some((0, 1)), next: (1, n) }
+}
+
+/// An iterator over a Farey sequence, created by [`farey_sequence`].
+#[derive(Debug, Clone)]
+pub struct FareySequence {
+    n: i64,
+    current: Option<(i64, i64)>,
+    next: (i64, i64),
+}
+
+impl Iterator for FareySequence {
+    type Item = Rational;
+
+    fn next(&mut self) -> Option<Rational> {
+        let (a, b) = self.current?;
+        let (c, d) = self.next;
+        self.current = if a == b {
+            None
+        } else {
+            let k = (self.n + b) / d;
+            self.next = (k * c - a, k * d - b);
+            Some((c, d))
+        };
+        Some(Rational::new(a, b))
+    }
+}
+
+/// Finds the simplest fraction within `tolerance` of `target` by searching the Stern–Brocot tree.
+///
+/// The Stern–Brocot tree contains every positive fraction exactly once, with simpler fractions
+/// (smaller denominators, then smaller numerators) closer to the root. Descending it towards
+/// `target` and stopping at the first fraction inside `[target − tolerance, target + tolerance]`
+/// yields the simplest fraction in that interval. Runs of steps in the same direction are taken
+/// at once, so the search takes a number of steps proportional to the number of continued
+/// fraction terms rather than to the size of the denominator.
+///
+/// This is useful for turning measured ratios into clean fractions, for example gear teeth
+/// counts or screen aspect ratios.
+///
+/// # Arguments
+///
+/// * `target` - The real number to approximate. Must be finite.
+/// * `tolerance` - The largest acceptable distance from `target`. Must be non-negative.
+///
+/// # Returns
+///
+/// * `Some(fraction)` with the simplest fraction in the interval, or `None` if its numerator or
+///   denominator would not fit in an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rational::{stern_brocot_search, Rational};
+///
+/// // A 1920x1080 screen measured as 1.7777 wide per unit of height.
+/// assert_eq!(stern_brocot_search(1.7777, 1e-3), Some(Rational::new(16, 9)));
+/// assert_eq!(stern_brocot_search(std::f64::consts::PI, 1e-3), Some(Rational::new(201, 64)));
+/// assert_eq!(stern_brocot_search(-0.33, 0.01), Some(Rational::new(-1, 3)));
+/// assert_eq!(stern_brocot_search(2.4, 0.5), Some(Rational::from(2)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `target` is not finite or `tolerance` is negative or NaN.
+pub fn stern_brocot_search(target: f64, tolerance: f64) -> Option<Rational> {
+    assert!(target.is_finite(), "Target must be finite.");
+    assert!(tolerance >= 0.0, "Tolerance must be non-negative.");
+    let (numerator, denominator) = simplest_between(target - tolerance, target + tolerance)?;
+    Some(Rational::new(numerator, denominator))
+}
+
+/// Returns the simplest fraction in the closed interval `[lo, hi]`, as `(numerator, denominator)`.
+fn simplest_between(lo: f64, hi: f64) -> Option<(i64, i64)> {
+    if lo <= 0.0 && 0.0 <= hi {
+        return Some((0, 1));
+    }
+    if hi < 0.0 {
+        let (p, q) = simplest_between(-hi, -lo)?;
+        return Some((-p, q));
+    }
+    let integer = lo.ceil();
+    if integer <= hi {
+        return (integer < i64::MAX as f64).then_some((integer as i64, 1));
+    }
+    // No integer fits, so the answer is floor(lo) + 1/y with y the simplest number in the
+    // reciprocal interval. This is a whole run of Stern–Brocot steps in one direction.
+    let whole = lo.floor();
+    let (p, q) = simplest_between(1.0 / (hi - whole), 1.0 / (lo - whole))?;
+    let numerator = (whole as i64).checked_mul(p)?.checked_add(q)?;
+    Some((numerator, p))
+}
@@ -0,0 +1,340 @@
+/// A small, seedable pseudo-random number generator based on the xoshiro256** algorithm.
+///
+/// `Rng` is the shared source of randomness for the crate. It has no external dependencies and
+/// produces the exact same sequence of values for the same seed on every platform, which makes
+/// simulations, statistical experiments, and randomized tests fully reproducible.
+///
+/// The 256-bit internal state is initialised from a single `u64` seed by running it through the
+/// SplitMix64 generator, as recommended by the authors of xoshiro. This guarantees that even
+/// "poor" seeds such as `0` or `1` produce a well-mixed starting state.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rng::Rng;
+///
+/// let mut a = Rng::new(42);
+/// let mut b = Rng::new(42);
+/// assert_eq!(a.next_u64(), b.next_u64()); // Same seed, same sequence.
+///
+/// let x = a.next_f64();
+/// assert!((0.0..1.0).contains(&x));
+/// ```
+///
+/// # Notes
+///
+/// This generator is fast and statistically strong, but it is **not** cryptographically secure.
+/// Do not use it to generate keys, tokens, or anything an attacker should not be able to predict.
+///
+/// # References
+///
+/// * [xoshiro / xoroshiro generators](https://prng.di.unimi.it/)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: [u64; 4],
+}
+
+impl Rng {
+    /// Creates a new generator from the given seed.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - Any `u64` value. Equal seeds always produce equal sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(7);
+    /// let value = rng.next_u64();
+    /// assert_eq!(Rng::new(7).next_u64(), value);
+    /// ```
+    pub fn new(seed: u64) -> Self {
+        let mut sm = seed;
+        let mut state = [0u64; 4];
+        for slot in state.iter_mut() {
+            *slot = splitmix64(&mut sm);
+        }
+        Rng { state }
+    }
+
+    /// Returns the next pseudo-random `u64`, uniformly distributed over all 2^64 values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(1);
+    /// assert_ne!(rng.next_u64(), rng.next_u64());
+    /// ```
+    pub fn next_u64(&mut self) -> u64 {
+        let result = self.state[1].wrapping_mul(5).rotate_left(7).wrapping_mul(9);
+        let t = self.state[1] << 17;
+
+        self.state[2] ^= self.state[0];
+        self.state[3] ^= self.state[1];
+        self.state[1] ^= self.state[2];
+        self.state[0] ^= self.state[3];
+
+        self.state[2] ^= t;
+        self.state[3] = self.state[3].rotate_left(45);
+
+        result
+    }
+
+    /// Returns the next pseudo-random `f64`, uniformly distributed in the half-open range `[0, 1)`.
+    ///
+    /// The value is built from the top 53 bits of [`Rng::next_u64`], so every representable
+    /// multiple of `2^-53` in the range is equally likely.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(123);
+    /// for _ in 0..1000 {
+    ///     let x = rng.next_f64();
+    ///     assert!(x >= 0.0 && x < 1.0);
+    /// }
+    /// ```
+    pub fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Returns a pseudo-random `f64` uniformly distributed in the half-open range `[low, high)`.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The inclusive lower bound of the range.
+    /// * `high` - The exclusive upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high` or either bound is not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(5);
+    /// let x = rng.uniform(-2.0, 3.0);
+    /// assert!((-2.0..3.0).contains(&x));
+    /// ```
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        assert!(low.is_finite() && high.is_finite(), "Range bounds must be finite.");
+        assert!(low < high, "Lower bound must be less than upper bound.");
+        let x = low + (high - low) * self.next_f64();
+        // Rounding can land exactly on `high` for very wide ranges; keep the range half-open.
+        if x < high { x } else { low }
+    }
+
+    /// Returns a pseudo-random integer uniformly distributed in the half-open range `[low, high)`.
+    ///
+    /// Rejection sampling is used so that every value in the range is exactly equally likely,
+    /// avoiding the modulo bias of `next_u64() % n`.
+    ///
+    /// # Arguments
+    ///
+    /// * `low` - The inclusive lower bound of the range.
+    /// * `high` - The exclusive upper bound of the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(5);
+    /// let die = rng.uniform_int(1, 7);
+    /// assert!((1..=6).contains(&die));
+    /// ```
+    pub fn uniform_int(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low < high, "Lower bound must be less than upper bound.");
+        let span = high - low;
+        let zone = u64::MAX - (u64::MAX - span + 1) % span;
+        loop {
+            let x = self.next_u64();
+            if x <= zone {
+                return low + x % span;
+            }
+        }
+    }
+
+    /// Returns a sample from the normal (Gaussian) distribution with the given mean and standard deviation.
+    ///
+    /// Samples are generated with the Box–Muller transform. Each call consumes two uniform values
+    /// and returns one normally distributed value.
+    ///
+    /// # Arguments
+    ///
+    /// * `mean` - The mean (centre) of the distribution.
+    /// * `std_dev` - The standard deviation (spread) of the distribution. Must be non-negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `std_dev` is negative or not finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(2024);
+    /// let n = 10_000;
+    /// let mean = (0..n).map(|_| rng.normal(10.0, 2.0)).sum::<f64>() / n as f64;
+    /// assert!((mean - 10.0).abs() < 0.1);
+    /// ```
+    pub fn normal(&mut self, mean: f64, std_dev: f64) -> f64 {
+        assert!(std_dev.is_finite() && std_dev >= 0.0, "Standard deviation must be non-negative.");
+        // 1 - u lies in (0, 1], which keeps the logarithm finite.
+        let u1 = 1.0 - self.next_f64();
+        let u2 = self.next_f64();
+        let z = (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos();
+        mean + std_dev * z
+    }
+
+    /// Returns a sample from the exponential distribution with rate `lambda`.
+    ///
+    /// Samples are generated by inverse transform sampling, `-ln(U) / lambda`. The mean of the
+    /// distribution is `1 / lambda`.
+    ///
+    /// # Arguments
+    ///
+    /// * `lambda` - The rate parameter. Must be positive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is not positive and finite.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(99);
+    /// let n = 10_000;
+    /// let mean = (0..n).map(|_| rng.exponential(4.0)).sum::<f64>() / n as f64;
+    /// assert!((mean - 0.25).abs() < 0.01);
+    /// ```
+    pub fn exponential(&mut self, lambda: f64) -> f64 {
+        assert!(lambda.is_finite() && lambda > 0.0, "Rate must be positive.");
+        -(1.0 - self.next_f64()).ln() / lambda
+    }
+
+    /// Returns `true` with probability `p` and `false` otherwise (a Bernoulli trial).
+    ///
+    /// # Arguments
+    ///
+    /// * `p` - The probability of success. Must lie in the range [0, 1].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is outside the range [0, 1].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::rng::Rng;
+    ///
+    /// let mut rng = Rng::new(11);
+    /// assert!(rng.bernoulli(1.0));
+    /// assert!(!rng.bernoulli(0.0));
+    /// ```
+    pub fn bernoulli(&mut self, p: f64) -> bool {
+        assert!((0.0..=1.0).contains(&p), "Probability must lie in the range [0, 1].");
+        self.next_f64() < p
+    }
+}
+
+/// Advances a SplitMix64 state and returns the next output.
+///
+/// This is a helper used by `Rng::new` to expand a single seed into the full generator state.
+fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Shuffles a slice in place using the Fisher–Yates algorithm.
+///
+/// Every permutation of the slice is equally likely (up to the quality of the generator), which
+/// makes this suitable for permutation tests and randomised experiments.
+///
+/// # Arguments
+///
+/// * `data` - The slice to shuffle. It is reordered in place.
+/// * `rng` - The random number generator used to pick swap positions.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rng::{shuffle, Rng};
+///
+/// let mut rng = Rng::new(3);
+/// let mut cards = vec![1, 2, 3, 4, 5];
+/// shuffle(&mut cards, &mut rng);
+///
+/// let mut sorted = cards.clone();
+/// sorted.sort();
+/// assert_eq!(sorted, vec![1, 2, 3, 4, 5]); // Same elements, new order.
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n)` time and does not allocate.
+pub fn shuffle<T>(data: &mut [T], rng: &mut Rng) {
+    for i in (1..data.len()).rev() {
+        let j = rng.uniform_int(0, i as u64 + 1) as usize;
+        data.swap(i, j);
+    }
+}
+
+/// Draws `k` distinct elements from `data` uniformly at random (sampling without replacement).
+///
+/// The selection is made with a partial Fisher–Yates shuffle over the element indices, so only
+/// `k` swaps are performed. The returned elements are in random order.
+///
+/// # Arguments
+///
+/// * `data` - The slice to sample from.
+/// * `k` - The number of elements to draw. If `k` exceeds `data.len()`, every element is returned
+///   (in shuffled order).
+/// * `rng` - The random number generator used for the selection.
+///
+/// # Returns
+///
+/// * A `Vec<T>` containing `min(k, data.len())` elements cloned from `data`.
+///
+/// # Examples
+///
+/// ```
+/// use hell::rng::{sample_k, Rng};
+///
+/// let mut rng = Rng::new(10);
+/// let population: Vec<u32> = (0..100).collect();
+/// let sample = sample_k(&population, 5, &mut rng);
+///
+/// assert_eq!(sample.len(), 5);
+/// let mut unique = sample.clone();
+/// unique.sort();
+/// unique.dedup();
+/// assert_eq!(unique.len(), 5); // No element is drawn twice.
+/// ```
+pub fn sample_k<T: Clone>(data: &[T], k: usize, rng: &mut Rng) -> Vec<T> {
+    let k = k.min(data.len());
+    let mut indices: Vec<usize> = (0..data.len()).collect();
+    for i in 0..k {
+        let j = rng.uniform_int(i as u64, data.len() as u64) as usize;
+        indices.swap(i, j);
+    }
+    indices[..k].iter().map(|&i| data[i].clone()).collect()
+}
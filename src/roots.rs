@@ -0,0 +1,263 @@
+use crate::calculus::jacobian;
+use crate::error::HellError;
+use crate::matrix::solve_linear_system;
+use crate::tolerance::Tolerance;
+
+/// Finds a root of `func` in the interval `[a, b]` by bisection.
+///
+/// The interval must bracket a root: `func(a)` and `func(b)` must have opposite signs (or one of
+/// them must be zero). Each iteration halves the interval while keeping the sign change inside it,
+/// so the method always converges, if slowly, for any continuous function.
+///
+/// # Arguments
+///
+/// * `func` - The function whose root is sought.
+/// * `a` - One end of the bracketing interval.
+/// * `b` - The other end of the bracketing interval.
+/// * `tolerance` - The iteration stops once the bracket is narrower than this. Must be positive.
+/// * `max_iter` - The maximum number of halvings.
+///
+/// # Returns
+///
+/// * `Ok(x)` with a point within `tolerance` of a root.
+/// * `Err(HellError::DomainError)` if `func(a)` and `func(b)` have the same sign, or either is NaN.
+/// * `Err(HellError::ConvergenceFailure)` if the bracket is still too wide after `max_iter`
+///   iterations.
+///
+/// # Examples
+///
+/// ```
+/// use hell::roots::bisection;
+///
+/// let root = bisection(|x| x * x - 2.0, 0.0, 2.0, 1e-12, 100).unwrap();
+/// assert!((root - 2f64.sqrt()).abs() < 1e-12);
+///
+/// assert!(bisection(|x| x * x + 1.0, -1.0, 1.0, 1e-12, 100).is_err());
+/// ```
+///
+/// # Performance
+///
+/// About `log2((b − a) / tolerance)` function evaluations are needed.
+pub fn bisection<F>(func: F, a: f64, b: f64, tolerance: f64, max_iter: usize) -> Result<f64, HellError>
+where
+    F: Fn(f64) -> f64,
+{
+    bisection_with_tolerance(func, a, b, Tolerance { absolute: tolerance, relative: 0.0 }, max_iter)
+}
+
+/// Finds a root of `func` in the interval `[a, b]` by bisection, stopping once the ends of the
+/// bracket are equal within `tolerance`.
+///
+/// This is [`bisection`] with a [`Tolerance`] in place of a fixed bracket width. Its relative part
+/// lets one tolerance serve roots of any magnitude.
+///
+/// # Arguments
+///
+/// * `func` - The function whose root is sought.
+/// * `a` - One end of the bracketing interval.
+/// * `b` - The other end of the bracketing interval.
+/// * `tolerance` - Decides when the bracket is narrow enough, by [`Tolerance::approx_eq`] on its
+///   ends.
+/// * `max_iter` - The maximum number of halvings.
+///
+/// # Returns
+///
+/// The same as [`bisection`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::roots::bisection_with_tolerance;
+/// use hell::tolerance::Tolerance;
+///
+/// // A root near 1e6, found to ten significant digits.
+/// let root = bisection_with_tolerance(|x| x - 1e6 - 0.25, 0.0, 2e6, Tolerance::DEFAULT, 100).unwrap();
+/// assert!((root - 1_000_000.25).abs() < 1e-3);
+/// ```
+pub fn bisection_with_tolerance<F>(func: F, a: f64, b: f64, tolerance: Tolerance, max_iter: usize) -> Result<f64, HellError>
+where
+    F: Fn(f64) -> f64,
+{
+    let (mut lo, mut hi) = (a.min(b), a.max(b));
+    let (mut f_lo, f_hi) = (func(lo), func(hi));
+    if f_lo == 0.0 {
+        return Ok(lo);
+    }
+    if f_hi == 0.0 {
+        return Ok(hi);
+    }
+    if f_lo.is_nan() || f_hi.is_nan() || f_lo.signum() == f_hi.signum() {
+        return Err(HellError::DomainError("Interval does not bracket a root."));
+    }
+    for _ in 0..max_iter {
+        let mid = lo + (hi - lo) / 2.0;
+        if tolerance.approx_eq(lo, hi) || mid == lo || mid == hi {
+            return Ok(mid);
+        }
+        let f_mid = func(mid);
+        if f_mid == 0.0 {
+            return Ok(mid);
+        }
+        if f_mid.signum() == f_lo.signum() {
+            (lo, f_lo) = (mid, f_mid);
+        } else {
+            hi = mid;
+        }
+    }
+    Err(HellError::ConvergenceFailure("Bisection did not reach the requested tolerance."))
+}
+
+/// Finds a root of `func` with Newton's method, starting from `x0`.
+///
+/// Each step follows the tangent line, `x ← x − f(x)/f'(x)`. Near a simple root convergence is
+/// quadratic, roughly doubling the number of correct digits per step, but the method can diverge
+/// from a poor starting point.
+///
+/// # Arguments
+///
+/// * `func` - The function whose root is sought.
+/// * `derivative` - The derivative of `func`. [`crate::calculus::derivative`] can supply a
+///   numerical one.
+/// * `x0` - The starting point.
+/// * `tolerance` - The iteration stops once a step is smaller than this.
+/// * `max_iter` - The maximum number of steps.
+///
+/// # Returns
+///
+/// * `Ok(x)` once a step smaller than `tolerance` is taken.
+/// * `Err(HellError::ConvergenceFailure)` if the derivative vanishes, an iterate is not finite, or
+///   `max_iter` steps are taken without converging.
+///
+/// # Examples
+///
+/// ```
+/// use hell::roots::newton;
+///
+/// let root = newton(|x| x.cos() - x, |x| -x.sin() - 1.0, 1.0, 1e-12, 50).unwrap();
+/// assert!((root - 0.739_085_133_215_160_6).abs() < 1e-12);
+/// ```
+pub fn newton<F, D>(func: F, derivative: D, x0: f64, tolerance: f64, max_iter: usize) -> Result<f64, HellError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    newton_with_tolerance(func, derivative, x0, Tolerance { absolute: tolerance, relative: 0.0 }, max_iter)
+}
+
+/// Finds a root of `func` with Newton's method, starting from `x0`, stopping once a step leaves
+/// the iterate unchanged within `tolerance`.
+///
+/// This is [`newton`] with a [`Tolerance`] in place of a fixed step size. Its relative part lets
+/// one tolerance serve roots of any magnitude.
+///
+/// # Arguments
+///
+/// * `func` - The function whose root is sought.
+/// * `derivative` - The derivative of `func`.
+/// * `x0` - The starting point.
+/// * `tolerance` - Decides when a step is small enough, by [`Tolerance::approx_eq`] on the iterates
+///   before and after it.
+/// * `max_iter` - The maximum number of steps.
+///
+/// # Returns
+///
+/// The same as [`newton`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::roots::newton_with_tolerance;
+/// use hell::tolerance::Tolerance;
+///
+/// // √(2·10²⁰), where a fixed step size of 1e-12 would be far below the spacing of f64 values.
+/// let root = newton_with_tolerance(|x| x * x - 2e20, |x| 2.0 * x, 1e10, Tolerance::DEFAULT, 50).unwrap();
+/// assert!((root / (2f64.sqrt() * 1e10) - 1.0).abs() < 1e-12);
+/// ```
+pub fn newton_with_tolerance<F, D>(func: F, derivative: D, x0: f64, tolerance: Tolerance, max_iter: usize) -> Result<f64, HellError>
+where
+    F: Fn(f64) -> f64,
+    D: Fn(f64) -> f64,
+{
+    let mut x = x0;
+    for _ in 0..max_iter {
+        let slope = derivative(x);
+        if slope == 0.0 {
+            return Err(HellError::ConvergenceFailure("Derivative vanished during Newton iteration."));
+        }
+        let step = func(x) / slope;
+        let previous = x;
+        x -= step;
+        if !x.is_finite() {
+            return Err(HellError::ConvergenceFailure("Newton iteration diverged."));
+        }
+        if tolerance.approx_eq(previous, x) {
+            return Ok(x);
+        }
+    }
+    Err(HellError::ConvergenceFailure("Newton iteration did not converge."))
+}
+
+/// Solves the nonlinear system `func(x) = 0` with Newton's method, starting from `x0`.
+///
+/// This is [`newton`] in several dimensions. Each step computes the Jacobian `J` of `func` at the
+/// current point with [`crate::calculus::jacobian`], solves the linear system `J·Δ = −func(x)`,
+/// and moves to `x + Δ`.
+///
+/// # Arguments
+///
+/// * `func` - The system to solve, mapping `n` unknowns to `n` residuals.
+/// * `x0` - The starting point.
+/// * `tolerance` - The iteration stops once every component of a step is smaller than this.
+/// * `max_iter` - The maximum number of steps.
+///
+/// # Returns
+///
+/// * `Ok(x)` once a small enough step is taken.
+/// * `Err(HellError::DimensionMismatch)` if `func` does not return one residual per unknown.
+/// * `Err(HellError::ConvergenceFailure)` if the Jacobian becomes singular, an iterate is not
+///   finite, or `max_iter` steps are taken without converging.
+///
+/// # Examples
+///
+/// ```
+/// use hell::roots::solve_nonlinear_system;
+///
+/// // The circle x² + y² = 4 meets the hyperbola xy = 1 at (1.9319, 0.5176) near the start point.
+/// let system = |v: &[f64]| vec![v[0] * v[0] + v[1] * v[1] - 4.0, v[0] * v[1] - 1.0];
+/// let root = solve_nonlinear_system(system, &[2.0, 0.5], 1e-12, 50).unwrap();
+///
+/// assert!((root[0] * root[0] + root[1] * root[1] - 4.0).abs() < 1e-9);
+/// assert!((root[0] * root[1] - 1.0).abs() < 1e-9);
+/// ```
+///
+/// # Notes
+///
+/// Like the scalar method, convergence is fast near a root with a nonsingular Jacobian but not
+/// guaranteed from a poor starting point. The Jacobian is approximated numerically, so each step
+/// costs `2n + 1` evaluations of `func` and one `O(n³)` linear solve.
+pub fn solve_nonlinear_system<F>(func: F, x0: &[f64], tolerance: f64, max_iter: usize) -> Result<Vec<f64>, HellError>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    let mut x = x0.to_vec();
+    for _ in 0..max_iter {
+        let residual = func(&x);
+        if residual.len() != x.len() {
+            return Err(HellError::DimensionMismatch("System must have as many equations as unknowns."));
+        }
+        let h = x.iter().fold(1.0_f64, |scale, v| scale.max(v.abs())) * 1e-7;
+        let rhs = residual.iter().map(|r| -r).collect();
+        let step = solve_linear_system(jacobian(&func, &x, h).to_rows(), rhs, Tolerance::DEFAULT)
+            .ok_or(HellError::ConvergenceFailure("Jacobian became singular during Newton iteration."))?;
+        for (xi, di) in x.iter_mut().zip(&step) {
+            *xi += di;
+        }
+        if x.iter().any(|v| !v.is_finite()) {
+            return Err(HellError::ConvergenceFailure("Newton iteration diverged."));
+        }
+        if step.iter().all(|d| d.abs() <= tolerance) {
+            return Ok(x);
+        }
+    }
+    Err(HellError::ConvergenceFailure("Newton iteration did not converge."))
+}
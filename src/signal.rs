@@ -0,0 +1,386 @@
+use crate::fft::{convolve, convolve_fft, CONVOLUTION_FFT_THRESHOLD};
+use std::f64::consts::PI;
+
+/// The shape of a window function, used by [`window`].
+///
+/// Multiplying a finite block of samples by a window that tapers to the edges reduces spectral
+/// leakage when the block is transformed with the [FFT](crate::fft). The windows trade frequency
+/// resolution (main-lobe width) against leakage suppression (side-lobe level).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WindowKind {
+    /// All ones: no tapering. Best resolution, worst leakage.
+    Rectangular,
+    /// `0.5 − 0.5·cos(2πk/(N−1))`. A good general-purpose default.
+    Hann,
+    /// `0.54 − 0.46·cos(2πk/(N−1))`. Lower first side lobe than Hann, but slower decay.
+    Hamming,
+    /// `0.42 − 0.5·cos(2πk/(N−1)) + 0.08·cos(4πk/(N−1))`. Strong leakage suppression, wide main
+    /// lobe.
+    Blackman,
+}
+
+/// Generates a window function of length `n`.
+///
+/// The windows are symmetric: `w[k] == w[n − 1 − k]`, and the tapered windows start and end at (or
+/// near) zero. This is the usual form for filter design and for analysing a single block of
+/// samples.
+///
+/// # Arguments
+///
+/// * `kind` - The shape of the window.
+/// * `n` - The number of samples. A window of length 1 is `[1.0]`.
+///
+/// # Returns
+///
+/// * The `n` window coefficients.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::{window, WindowKind};
+///
+/// let hann = window(WindowKind::Hann, 5);
+/// let expected = [0.0, 0.5, 1.0, 0.5, 0.0];
+/// assert!(hann.iter().zip(expected).all(|(a, b)| (a - b).abs() < 1e-12));
+///
+/// assert_eq!(window(WindowKind::Rectangular, 3), vec![1.0, 1.0, 1.0]);
+/// assert!((window(WindowKind::Hamming, 5)[0] - 0.08).abs() < 1e-12);
+///
+/// // Apply a window to a block of samples before transforming it.
+/// let samples = [0.3, 0.9, -0.2, 0.4];
+/// let windowed: Vec<f64> = samples.iter().zip(window(WindowKind::Blackman, 4)).map(|(x, w)| x * w).collect();
+/// assert_eq!(windowed.len(), 4);
+/// ```
+pub fn window(kind: WindowKind, n: usize) -> Vec<f64> {
+    if n == 1 {
+        return vec![1.0];
+    }
+    let denominator = n.saturating_sub(1) as f64;
+    (0..n)
+        .map(|k| {
+            let phase = 2.0 * PI * k as f64 / denominator;
+            match kind {
+                WindowKind::Rectangular => 1.0,
+                WindowKind::Hann => 0.5 - 0.5 * phase.cos(),
+                WindowKind::Hamming => 0.54 - 0.46 * phase.cos(),
+                WindowKind::Blackman => 0.42 - 0.5 * phase.cos() + 0.08 * (2.0 * phase).cos(),
+            }
+        })
+        .collect()
+}
+
+/// A streaming finite impulse response (FIR) filter.
+///
+/// The output is `y[n] = Σ h[k]·x[n − k]`, where `h` are the filter coefficients. The filter keeps
+/// the last few input samples between calls, so a long signal can be fed in chunks of any size
+/// and the result is the same as filtering it in one go. Samples before the first input are
+/// treated as zero.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::{filter_fir, FirFilter};
+///
+/// let signal = [1.0, 2.0, 3.0, 4.0, 5.0];
+/// let coefficients = [0.5, 0.5]; // Two-point average.
+///
+/// let mut filter = FirFilter::new(&coefficients);
+/// let mut streamed = filter.process(&signal[..2]);
+/// streamed.extend(filter.process(&signal[2..]));
+///
+/// assert_eq!(streamed, filter_fir(&signal, &coefficients));
+/// assert_eq!(streamed, vec![0.5, 1.5, 2.5, 3.5, 4.5]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct FirFilter {
+    coefficients: Vec<f64>,
+    /// Ring buffer of the most recent inputs; `history[position]` is the oldest.
+    history: Vec<f64>,
+    position: usize,
+}
+
+impl FirFilter {
+    /// Creates a filter with the given coefficients, `coefficients[k]` weighting the input from
+    /// `k` samples ago.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `coefficients` is empty.
+    pub fn new(coefficients: &[f64]) -> Self {
+        assert!(!coefficients.is_empty(), "A FIR filter needs at least one coefficient.");
+        FirFilter { coefficients: coefficients.to_vec(), history: vec![0.0; coefficients.len()], position: 0 }
+    }
+
+    /// Filters a single sample and returns the corresponding output.
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let len = self.history.len();
+        self.history[self.position] = x;
+        // coefficients[k] pairs with the input k samples ago, walking the ring buffer backwards.
+        let output = self
+            .coefficients
+            .iter()
+            .enumerate()
+            .map(|(k, h)| h * self.history[(self.position + len - k) % len])
+            .sum();
+        self.position = (self.position + 1) % len;
+        output
+    }
+
+    /// Filters a chunk of samples, continuing from the end of the previous chunk.
+    pub fn process(&mut self, chunk: &[f64]) -> Vec<f64> {
+        chunk.iter().map(|&x| self.process_sample(x)).collect()
+    }
+
+    /// Clears the stored history, as if no samples had been processed.
+    pub fn reset(&mut self) {
+        self.history.fill(0.0);
+        self.position = 0;
+    }
+}
+
+/// Filters a whole signal with a FIR filter.
+///
+/// This is a convenience wrapper around [`FirFilter`]. The output has the same length as the
+/// input; samples before the start of the signal are treated as zero.
+///
+/// # Arguments
+///
+/// * `signal` - The input samples.
+/// * `coefficients` - The filter coefficients. Must not be empty.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::filter_fir;
+///
+/// // A first difference highlights changes in the signal.
+/// assert_eq!(filter_fir(&[1.0, 1.0, 4.0, 4.0], &[1.0, -1.0]), vec![1.0, 0.0, 3.0, 0.0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `coefficients` is empty.
+pub fn filter_fir(signal: &[f64], coefficients: &[f64]) -> Vec<f64> {
+    FirFilter::new(coefficients).process(signal)
+}
+
+/// A streaming simple moving average over the last `window` samples.
+///
+/// A running sum is kept, so each sample costs `O(1)` regardless of the window size. Until
+/// `window` samples have been seen, the average is taken over the samples seen so far.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::MovingAverage;
+///
+/// let mut average = MovingAverage::new(3);
+/// assert_eq!(average.process(&[3.0, 6.0, 9.0, 12.0]), vec![3.0, 4.5, 6.0, 9.0]);
+/// assert_eq!(average.process_sample(0.0), 7.0); // Continues across calls.
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovingAverage {
+    window: Vec<f64>,
+    position: usize,
+    filled: usize,
+    sum: f64,
+}
+
+impl MovingAverage {
+    /// Creates a moving average over the last `window` samples.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn new(window: usize) -> Self {
+        assert!(window > 0, "Window size must be positive.");
+        MovingAverage { window: vec![0.0; window], position: 0, filled: 0, sum: 0.0 }
+    }
+
+    /// Adds a sample and returns the current average.
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        self.sum += x - self.window[self.position];
+        self.window[self.position] = x;
+        self.position = (self.position + 1) % self.window.len();
+        self.filled = (self.filled + 1).min(self.window.len());
+        self.sum / self.filled as f64
+    }
+
+    /// Adds a chunk of samples and returns the average after each one.
+    pub fn process(&mut self, chunk: &[f64]) -> Vec<f64> {
+        chunk.iter().map(|&x| self.process_sample(x)).collect()
+    }
+}
+
+/// Smooths a whole signal with a simple moving average over `window` samples.
+///
+/// This is a convenience wrapper around [`MovingAverage`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::moving_average;
+///
+/// assert_eq!(moving_average(&[2.0, 4.0, 6.0, 8.0], 2), vec![2.0, 3.0, 5.0, 7.0]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `window` is zero.
+pub fn moving_average(signal: &[f64], window: usize) -> Vec<f64> {
+    MovingAverage::new(window).process(signal)
+}
+
+/// A streaming exponential moving average, `y[n] = α·x[n] + (1 − α)·y[n − 1]`.
+///
+/// Recent samples get weight `α` and older ones decay geometrically, so the average reacts
+/// smoothly to changes without storing any history. The first sample initialises the average.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::ExponentialMovingAverage;
+///
+/// let mut ema = ExponentialMovingAverage::new(0.5);
+/// assert_eq!(ema.process(&[4.0, 8.0, 8.0]), vec![4.0, 6.0, 7.0]);
+/// assert_eq!(ema.value(), Some(7.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExponentialMovingAverage {
+    alpha: f64,
+    value: Option<f64>,
+}
+
+impl ExponentialMovingAverage {
+    /// Creates an exponential moving average with smoothing factor `alpha`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not in the range `(0, 1]`.
+    pub fn new(alpha: f64) -> Self {
+        assert!(alpha > 0.0 && alpha <= 1.0, "Smoothing factor must lie in the range (0, 1].");
+        ExponentialMovingAverage { alpha, value: None }
+    }
+
+    /// Returns the current average, or `None` if no samples have been processed.
+    pub fn value(&self) -> Option<f64> {
+        self.value
+    }
+
+    /// Adds a sample and returns the updated average.
+    pub fn process_sample(&mut self, x: f64) -> f64 {
+        let value = match self.value {
+            Some(previous) => previous + self.alpha * (x - previous),
+            None => x,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    /// Adds a chunk of samples and returns the average after each one.
+    pub fn process(&mut self, chunk: &[f64]) -> Vec<f64> {
+        chunk.iter().map(|&x| self.process_sample(x)).collect()
+    }
+}
+
+/// Smooths a whole signal with an exponential moving average.
+///
+/// This is a convenience wrapper around [`ExponentialMovingAverage`].
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::exponential_moving_average;
+///
+/// assert_eq!(exponential_moving_average(&[0.0, 10.0, 10.0], 0.2), vec![0.0, 2.0, 3.6]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `alpha` is not in the range `(0, 1]`.
+pub fn exponential_moving_average(signal: &[f64], alpha: f64) -> Vec<f64> {
+    ExponentialMovingAverage::new(alpha).process(signal)
+}
+
+/// Computes the autocorrelation `r[k] = Σ x[n]·x[n + k]` of a signal for lags `0..=max_lag`.
+///
+/// Peaks of the autocorrelation at non-zero lags reveal periodicity: a signal that repeats every
+/// `p` samples correlates strongly with itself shifted by `p`. Divide by `r[0]` to get
+/// coefficients in `[-1, 1]`, and subtract the mean first to ignore a constant offset.
+///
+/// Small lag ranges are computed directly in `O(n·max_lag)`; larger ones go through the FFT in
+/// `O(n log n)`.
+///
+/// # Arguments
+///
+/// * `signal` - The input samples.
+/// * `max_lag` - The largest lag to compute. Lags beyond `signal.len() − 1` are all zero and are
+///   omitted.
+///
+/// # Returns
+///
+/// * The values `r[0], r[1], ..., r[min(max_lag, n − 1)]`, or an empty vector for an empty signal.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::autocorrelation;
+///
+/// assert_eq!(autocorrelation(&[1.0, 2.0, 3.0], 5), vec![14.0, 8.0, 3.0]);
+///
+/// // A signal with period 4 correlates best with itself shifted by 4.
+/// let signal: Vec<f64> = (0..400).map(|i| [1.0, 0.0, -1.0, 0.0][i % 4]).collect();
+/// let r = autocorrelation(&signal, 100);
+/// let best = (1..r.len()).max_by(|&a, &b| r[a].total_cmp(&r[b])).unwrap();
+/// assert_eq!(best, 4);
+/// ```
+pub fn autocorrelation(signal: &[f64], max_lag: usize) -> Vec<f64> {
+    let n = signal.len();
+    if n == 0 {
+        return Vec::new();
+    }
+    let lags = max_lag.min(n - 1) + 1;
+    if lags <= CONVOLUTION_FFT_THRESHOLD {
+        (0..lags).map(|k| signal.iter().zip(&signal[k..]).map(|(a, b)| a * b).sum()).collect()
+    } else {
+        let reversed: Vec<f64> = signal.iter().rev().copied().collect();
+        convolve_fft(signal, &reversed)[n - 1..n - 1 + lags].to_vec()
+    }
+}
+
+/// Computes the full cross-correlation `c[lag] = Σ a[n + lag]·b[n]` of two signals.
+///
+/// The result covers every lag at which the signals overlap, from `−(b.len() − 1)` to
+/// `a.len() − 1`; element `i` holds lag `i − (b.len() − 1)`. The lag of the largest value is the
+/// shift that best aligns `b` with `a`, which is how delays between two recordings are measured.
+///
+/// This is the convolution of `a` with `b` reversed, and is computed with [`convolve`], which
+/// switches to the FFT for long inputs.
+///
+/// # Arguments
+///
+/// * `a` - The first signal.
+/// * `b` - The second signal.
+///
+/// # Returns
+///
+/// * The `a.len() + b.len() − 1` correlation values, or an empty vector if either input is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hell::signal::cross_correlation;
+///
+/// assert_eq!(cross_correlation(&[1.0, 2.0, 3.0], &[0.0, 1.0]), vec![1.0, 2.0, 3.0, 0.0]);
+///
+/// // `a` is `b` delayed by 3 samples.
+/// let b = [0.0, 1.0, 5.0, 2.0, 0.0, 0.0, 0.0, 0.0];
+/// let a = [0.0, 0.0, 0.0, 0.0, 1.0, 5.0, 2.0, 0.0];
+/// let c = cross_correlation(&a, &b);
+/// let best = (0..c.len()).max_by(|&i, &j| c[i].total_cmp(&c[j])).unwrap();
+/// assert_eq!(best as isize - (b.len() as isize - 1), 3);
+/// ```
+pub fn cross_correlation(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let reversed: Vec<f64> = b.iter().rev().copied().collect();
+    convolve(a, &reversed)
+}
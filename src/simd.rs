@@ -0,0 +1,247 @@
+//! Vectorized kernels on `f64` slices, used by the `simd` feature.
+//!
+//! Each kernel checks at runtime for the widest supported instruction set (AVX on x86-64, NEON on
+//! AArch64) and otherwise falls back to a plain loop. [`gemm`], [`axpy`] and [`add`] perform
+//! exactly the same floating-point operations as the scalar loops, lane by lane and in the same
+//! order, so their results are identical; [`dot`] keeps several partial sums and adds them at the
+//! end, so its result can differ from a sequential sum in the last bits.
+
+use std::ops::Range;
+
+/// Computes `c += a · b`, where `a` is `m × k`, `b` is `k × n` and `c` is `m × n`, each stored
+/// row-major with the given row strides. Every element of `c` adds its products in increasing
+/// order of `k`.
+///
+/// # Panics
+/// Panics if a slice is too short for its dimensions and stride.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn gemm(m: usize, k: usize, n: usize, a: &[f64], lda: usize, b: &[f64], ldb: usize, c: &mut [f64], ldc: usize) {
+    if m == 0 || n == 0 || k == 0 {
+        return;
+    }
+    assert!(a.len() >= (m - 1) * lda + k && b.len() >= (k - 1) * ldb + n && c.len() >= (m - 1) * ldc + n, "Matrix block out of bounds.");
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx") {
+        // SAFETY: the CPU supports AVX, and the slices cover the blocks, as just checked.
+        return unsafe { x86::gemm(m, k, n, a, lda, b, ldb, c, ldc) };
+    }
+    scalar_gemm(0..m, 0..n, k, a, lda, b, ldb, c, ldc);
+}
+
+/// Computes `y[i] += alpha · x[i]` over the common length of `x` and `y`.
+pub(crate) fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx") {
+        // SAFETY: the CPU supports AVX, as just checked.
+        return unsafe { x86::axpy(alpha, x, y) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        // SAFETY: the CPU supports NEON, as just checked.
+        return unsafe { neon::axpy(alpha, x, y) };
+    }
+    scalar_axpy(alpha, x, y);
+}
+
+/// Computes `y[i] += x[i]` over the common length of `x` and `y`.
+pub(crate) fn add(x: &[f64], y: &mut [f64]) {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx") {
+        // SAFETY: the CPU supports AVX, as just checked.
+        return unsafe { x86::add(x, y) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        // SAFETY: the CPU supports NEON, as just checked.
+        return unsafe { neon::add(x, y) };
+    }
+    scalar_add(x, y);
+}
+
+/// Returns the dot product of `x` and `y` over their common length.
+pub(crate) fn dot(x: &[f64], y: &[f64]) -> f64 {
+    #[cfg(target_arch = "x86_64")]
+    if is_x86_feature_detected!("avx") {
+        // SAFETY: the CPU supports AVX, as just checked.
+        return unsafe { x86::dot(x, y) };
+    }
+    #[cfg(target_arch = "aarch64")]
+    if std::arch::is_aarch64_feature_detected!("neon") {
+        // SAFETY: the CPU supports NEON, as just checked.
+        return unsafe { neon::dot(x, y) };
+    }
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+/// The scalar version of [`gemm`], restricted to the given rows and columns of `c`.
+#[allow(clippy::too_many_arguments)]
+fn scalar_gemm(rows: Range<usize>, cols: Range<usize>, k: usize, a: &[f64], lda: usize, b: &[f64], ldb: usize, c: &mut [f64], ldc: usize) {
+    for i in rows {
+        let c_row = &mut c[i * ldc + cols.start..i * ldc + cols.end];
+        for kk in 0..k {
+            scalar_axpy(a[i * lda + kk], &b[kk * ldb + cols.start..kk * ldb + cols.end], c_row);
+        }
+    }
+}
+
+fn scalar_axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (y, &x) in y.iter_mut().zip(x) {
+        *y += alpha * x;
+    }
+}
+
+fn scalar_add(x: &[f64], y: &mut [f64]) {
+    for (y, &x) in y.iter_mut().zip(x) {
+        *y += x;
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86 {
+    use std::arch::x86_64::*;
+
+    const LANES: usize = 4;
+
+    /// The rows of `c` kept in registers by the `gemm` micro-kernel.
+    const TILE_ROWS: usize = 4;
+
+    /// The columns of `c` kept in registers by the `gemm` micro-kernel.
+    const TILE_COLS: usize = 2 * LANES;
+
+    /// Computes `c += a · b` with a micro-kernel that holds a `TILE_ROWS × TILE_COLS` tile of `c`
+    /// in registers while it runs through `k`, and the scalar loop for the edges.
+    ///
+    /// # Safety
+    /// The CPU must support AVX, and the slices must cover their blocks as checked by
+    /// [`super::gemm`].
+    #[allow(clippy::too_many_arguments)]
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn gemm(m: usize, k: usize, n: usize, a: &[f64], lda: usize, b: &[f64], ldb: usize, c: &mut [f64], ldc: usize) {
+        let m_main = m - m % TILE_ROWS;
+        let n_main = n - n % TILE_COLS;
+        for i in (0..m_main).step_by(TILE_ROWS) {
+            for j in (0..n_main).step_by(TILE_COLS) {
+                let mut acc = [[_mm256_setzero_pd(); 2]; TILE_ROWS];
+                for (r, acc_row) in acc.iter_mut().enumerate() {
+                    let p = c.as_ptr().add((i + r) * ldc + j);
+                    *acc_row = [_mm256_loadu_pd(p), _mm256_loadu_pd(p.add(LANES))];
+                }
+                for kk in 0..k {
+                    let p = b.as_ptr().add(kk * ldb + j);
+                    let (b0, b1) = (_mm256_loadu_pd(p), _mm256_loadu_pd(p.add(LANES)));
+                    for (r, acc_row) in acc.iter_mut().enumerate() {
+                        let av = _mm256_broadcast_sd(&*a.as_ptr().add((i + r) * lda + kk));
+                        acc_row[0] = _mm256_add_pd(acc_row[0], _mm256_mul_pd(av, b0));
+                        acc_row[1] = _mm256_add_pd(acc_row[1], _mm256_mul_pd(av, b1));
+                    }
+                }
+                for (r, acc_row) in acc.iter().enumerate() {
+                    let p = c.as_mut_ptr().add((i + r) * ldc + j);
+                    _mm256_storeu_pd(p, acc_row[0]);
+                    _mm256_storeu_pd(p.add(LANES), acc_row[1]);
+                }
+            }
+        }
+        super::scalar_gemm(0..m_main, n_main..n, k, a, lda, b, ldb, c, ldc);
+        super::scalar_gemm(m_main..m, 0..n, k, a, lda, b, ldb, c, ldc);
+    }
+
+    /// # Safety
+    /// The CPU must support AVX.
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        let a = _mm256_set1_pd(alpha);
+        for i in (0..split).step_by(LANES) {
+            let xv = _mm256_loadu_pd(x.as_ptr().add(i));
+            let yv = _mm256_loadu_pd(y.as_ptr().add(i));
+            _mm256_storeu_pd(y.as_mut_ptr().add(i), _mm256_add_pd(yv, _mm256_mul_pd(a, xv)));
+        }
+        super::scalar_axpy(alpha, &x[split..n], &mut y[split..n]);
+    }
+
+    /// # Safety
+    /// The CPU must support AVX.
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn add(x: &[f64], y: &mut [f64]) {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        for i in (0..split).step_by(LANES) {
+            let xv = _mm256_loadu_pd(x.as_ptr().add(i));
+            let yv = _mm256_loadu_pd(y.as_ptr().add(i));
+            _mm256_storeu_pd(y.as_mut_ptr().add(i), _mm256_add_pd(yv, xv));
+        }
+        super::scalar_add(&x[split..n], &mut y[split..n]);
+    }
+
+    /// # Safety
+    /// The CPU must support AVX.
+    #[target_feature(enable = "avx")]
+    pub(super) unsafe fn dot(x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        let mut sum = _mm256_setzero_pd();
+        for i in (0..split).step_by(LANES) {
+            let xv = _mm256_loadu_pd(x.as_ptr().add(i));
+            let yv = _mm256_loadu_pd(y.as_ptr().add(i));
+            sum = _mm256_add_pd(sum, _mm256_mul_pd(xv, yv));
+        }
+        let mut lanes = [0.0; LANES];
+        _mm256_storeu_pd(lanes.as_mut_ptr(), sum);
+        let tail: f64 = x[split..n].iter().zip(&y[split..n]).map(|(a, b)| a * b).sum();
+        lanes.iter().sum::<f64>() + tail
+    }
+}
+
+#[cfg(target_arch = "aarch64")]
+mod neon {
+    use std::arch::aarch64::*;
+
+    const LANES: usize = 2;
+
+    /// # Safety
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        let a = vdupq_n_f64(alpha);
+        for i in (0..split).step_by(LANES) {
+            let xv = vld1q_f64(x.as_ptr().add(i));
+            let yv = vld1q_f64(y.as_ptr().add(i));
+            vst1q_f64(y.as_mut_ptr().add(i), vaddq_f64(yv, vmulq_f64(a, xv)));
+        }
+        super::scalar_axpy(alpha, &x[split..n], &mut y[split..n]);
+    }
+
+    /// # Safety
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn add(x: &[f64], y: &mut [f64]) {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        for i in (0..split).step_by(LANES) {
+            let xv = vld1q_f64(x.as_ptr().add(i));
+            let yv = vld1q_f64(y.as_ptr().add(i));
+            vst1q_f64(y.as_mut_ptr().add(i), vaddq_f64(yv, xv));
+        }
+        super::scalar_add(&x[split..n], &mut y[split..n]);
+    }
+
+    /// # Safety
+    /// The CPU must support NEON.
+    #[target_feature(enable = "neon")]
+    pub(super) unsafe fn dot(x: &[f64], y: &[f64]) -> f64 {
+        let n = x.len().min(y.len());
+        let split = n - n % LANES;
+        let mut sum = vdupq_n_f64(0.0);
+        for i in (0..split).step_by(LANES) {
+            let xv = vld1q_f64(x.as_ptr().add(i));
+            let yv = vld1q_f64(y.as_ptr().add(i));
+            sum = vaddq_f64(sum, vmulq_f64(xv, yv));
+        }
+        let tail: f64 = x[split..n].iter().zip(&y[split..n]).map(|(a, b)| a * b).sum();
+        vaddvq_f64(sum) + tail
+    }
+}
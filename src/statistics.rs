@@ -0,0 +1,220 @@
+//! Descriptive statistics over slices of `f64`.
+//!
+//! Order statistics such as [`median`] and [`nth_smallest`] use a linear-time selection algorithm
+//! rather than sorting, and work in place: they reorder the slice they are given instead of
+//! allocating a sorted copy. Clone the data first if its order matters.
+//!
+//! Values are compared with [`f64::total_cmp`], so NaNs are accepted and sort after every other
+//! value (or before, for NaNs with the sign bit set).
+
+/// Returns the `k`-th smallest value of `data`, counting from zero.
+///
+/// The slice is partially reordered: afterwards the returned value sits at index `k`, every
+/// element before it is no larger, and every element after it is no smaller.
+///
+/// # Arguments
+///
+/// * `data` - The values to select from. Reordered in place.
+/// * `k` - The zero-based rank to select; `0` is the minimum and `data.len() - 1` the maximum.
+///
+/// # Returns
+///
+/// * `Some(value)` with the `k`-th smallest value.
+/// * `None` if `k` is out of bounds.
+///
+/// # Examples
+///
+/// ```
+/// use hell::statistics::nth_smallest;
+///
+/// let mut data = [9.0, 1.0, 8.0, 2.0, 7.0, 3.0];
+/// assert_eq!(nth_smallest(&mut data, 0), Some(1.0));
+/// assert_eq!(nth_smallest(&mut data, 4), Some(8.0));
+/// assert_eq!(nth_smallest(&mut data, 6), None);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n)` time on average and in the worst case, with no allocation. This is the
+/// introselect algorithm behind [`slice::select_nth_unstable_by`].
+pub fn nth_smallest(data: &mut [f64], k: usize) -> Option<f64> {
+    if k >= data.len() {
+        return None;
+    }
+    let (_, value, _) = data.select_nth_unstable_by(k, f64::total_cmp);
+    Some(*value)
+}
+
+/// Returns the median of `data`.
+///
+/// For an even number of values, the median is the mean of the two middle values.
+///
+/// # Arguments
+///
+/// * `data` - The values. Reordered in place.
+///
+/// # Returns
+///
+/// * `Some(median)` for a non-empty slice.
+/// * `None` if `data` is empty.
+///
+/// # Examples
+///
+/// ```
+/// use hell::statistics::median;
+///
+/// assert_eq!(median(&mut [3.0, 1.0, 2.0]), Some(2.0));
+/// assert_eq!(median(&mut [4.0, 1.0, 3.0, 2.0]), Some(2.5));
+/// assert_eq!(median(&mut []), None);
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n)` time using selection instead of sorting, which matters for large buffers. The
+/// lower middle value of an even-length slice is found with a linear scan of the lower partition
+/// left behind by the first selection.
+pub fn median(data: &mut [f64]) -> Option<f64> {
+    let n = data.len();
+    if n == 0 {
+        return None;
+    }
+    let (lower, &mut upper_middle, _) = data.select_nth_unstable_by(n / 2, f64::total_cmp);
+    if n % 2 == 1 {
+        return Some(upper_middle);
+    }
+    let lower_middle = lower.iter().copied().max_by(f64::total_cmp)?;
+    Some(lower_middle + (upper_middle - lower_middle) / 2.0)
+}
+
+/// Returns the `k`-th smallest value of `data`, counting from zero, partitioning in parallel.
+///
+/// This is the parallel counterpart of [`nth_smallest`]: it takes the same arguments, returns the
+/// same value and leaves the slice with the same guarantee, although the elements on either side
+/// of index `k` may be arranged differently. It is only available with the `parallel` feature.
+///
+/// Each round picks a pivot from an evenly spaced sample, splits the slice into the values below,
+/// equal to, and above it across the rayon thread pool, and continues in the part that holds
+/// index `k`. Once that part is small, [`nth_smallest`] finishes the job.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parallel")] {
+/// use hell::rng::Rng;
+/// use hell::statistics::{nth_smallest, par_nth_smallest};
+///
+/// let mut rng = Rng::new(7);
+/// let data: Vec<f64> = (0..200_000).map(|_| rng.normal(0.0, 1.0)).collect();
+/// let (mut a, mut b) = (data.clone(), data);
+/// assert_eq!(par_nth_smallest(&mut a, 1_234), nth_smallest(&mut b, 1_234));
+/// assert!(a[..1_234].iter().all(|&v| v <= a[1_234]));
+/// assert_eq!(par_nth_smallest(&mut a, 200_000), None);
+/// # }
+/// ```
+///
+/// # Performance
+///
+/// Runs in `O(n)` time on average. Unlike [`nth_smallest`] it allocates a buffer the size of
+/// `data`, and it only pays off for slices of a few hundred thousand values or more.
+#[cfg(feature = "parallel")]
+pub fn par_nth_smallest(data: &mut [f64], k: usize) -> Option<f64> {
+    use rayon::prelude::*;
+    use std::cmp::Ordering;
+
+    /// Below this many values, selection finishes serially.
+    const SERIAL_CUTOFF: usize = 1 << 16;
+    /// The number of values the pivot is chosen from.
+    const SAMPLE: usize = 1024;
+
+    if k >= data.len() {
+        return None;
+    }
+    // Invariant: every value in data[..lo] is at most every value in data[lo..hi], which is at
+    // most every value in data[hi..], and lo ≤ k < hi.
+    let (mut lo, mut hi) = (0, data.len());
+    let mut buffer = Vec::new();
+    while hi - lo > SERIAL_CUTOFF {
+        let range = &mut data[lo..hi];
+        let mut sample: Vec<f64> = range.iter().step_by(range.len() / SAMPLE).copied().collect();
+        let middle = sample.len() / 2;
+        let pivot = *sample.select_nth_unstable_by(middle, f64::total_cmp).1;
+
+        // Count each chunk's values below and equal to the pivot, then copy them into their
+        // places in the buffer, every chunk writing to its own disjoint slices.
+        let chunk = range.len().div_ceil(4 * rayon::current_num_threads());
+        let counts: Vec<(usize, usize, usize)> = range
+            .par_chunks(chunk)
+            .map(|values| {
+                let (below, equal) = values.iter().fold((0, 0), |(below, equal), v| match v.total_cmp(&pivot) {
+                    Ordering::Less => (below + 1, equal),
+                    Ordering::Equal => (below, equal + 1),
+                    Ordering::Greater => (below, equal),
+                });
+                (below, equal, values.len() - below - equal)
+            })
+            .collect();
+        let below: usize = counts.iter().map(|c| c.0).sum();
+        let equal: usize = counts.iter().map(|c| c.1).sum();
+        buffer.resize(range.len(), 0.0);
+        let (mut below_out, rest) = buffer.split_at_mut(below);
+        let (mut equal_out, mut above_out) = rest.split_at_mut(equal);
+        let mut targets = Vec::with_capacity(counts.len());
+        for &(b, e, a) in &counts {
+            let (b_out, b_rest) = std::mem::take(&mut below_out).split_at_mut(b);
+            let (e_out, e_rest) = std::mem::take(&mut equal_out).split_at_mut(e);
+            let (a_out, a_rest) = std::mem::take(&mut above_out).split_at_mut(a);
+            (below_out, equal_out, above_out) = (b_rest, e_rest, a_rest);
+            targets.push((b_out, e_out, a_out));
+        }
+        range.par_chunks(chunk).zip(targets).for_each(|(values, (b_out, e_out, a_out))| {
+            let (mut b, mut e, mut a) = (0, 0, 0);
+            for &v in values {
+                match v.total_cmp(&pivot) {
+                    Ordering::Less => (b_out[b], b) = (v, b + 1),
+                    Ordering::Equal => (e_out[e], e) = (v, e + 1),
+                    Ordering::Greater => (a_out[a], a) = (v, a + 1),
+                }
+            }
+        });
+        range.par_iter_mut().zip(buffer.par_iter()).for_each(|(v, &b)| *v = b);
+
+        match k - lo {
+            offset if offset < below => hi = lo + below,
+            offset if offset < below + equal => return Some(pivot),
+            _ => lo += below + equal,
+        }
+    }
+    nth_smallest(&mut data[lo..hi], k - lo)
+}
+
+/// Returns the median of `data`, selecting the middle values in parallel.
+///
+/// This is the parallel counterpart of [`median`] and takes the same argument. It uses
+/// [`par_nth_smallest`] for the selection, so it is only worthwhile for large slices. It is only
+/// available with the `parallel` feature.
+///
+/// # Examples
+///
+/// ```
+/// # #[cfg(feature = "parallel")] {
+/// use hell::rng::Rng;
+/// use hell::statistics::{median, par_median};
+///
+/// let mut rng = Rng::new(11);
+/// let data: Vec<f64> = (0..300_000).map(|_| rng.exponential(2.0)).collect();
+/// assert_eq!(par_median(&mut data.clone()), median(&mut data.clone()));
+/// assert_eq!(par_median(&mut []), None);
+/// # }
+/// ```
+#[cfg(feature = "parallel")]
+pub fn par_median(data: &mut [f64]) -> Option<f64> {
+    use rayon::prelude::*;
+
+    let n = data.len();
+    let upper_middle = par_nth_smallest(data, n / 2)?;
+    if n % 2 == 1 {
+        return Some(upper_middle);
+    }
+    let lower_middle = data[..n / 2].par_iter().copied().max_by(f64::total_cmp)?;
+    Some(lower_middle + (upper_middle - lower_middle) / 2.0)
+}
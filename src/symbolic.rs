@@ -0,0 +1,961 @@
+use crate::error::HellError;
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+/// An elementary function that can appear in an [`Expr`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Func {
+    Sin,
+    Cos,
+    Tan,
+    Exp,
+    Ln,
+    Sqrt,
+}
+
+impl Func {
+    /// Returns the name used when printing or parsing the function.
+    pub fn name(self) -> &'static str {
+        match self {
+            Func::Sin => "sin",
+            Func::Cos => "cos",
+            Func::Tan => "tan",
+            Func::Exp => "exp",
+            Func::Ln => "ln",
+            Func::Sqrt => "sqrt",
+        }
+    }
+
+    /// Applies the function to a number.
+    pub fn apply(self, x: f64) -> f64 {
+        match self {
+            Func::Sin => x.sin(),
+            Func::Cos => x.cos(),
+            Func::Tan => x.tan(),
+            Func::Exp => x.exp(),
+            Func::Ln => x.ln(),
+            Func::Sqrt => x.sqrt(),
+        }
+    }
+}
+
+/// A symbolic mathematical expression, represented as a tree.
+///
+/// Expressions are built from constants, named variables, the arithmetic operators, powers, and
+/// the elementary functions in [`Func`]. They can be evaluated numerically with [`Expr::eval`] and
+/// differentiated exactly with [`Expr::differentiate`].
+///
+/// The arithmetic operators are overloaded on `Expr`, so trees can be written naturally.
+///
+/// # Examples
+///
+/// ```
+/// use hell::symbolic::Expr;
+/// use std::collections::HashMap;
+///
+/// // f(x, y) = x^2 * y + sin(x)
+/// let x = Expr::var("x");
+/// let y = Expr::var("y");
+/// let f = x.clone().pow(Expr::from(2.0)) * y + x.sin();
+///
+/// let vars = HashMap::from([("x", 0.0), ("y", 5.0)]);
+/// assert_eq!(f.eval(&vars), Some(0.0));
+/// assert_eq!(f.to_string(), "x^2 * y + sin(x)");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    /// A numeric constant.
+    Const(f64),
+    /// A named variable.
+    Var(String),
+    /// The sum of two expressions.
+    Add(Box<Expr>, Box<Expr>),
+    /// The difference of two expressions.
+    Sub(Box<Expr>, Box<Expr>),
+    /// The product of two expressions.
+    Mul(Box<Expr>, Box<Expr>),
+    /// The quotient of two expressions.
+    Div(Box<Expr>, Box<Expr>),
+    /// The first expression raised to the power of the second.
+    Pow(Box<Expr>, Box<Expr>),
+    /// The negation of an expression.
+    Neg(Box<Expr>),
+    /// An elementary function applied to an expression.
+    Func(Func, Box<Expr>),
+}
+
+impl Expr {
+    /// Creates a variable with the given name.
+    pub fn var(name: &str) -> Expr {
+        Expr::Var(name.to_string())
+    }
+
+    /// Parses an expression from text.
+    ///
+    /// The grammar supports numbers (`2`, `0.5`, `1e-3`), variables (identifiers such as `x` or
+    /// `rate`), the constants `pi` and `e`, the operators `+ - * / ^` with the usual precedence
+    /// (`^` binds tightest and is right-associative), unary minus, parentheses, and calls to the
+    /// functions in [`Func`] by name, e.g. `sin(x)`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(expr)`, or `Err(HellError::ParseError)` describing the first syntax error found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    /// use std::collections::HashMap;
+    ///
+    /// let f = Expr::parse("2 * x^2 - sin(pi * x) / 4").unwrap();
+    /// let value = f.eval(&HashMap::from([("x", 0.5)])).unwrap();
+    /// assert!((value - 0.25).abs() < 1e-12);
+    ///
+    /// assert!(Expr::parse("2 * (x + 1").is_err());
+    /// ```
+    pub fn parse(input: &str) -> Result<Expr, HellError> {
+        let tokens = tokenize(input)?;
+        let mut parser = Parser { tokens, pos: 0 };
+        let expr = parser.expression()?;
+        if parser.pos != parser.tokens.len() {
+            return Err(HellError::ParseError("Unexpected token after end of expression."));
+        }
+        Ok(expr)
+    }
+
+    /// Raises `self` to the power `exponent`.
+    pub fn pow(self, exponent: Expr) -> Expr {
+        Expr::Pow(Box::new(self), Box::new(exponent))
+    }
+
+    /// Applies an elementary function to `self`.
+    pub fn apply(self, func: Func) -> Expr {
+        Expr::Func(func, Box::new(self))
+    }
+
+    /// Returns `sin(self)`.
+    pub fn sin(self) -> Expr {
+        self.apply(Func::Sin)
+    }
+
+    /// Returns `cos(self)`.
+    pub fn cos(self) -> Expr {
+        self.apply(Func::Cos)
+    }
+
+    /// Returns `tan(self)`.
+    pub fn tan(self) -> Expr {
+        self.apply(Func::Tan)
+    }
+
+    /// Returns `exp(self)`.
+    pub fn exp(self) -> Expr {
+        self.apply(Func::Exp)
+    }
+
+    /// Returns `ln(self)`.
+    pub fn ln(self) -> Expr {
+        self.apply(Func::Ln)
+    }
+
+    /// Returns `sqrt(self)`.
+    pub fn sqrt(self) -> Expr {
+        self.apply(Func::Sqrt)
+    }
+
+    /// Evaluates the expression numerically.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - The values of the variables appearing in the expression.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(value)`, or `None` if the expression refers to a variable missing from `vars`.
+    ///   Domain errors such as `ln(-1)` follow `f64` semantics and produce `NaN` or infinities.
+    pub fn eval(&self, vars: &HashMap<&str, f64>) -> Option<f64> {
+        Some(match self {
+            Expr::Const(c) => *c,
+            Expr::Var(name) => *vars.get(name.as_str())?,
+            Expr::Add(a, b) => a.eval(vars)? + b.eval(vars)?,
+            Expr::Sub(a, b) => a.eval(vars)? - b.eval(vars)?,
+            Expr::Mul(a, b) => a.eval(vars)? * b.eval(vars)?,
+            Expr::Div(a, b) => a.eval(vars)? / b.eval(vars)?,
+            Expr::Pow(a, b) => a.eval(vars)?.powf(b.eval(vars)?),
+            Expr::Neg(a) => -a.eval(vars)?,
+            Expr::Func(func, a) => func.apply(a.eval(vars)?),
+        })
+    }
+
+    /// Returns `true` if the expression does not depend on the variable `var`.
+    pub fn is_constant_in(&self, var: &str) -> bool {
+        match self {
+            Expr::Const(_) => true,
+            Expr::Var(name) => name != var,
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) | Expr::Pow(a, b) => {
+                a.is_constant_in(var) && b.is_constant_in(var)
+            }
+            Expr::Neg(a) | Expr::Func(_, a) => a.is_constant_in(var),
+        }
+    }
+
+    /// Computes the exact partial derivative of the expression with respect to `var`.
+    ///
+    /// The standard rules are applied recursively: linearity, the product and quotient rules,
+    /// the power rule (and the general rule `d(u^v) = u^v (v' ln u + v u' / u)` when the exponent
+    /// depends on `var`), and the chain rule for the elementary functions. The result is not
+    /// simplified and may contain redundant terms such as `0 * x`.
+    ///
+    /// # Arguments
+    ///
+    /// * `var` - The name of the variable to differentiate with respect to. All other variables
+    ///   are treated as constants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    /// use hell::calculus::derivative;
+    /// use std::collections::HashMap;
+    ///
+    /// // f(x) = x * sin(x), f'(x) = sin(x) + x * cos(x)
+    /// let x = Expr::var("x");
+    /// let f = x.clone() * x.sin();
+    /// let df = f.differentiate("x");
+    ///
+    /// let at = |e: &Expr, v: f64| e.eval(&HashMap::from([("x", v)])).unwrap();
+    /// let exact = at(&df, 1.0);
+    /// assert!((exact - (1f64.sin() + 1f64.cos())).abs() < 1e-12);
+    ///
+    /// // Agrees with the numerical estimate.
+    /// let numeric = derivative(|v| at(&f, v), 1.0, 1e-5);
+    /// assert!((exact - numeric).abs() < 1e-8);
+    /// ```
+    pub fn differentiate(&self, var: &str) -> Expr {
+        match self {
+            Expr::Const(_) => Expr::Const(0.0),
+            Expr::Var(name) => Expr::Const(if name == var { 1.0 } else { 0.0 }),
+            Expr::Add(a, b) => a.differentiate(var) + b.differentiate(var),
+            Expr::Sub(a, b) => a.differentiate(var) - b.differentiate(var),
+            Expr::Mul(a, b) => {
+                a.differentiate(var) * (**b).clone() + (**a).clone() * b.differentiate(var)
+            }
+            Expr::Div(a, b) => {
+                (a.differentiate(var) * (**b).clone() - (**a).clone() * b.differentiate(var))
+                    / (**b).clone().pow(Expr::Const(2.0))
+            }
+            Expr::Pow(base, exponent) => {
+                if exponent.is_constant_in(var) {
+                    // Power rule with chain rule: n * u^(n - 1) * u'
+                    (**exponent).clone()
+                        * (**base).clone().pow((**exponent).clone() - Expr::Const(1.0))
+                        * base.differentiate(var)
+                } else {
+                    // d(u^v) = u^v * (v' * ln(u) + v * u' / u)
+                    self.clone()
+                        * (exponent.differentiate(var) * (**base).clone().ln()
+                            + (**exponent).clone() * base.differentiate(var) / (**base).clone())
+                }
+            }
+            Expr::Neg(a) => -a.differentiate(var),
+            Expr::Func(func, arg) => {
+                let u = (**arg).clone();
+                let du = arg.differentiate(var);
+                match func {
+                    Func::Sin => u.cos() * du,
+                    Func::Cos => -(u.sin()) * du,
+                    Func::Tan => du / u.cos().pow(Expr::Const(2.0)),
+                    Func::Exp => u.exp() * du,
+                    Func::Ln => du / u,
+                    Func::Sqrt => du / (Expr::Const(2.0) * u.sqrt()),
+                }
+            }
+        }
+    }
+
+    /// Shorthand for [`Expr::differentiate`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    /// use hell::calculus::derivative;
+    /// use std::collections::HashMap;
+    ///
+    /// // f(x) = x^3, f'(2) = 12
+    /// let f = Expr::var("x").pow(Expr::Const(3.0));
+    /// let at = |e: &Expr, v: f64| e.eval(&HashMap::from([("x", v)])).unwrap();
+    /// let exact = at(&f.diff("x"), 2.0);
+    /// assert!((exact - derivative(|v| at(&f, v), 2.0, 1e-5)).abs() < 1e-8);
+    /// ```
+    pub fn diff(&self, var: &str) -> Expr {
+        self.differentiate(var)
+    }
+
+    /// Simplifies the expression algebraically.
+    ///
+    /// The following rewrites are applied bottom-up until nothing changes:
+    ///
+    /// * Constant folding: `2 * 3 → 6`, `sin(0) → 0`.
+    /// * Identity elimination: `x + 0 → x`, `x * 1 → x`, `x * 0 → 0`, `x / 1 → x`, `x^1 → x`,
+    ///   `x^0 → 1`, `0 - x → -x`, `--x → x`.
+    /// * Like-term combination: `x + x → 2 * x`, `3 * x - x → 2 * x`, `x * x → x^2`,
+    ///   `x^2 * x^3 → x^5`.
+    ///
+    /// The result is numerically equivalent to the original wherever the original is defined
+    /// (eliminating `x * 0` can remove a division by zero elsewhere in the tree).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    ///
+    /// let x = Expr::var("x");
+    /// let f = x.clone().pow(Expr::from(3.0)) + Expr::from(2.0) * x.clone();
+    ///
+    /// // The raw derivative is cluttered with `* 1` and `3 - 1`...
+    /// let df = f.differentiate("x");
+    /// assert_eq!(df.to_string(), "3 * x^(3 - 1) * 1 + 0 * x + 2 * 1");
+    ///
+    /// // ...which simplification cleans up.
+    /// assert_eq!(df.simplify().to_string(), "3 * x^2 + 2");
+    ///
+    /// assert_eq!((x.clone() + x.clone()).simplify().to_string(), "2 * x");
+    /// assert_eq!((x.clone() * x).simplify().to_string(), "x^2");
+    ///
+    /// // Powers of powers are only merged when that is valid for negative bases.
+    /// let x = Expr::var("x");
+    /// assert_eq!(x.clone().pow(Expr::from(2.0)).pow(Expr::from(3.0)).simplify().to_string(), "x^6");
+    /// assert_eq!(x.clone().pow(Expr::from(2.0)).pow(Expr::from(0.5)).simplify().to_string(), "(x^2)^0.5");
+    ///
+    /// // Undefined constants are kept rather than simplified away.
+    /// let g = Expr::parse("sqrt(0 - 1) + x").unwrap().simplify();
+    /// assert!(g.eval(&std::collections::HashMap::from([("x", 1.0)])).unwrap().is_nan());
+    /// ```
+    pub fn simplify(&self) -> Expr {
+        let mut current = self.clone();
+        loop {
+            let next = current.simplify_once();
+            if next.is_identical(&current) {
+                return next;
+            }
+            current = next;
+        }
+    }
+
+    /// Returns whether two expressions have the same structure and bitwise-equal constants.
+    ///
+    /// Unlike `==`, this treats a `NaN` constant as identical to itself, so a pass that leaves a
+    /// `NaN` in place is still recognized as making no change.
+    fn is_identical(&self, other: &Expr) -> bool {
+        match (self, other) {
+            (Expr::Const(a), Expr::Const(b)) => a.to_bits() == b.to_bits(),
+            (Expr::Var(a), Expr::Var(b)) => a == b,
+            (Expr::Add(a, b), Expr::Add(c, d))
+            | (Expr::Sub(a, b), Expr::Sub(c, d))
+            | (Expr::Mul(a, b), Expr::Mul(c, d))
+            | (Expr::Div(a, b), Expr::Div(c, d))
+            | (Expr::Pow(a, b), Expr::Pow(c, d)) => a.is_identical(c) && b.is_identical(d),
+            (Expr::Neg(a), Expr::Neg(b)) => a.is_identical(b),
+            (Expr::Func(f, a), Expr::Func(g, b)) => f == g && a.is_identical(b),
+            _ => false,
+        }
+    }
+
+    /// Performs one bottom-up simplification pass.
+    fn simplify_once(&self) -> Expr {
+        match self {
+            Expr::Const(_) | Expr::Var(_) => self.clone(),
+            Expr::Add(a, b) => simplify_add(a.simplify_once(), b.simplify_once()),
+            Expr::Sub(a, b) => simplify_sub(a.simplify_once(), b.simplify_once()),
+            Expr::Mul(a, b) => simplify_mul(a.simplify_once(), b.simplify_once()),
+            Expr::Div(a, b) => simplify_div(a.simplify_once(), b.simplify_once()),
+            Expr::Pow(a, b) => simplify_pow(a.simplify_once(), b.simplify_once()),
+            Expr::Neg(a) => match a.simplify_once() {
+                Expr::Const(c) => Expr::Const(-c),
+                Expr::Neg(inner) => *inner,
+                other => -other,
+            },
+            Expr::Func(func, a) => match a.simplify_once() {
+                Expr::Const(c) => Expr::Const(func.apply(c)),
+                other => other.apply(*func),
+            },
+        }
+    }
+
+    /// Renders the expression as LaTeX math markup.
+    ///
+    /// Quotients use `\frac`, products use `\cdot`, and the elementary functions use their
+    /// standard LaTeX commands (`exp` is written as a power of `e`). Parentheses are only
+    /// inserted where precedence requires them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    ///
+    /// let x = Expr::var("x");
+    /// let f = (x.clone().pow(Expr::from(2.0)) + Expr::from(1.0)) / x.clone().sqrt() * x.sin();
+    /// assert_eq!(f.to_latex(), r"\frac{x^{2} + 1}{\sqrt{x}} \cdot \sin\left(x\right)");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        // Renders `child`, wrapped in parentheses if it binds more loosely than `min`.
+        let child = |child: &Expr, min: u8| {
+            if child.precedence() < min {
+                format!(r"\left({}\right)", child.to_latex())
+            } else {
+                child.to_latex()
+            }
+        };
+        match self {
+            Expr::Const(c) => format!("{}", c),
+            Expr::Var(name) => name.clone(),
+            Expr::Add(a, b) => format!("{} + {}", child(a, 1), child(b, 1)),
+            Expr::Sub(a, b) => format!("{} - {}", child(a, 1), child(b, 2)),
+            Expr::Mul(a, b) => format!(r"{} \cdot {}", child(a, 2), child(b, 2)),
+            Expr::Div(a, b) => format!(r"\frac{{{}}}{{{}}}", a.to_latex(), b.to_latex()),
+            Expr::Pow(a, b) => format!("{}^{{{}}}", child(a, 5), b.to_latex()),
+            Expr::Neg(a) => format!("-{}", child(a, 4)),
+            Expr::Func(Func::Exp, a) => format!("e^{{{}}}", a.to_latex()),
+            Expr::Func(Func::Sqrt, a) => format!(r"\sqrt{{{}}}", a.to_latex()),
+            Expr::Func(func, a) => format!(r"\{}\left({}\right)", func.name(), a.to_latex()),
+        }
+    }
+
+    /// Compiles the expression into a flat instruction list for fast repeated evaluation.
+    ///
+    /// Each distinct variable is bound to a numbered slot (in order of first appearance, see
+    /// [`CompiledExpr::variables`]), and the tree is flattened into postfix instructions for a
+    /// small stack machine. Evaluating the compiled form avoids both the recursive tree walk and
+    /// the per-variable `HashMap` lookups of [`Expr::eval`], which matters when the same formula
+    /// is evaluated many times, for example inside [`crate::calculus::integral`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::calculus::integral;
+    /// use hell::symbolic::Expr;
+    ///
+    /// let x = Expr::var("x");
+    /// let f = x.clone() * x.clone() + x.sin();
+    /// let compiled = f.compile();
+    ///
+    /// assert_eq!(compiled.variables(), &["x".to_string()]);
+    /// assert_eq!(compiled.eval(&[2.0]), 4.0 + 2f64.sin());
+    ///
+    /// let area = integral(|v| compiled.eval(&[v]), 0.0, 1.0, 100_000);
+    /// assert!((area - (1.0 / 3.0 + 1.0 - 1f64.cos())).abs() < 1e-9);
+    /// ```
+    pub fn compile(&self) -> CompiledExpr {
+        let mut compiled = CompiledExpr { variables: Vec::new(), code: Vec::new(), stack_size: 0 };
+        let mut depth = 0;
+        self.emit(&mut compiled, &mut depth);
+        compiled
+    }
+
+    /// Appends the postfix instructions for `self` to `out`, tracking the stack depth.
+    fn emit(&self, out: &mut CompiledExpr, depth: &mut usize) {
+        let push = |out: &mut CompiledExpr, depth: &mut usize, instr: Instr| {
+            out.code.push(instr);
+            *depth += 1;
+            out.stack_size = out.stack_size.max(*depth);
+        };
+        match self {
+            Expr::Const(c) => push(out, depth, Instr::Const(*c)),
+            Expr::Var(name) => {
+                let slot = match out.variables.iter().position(|v| v == name) {
+                    Some(slot) => slot,
+                    None => {
+                        out.variables.push(name.clone());
+                        out.variables.len() - 1
+                    }
+                };
+                push(out, depth, Instr::Load(slot));
+            }
+            Expr::Add(a, b) | Expr::Sub(a, b) | Expr::Mul(a, b) | Expr::Div(a, b) | Expr::Pow(a, b) => {
+                a.emit(out, depth);
+                b.emit(out, depth);
+                out.code.push(match self {
+                    Expr::Add(..) => Instr::Add,
+                    Expr::Sub(..) => Instr::Sub,
+                    Expr::Mul(..) => Instr::Mul,
+                    Expr::Div(..) => Instr::Div,
+                    _ => Instr::Pow,
+                });
+                *depth -= 1;
+            }
+            Expr::Neg(a) => {
+                a.emit(out, depth);
+                out.code.push(Instr::Neg);
+            }
+            Expr::Func(func, a) => {
+                a.emit(out, depth);
+                out.code.push(Instr::Func(*func));
+            }
+        }
+    }
+
+    /// Returns the binding strength of the top-level node, used to decide where parentheses are
+    /// needed when printing.
+    fn precedence(&self) -> u8 {
+        match self {
+            Expr::Add(..) | Expr::Sub(..) => 1,
+            Expr::Mul(..) | Expr::Div(..) => 2,
+            Expr::Neg(_) => 3,
+            Expr::Const(c) if *c < 0.0 => 3,
+            Expr::Pow(..) => 4,
+            Expr::Const(_) | Expr::Var(_) | Expr::Func(..) => 5,
+        }
+    }
+}
+
+/// A lexical token of the expression grammar accepted by [`Expr::parse`].
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Ident(String),
+    Op(char),
+    LParen,
+    RParen,
+}
+
+/// Splits the input text into tokens.
+fn tokenize(input: &str) -> Result<Vec<Token>, HellError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || c == '.' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                i += 1;
+            }
+            // Optional exponent, e.g. 1e-3.
+            if i < chars.len() && (chars[i] == 'e' || chars[i] == 'E') {
+                let mut j = i + 1;
+                if j < chars.len() && (chars[j] == '+' || chars[j] == '-') {
+                    j += 1;
+                }
+                if j < chars.len() && chars[j].is_ascii_digit() {
+                    i = j;
+                    while i < chars.len() && chars[i].is_ascii_digit() {
+                        i += 1;
+                    }
+                }
+            }
+            let text: String = chars[start..i].iter().collect();
+            tokens.push(Token::Number(text.parse().map_err(|_| HellError::ParseError("Invalid number."))?));
+        } else if c.is_alphabetic() || c == '_' {
+            let start = i;
+            while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                i += 1;
+            }
+            tokens.push(Token::Ident(chars[start..i].iter().collect()));
+        } else {
+            tokens.push(match c {
+                '+' | '-' | '*' | '/' | '^' => Token::Op(c),
+                '(' => Token::LParen,
+                ')' => Token::RParen,
+                _ => return Err(HellError::ParseError("Unexpected character in expression.")),
+            });
+            i += 1;
+        }
+    }
+    Ok(tokens)
+}
+
+/// A recursive-descent parser over a token stream.
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        token
+    }
+
+    /// expression := term (('+' | '-') term)*
+    fn expression(&mut self) -> Result<Expr, HellError> {
+        let mut lhs = self.term()?;
+        while let Some(Token::Op(op @ ('+' | '-'))) = self.peek().cloned() {
+            self.pos += 1;
+            let rhs = self.term()?;
+            lhs = if op == '+' { lhs + rhs } else { lhs - rhs };
+        }
+        Ok(lhs)
+    }
+
+    /// term := unary (('*' | '/') unary)*
+    fn term(&mut self) -> Result<Expr, HellError> {
+        let mut lhs = self.unary()?;
+        while let Some(Token::Op(op @ ('*' | '/'))) = self.peek().cloned() {
+            self.pos += 1;
+            let rhs = self.unary()?;
+            lhs = if op == '*' { lhs * rhs } else { lhs / rhs };
+        }
+        Ok(lhs)
+    }
+
+    /// unary := '-' unary | power
+    fn unary(&mut self) -> Result<Expr, HellError> {
+        if self.peek() == Some(&Token::Op('-')) {
+            self.pos += 1;
+            return Ok(-self.unary()?);
+        }
+        self.power()
+    }
+
+    /// power := atom ('^' unary)?
+    fn power(&mut self) -> Result<Expr, HellError> {
+        let base = self.atom()?;
+        if self.peek() == Some(&Token::Op('^')) {
+            self.pos += 1;
+            return Ok(base.pow(self.unary()?));
+        }
+        Ok(base)
+    }
+
+    /// atom := number | identifier | identifier '(' expression ')' | '(' expression ')'
+    fn atom(&mut self) -> Result<Expr, HellError> {
+        match self.next() {
+            Some(Token::Number(n)) => Ok(Expr::Const(n)),
+            Some(Token::LParen) => {
+                let inner = self.expression()?;
+                self.expect_rparen()?;
+                Ok(inner)
+            }
+            Some(Token::Ident(name)) => {
+                if self.peek() == Some(&Token::LParen) {
+                    self.pos += 1;
+                    let func = [Func::Sin, Func::Cos, Func::Tan, Func::Exp, Func::Ln, Func::Sqrt]
+                        .into_iter()
+                        .find(|f| f.name() == name)
+                        .ok_or(HellError::ParseError("Unknown function."))?;
+                    let arg = self.expression()?;
+                    self.expect_rparen()?;
+                    return Ok(arg.apply(func));
+                }
+                Ok(match name.as_str() {
+                    "pi" => Expr::Const(std::f64::consts::PI),
+                    "e" => Expr::Const(std::f64::consts::E),
+                    _ => Expr::Var(name),
+                })
+            }
+            Some(_) => Err(HellError::ParseError("Unexpected token in expression.")),
+            None => Err(HellError::ParseError("Unexpected end of expression.")),
+        }
+    }
+
+    fn expect_rparen(&mut self) -> Result<(), HellError> {
+        match self.next() {
+            Some(Token::RParen) => Ok(()),
+            _ => Err(HellError::ParseError("Missing closing parenthesis.")),
+        }
+    }
+}
+
+/// A single instruction of a [`CompiledExpr`] stack program.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Instr {
+    Const(f64),
+    Load(usize),
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Pow,
+    Neg,
+    Func(Func),
+}
+
+/// An [`Expr`] compiled into a flat postfix program with indexed variable slots.
+///
+/// Created by [`Expr::compile`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledExpr {
+    variables: Vec<String>,
+    code: Vec<Instr>,
+    stack_size: usize,
+}
+
+impl CompiledExpr {
+    /// Returns the variable names in slot order. The values passed to [`CompiledExpr::eval`]
+    /// must follow this order.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the slot index of the variable `name`, or `None` if it does not occur.
+    pub fn slot(&self, name: &str) -> Option<usize> {
+        self.variables.iter().position(|v| v == name)
+    }
+
+    /// Evaluates the compiled expression.
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - The variable values, indexed by slot (see [`CompiledExpr::variables`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is shorter than the number of variables.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::symbolic::Expr;
+    ///
+    /// let f = Expr::var("y") / Expr::var("x");
+    /// let compiled = f.compile();
+    /// let x = compiled.slot("x").unwrap();
+    /// let y = compiled.slot("y").unwrap();
+    ///
+    /// let mut values = [0.0; 2];
+    /// values[x] = 4.0;
+    /// values[y] = 10.0;
+    /// assert_eq!(compiled.eval(&values), 2.5);
+    /// ```
+    pub fn eval(&self, values: &[f64]) -> f64 {
+        assert!(values.len() >= self.variables.len(), "Not enough variable values.");
+        let mut stack: Vec<f64> = Vec::with_capacity(self.stack_size);
+        for instr in &self.code {
+            match *instr {
+                Instr::Const(c) => stack.push(c),
+                Instr::Load(slot) => stack.push(values[slot]),
+                Instr::Neg => {
+                    let top = stack.last_mut().expect("stack underflow");
+                    *top = -*top;
+                }
+                Instr::Func(func) => {
+                    let top = stack.last_mut().expect("stack underflow");
+                    *top = func.apply(*top);
+                }
+                binary => {
+                    let b = stack.pop().expect("stack underflow");
+                    let a = stack.last_mut().expect("stack underflow");
+                    *a = match binary {
+                        Instr::Add => *a + b,
+                        Instr::Sub => *a - b,
+                        Instr::Mul => *a * b,
+                        Instr::Div => *a / b,
+                        _ => a.powf(b),
+                    };
+                }
+            }
+        }
+        stack.pop().expect("stack underflow")
+    }
+}
+
+/// Splits a term into a numeric coefficient and the remaining factor, e.g. `3 * x → (3, x)`.
+fn split_coefficient(expr: Expr) -> (f64, Expr) {
+    match expr {
+        Expr::Mul(a, b) => match (*a, *b) {
+            (Expr::Const(c), t) | (t, Expr::Const(c)) => (c, t),
+            (a, b) => (1.0, a * b),
+        },
+        Expr::Neg(a) => {
+            let (c, t) = split_coefficient(*a);
+            (-c, t)
+        }
+        other => (1.0, other),
+    }
+}
+
+/// Rebuilds a term from a coefficient and a factor, eliminating trivial coefficients.
+fn join_coefficient(c: f64, term: Expr) -> Expr {
+    if c == 0.0 {
+        Expr::Const(0.0)
+    } else if c == 1.0 {
+        term
+    } else if c == -1.0 {
+        -term
+    } else {
+        Expr::Const(c) * term
+    }
+}
+
+/// Splits a factor into base and exponent, e.g. `x^3 → (x, 3)` and `x → (x, 1)`.
+fn split_power(expr: Expr) -> (Expr, Expr) {
+    match expr {
+        Expr::Pow(base, exponent) => (*base, *exponent),
+        other => (other, Expr::Const(1.0)),
+    }
+}
+
+fn simplify_add(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x + y),
+        (Expr::Const(0.0), e) | (e, Expr::Const(0.0)) => e,
+        (a, Expr::Neg(b)) => simplify_sub(a, *b),
+        (a, b) => {
+            let (ca, ta) = split_coefficient(a.clone());
+            let (cb, tb) = split_coefficient(b.clone());
+            if ta == tb {
+                join_coefficient(ca + cb, ta)
+            } else {
+                a + b
+            }
+        }
+    }
+}
+
+fn simplify_sub(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x - y),
+        (e, Expr::Const(0.0)) => e,
+        (Expr::Const(0.0), e) => -e,
+        (a, Expr::Neg(b)) => simplify_add(a, *b),
+        (a, b) => {
+            let (ca, ta) = split_coefficient(a.clone());
+            let (cb, tb) = split_coefficient(b.clone());
+            if ta == tb {
+                join_coefficient(ca - cb, ta)
+            } else {
+                a - b
+            }
+        }
+    }
+}
+
+fn simplify_mul(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x * y),
+        (Expr::Const(0.0), _) | (_, Expr::Const(0.0)) => Expr::Const(0.0),
+        (Expr::Const(1.0), e) | (e, Expr::Const(1.0)) => e,
+        (Expr::Const(-1.0), e) | (e, Expr::Const(-1.0)) => -e,
+        // Gather numeric factors at the front: c1 * (c2 * t) → (c1 * c2) * t.
+        (Expr::Const(c), Expr::Mul(x, y)) if matches!(*x, Expr::Const(_)) => {
+            let Expr::Const(inner) = *x else { unreachable!() };
+            Expr::Const(c * inner) * *y
+        }
+        (e, Expr::Const(c)) => Expr::Const(c) * e,
+        (Expr::Neg(a), b) | (b, Expr::Neg(a)) => -(*a * b),
+        (a, b) => {
+            let (base_a, exp_a) = split_power(a.clone());
+            let (base_b, exp_b) = split_power(b.clone());
+            if base_a == base_b {
+                base_a.pow(exp_a + exp_b)
+            } else {
+                a * b
+            }
+        }
+    }
+}
+
+fn simplify_div(a: Expr, b: Expr) -> Expr {
+    match (a, b) {
+        (Expr::Const(x), Expr::Const(y)) if y != 0.0 => Expr::Const(x / y),
+        (Expr::Const(z), e) if z == 0.0 && e != Expr::Const(0.0) => Expr::Const(0.0),
+        (e, Expr::Const(1.0)) => e,
+        (a, b) if a == b && a != Expr::Const(0.0) => Expr::Const(1.0),
+        (a, b) => a / b,
+    }
+}
+
+fn simplify_pow(base: Expr, exponent: Expr) -> Expr {
+    match (base, exponent) {
+        (Expr::Const(x), Expr::Const(y)) => Expr::Const(x.powf(y)),
+        (_, Expr::Const(0.0)) => Expr::Const(1.0),
+        (e, Expr::Const(1.0)) => e,
+        (Expr::Const(1.0), _) => Expr::Const(1.0),
+        // (u^a)^b → u^(a * b), which only holds for negative u when b is an integer or a is odd:
+        // (x^2)^0.5 is |x|, not x.
+        (Expr::Pow(u, a), Expr::Const(b)) if b.fract() == 0.0 || is_odd_integer(&a) => u.pow(*a * Expr::Const(b)),
+        (base, exponent) => base.pow(exponent),
+    }
+}
+
+/// Returns whether `e` is a constant odd integer.
+fn is_odd_integer(e: &Expr) -> bool {
+    matches!(e, Expr::Const(c) if c.fract() == 0.0 && c.rem_euclid(2.0) == 1.0)
+}
+
+impl From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        Expr::Const(value)
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Writes `child`, wrapped in parentheses if it binds more loosely than `min`.
+        let child = |f: &mut fmt::Formatter<'_>, child: &Expr, min: u8| {
+            if child.precedence() < min {
+                write!(f, "({})", child)
+            } else {
+                write!(f, "{}", child)
+            }
+        };
+        match self {
+            Expr::Const(c) => write!(f, "{}", c),
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Add(a, b) => {
+                child(f, a, 1)?;
+                write!(f, " + ")?;
+                child(f, b, 1)
+            }
+            Expr::Sub(a, b) => {
+                child(f, a, 1)?;
+                write!(f, " - ")?;
+                child(f, b, 2)
+            }
+            Expr::Mul(a, b) => {
+                child(f, a, 2)?;
+                write!(f, " * ")?;
+                child(f, b, 2)
+            }
+            Expr::Div(a, b) => {
+                child(f, a, 2)?;
+                write!(f, " / ")?;
+                child(f, b, 3)
+            }
+            Expr::Pow(a, b) => {
+                child(f, a, 5)?;
+                write!(f, "^")?;
+                child(f, b, 4)
+            }
+            Expr::Neg(a) => {
+                write!(f, "-")?;
+                child(f, a, 4)
+            }
+            Expr::Func(func, a) => write!(f, "{}({})", func.name(), a),
+        }
+    }
+}
+
+macro_rules! impl_expr_binary_op {
+    ($trait:ident, $method:ident, $variant:ident) => {
+        impl $trait for Expr {
+            type Output = Expr;
+
+            fn $method(self, other: Expr) -> Expr {
+                Expr::$variant(Box::new(self), Box::new(other))
+            }
+        }
+    };
+}
+
+impl_expr_binary_op!(Add, add, Add);
+impl_expr_binary_op!(Sub, sub, Sub);
+impl_expr_binary_op!(Mul, mul, Mul);
+impl_expr_binary_op!(Div, div, Div);
+
+impl Neg for Expr {
+    type Output = Expr;
+
+    fn neg(self) -> Expr {
+        Expr::Neg(Box::new(self))
+    }
+}
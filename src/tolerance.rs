@@ -0,0 +1,114 @@
+/// The tolerances used by comparison-sensitive numerical operations.
+///
+/// Floating-point results are rarely exact, so operations that must decide whether a value is
+/// "zero" or two values are "equal" (pivot selection, root classification, and similar) compare
+/// against a `Tolerance` instead of a hard-coded epsilon. Every such operation has a variant that
+/// accepts a `Tolerance`; the plain variant uses [`Tolerance::DEFAULT`].
+///
+/// A tolerance has two parts:
+///
+/// * `absolute` - Differences at or below this value are always negligible. This matters for
+///   values close to zero, where a relative comparison is meaningless.
+/// * `relative` - Differences at or below this fraction of the magnitude of the values involved
+///   are negligible. This keeps comparisons meaningful for both very large and very small data.
+///
+/// # Examples
+///
+/// ```
+/// use hell::tolerance::Tolerance;
+///
+/// let tol = Tolerance::default();
+/// assert!(tol.approx_eq(0.1 + 0.2, 0.3));
+/// assert!(!tol.approx_eq(1.0, 1.001));
+///
+/// let loose = Tolerance::new(1e-3, 1e-3);
+/// assert!(loose.approx_eq(1.0, 1.0005));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tolerance {
+    /// The largest difference that is always treated as negligible.
+    pub absolute: f64,
+    /// The largest difference, as a fraction of the magnitude of the values, treated as negligible.
+    pub relative: f64,
+}
+
+impl Tolerance {
+    /// The default tolerance: `1e-12` absolute and `1e-10` relative.
+    ///
+    /// This is loose enough to absorb the rounding error of typical double-precision computations,
+    /// while still distinguishing values that differ in their tenth significant digit.
+    ///
+    /// Linear solves ([`Matrix::solve`](crate::Matrix::solve),
+    /// [`fit_polynomial`](crate::polynomial::fit_polynomial) and the Newton steps of
+    /// [`solve_nonlinear_system`](crate::roots::solve_nonlinear_system)) treat a pivot as zero
+    /// when it is at most `1e-10` times the largest entry of the matrix. They used to allow pivots
+    /// down to `n · ε` times that entry, so systems that are nearly singular, with a condition
+    /// number above about `1e10`, are now reported as singular instead of solved. Pass a smaller
+    /// relative part to the `_with_tolerance` variants to restore the old behavior.
+    pub const DEFAULT: Tolerance = Tolerance { absolute: 1e-12, relative: 1e-10 };
+
+    /// Creates a tolerance from its absolute and relative parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either part is negative or NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    ///
+    /// let tol = Tolerance::new(1e-6, 0.0);
+    /// assert!(tol.approx_eq(5.0, 5.0000001));
+    /// ```
+    pub fn new(absolute: f64, relative: f64) -> Self {
+        assert!(absolute >= 0.0 && relative >= 0.0, "Tolerances must be non-negative.");
+        Tolerance { absolute, relative }
+    }
+
+    /// Returns `true` if `a` and `b` are equal within this tolerance.
+    ///
+    /// The values are equal if their difference is at most `absolute`, or at most `relative`
+    /// times the larger of their magnitudes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    ///
+    /// let tol = Tolerance::DEFAULT;
+    /// assert!(tol.approx_eq(1e20, 1e20 + 1e5)); // Relative part.
+    /// assert!(tol.approx_eq(0.0, 1e-13)); // Absolute part.
+    /// ```
+    pub fn approx_eq(&self, a: f64, b: f64) -> bool {
+        let diff = (a - b).abs();
+        diff <= self.absolute || diff <= self.relative * a.abs().max(b.abs())
+    }
+
+    /// Returns `true` if `value` is negligible compared to `scale`, the magnitude of the data it
+    /// was computed from.
+    ///
+    /// Only the relative part is used, so that a problem whose entries are all tiny is not treated
+    /// as entirely zero. Algorithms use this to decide, for example, whether a pivot is small
+    /// enough to call a matrix singular.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::tolerance::Tolerance;
+    ///
+    /// let tol = Tolerance::DEFAULT;
+    /// assert!(tol.is_negligible(1e-8, 1e4));
+    /// assert!(!tol.is_negligible(1e-20, 1e-15));
+    /// ```
+    pub fn is_negligible(&self, value: f64, scale: f64) -> bool {
+        value.abs() <= self.relative * scale.abs()
+    }
+}
+
+impl Default for Tolerance {
+    fn default() -> Self {
+        Tolerance::DEFAULT
+    }
+}
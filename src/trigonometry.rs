@@ -1,3 +1,4 @@
+use crate::error::HellError;
 use std::f64::consts::PI;
 
 /// Computes the sine of an angle provided in radians.
@@ -15,6 +16,8 @@ use std::f64::consts::PI;
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::sine;
+///
 /// let angle = std::f64::consts::PI / 2.0; // 90 degrees
 /// let sine_value = sine(angle);
 /// assert_eq!(sine_value, 1.0);
@@ -42,6 +45,8 @@ pub fn sine(angle_rad: f64) -> f64 {
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::cosine;
+///
 /// let angle = std::f64::consts::PI; // 180 degrees
 /// let cosine_value = cosine(angle);
 /// assert_eq!(cosine_value, -1.0);
@@ -69,9 +74,11 @@ pub fn cosine(angle_rad: f64) -> f64 {
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::tangent;
+///
 /// let angle = std::f64::consts::PI / 4.0; // 45 degrees
 /// let tangent_value = tangent(angle);
-/// assert_eq!(tangent_value, 1.0);
+/// assert!((tangent_value - 1.0).abs() < 1e-10);
 /// ```
 ///
 /// # Notes
@@ -91,14 +98,17 @@ pub fn tangent(angle_rad: f64) -> f64 {
 ///
 /// # Returns
 ///
-/// * An `Option<f64>` where `Some(angle)` is the result in radians, and `None` is returned if the input
-///   is outside the valid range of [-1, 1]. The result angle will be in the range of [-π/2, π/2].
+/// * A `Result<f64, HellError>` where `Ok(angle)` is the result in radians, and
+///   `Err(HellError::DomainError)` is returned if the input is outside the valid range of [-1, 1].
+///   The result angle will be in the range of [-π/2, π/2].
 ///
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::arcsine;
+///
 /// let value = 0.5;
-/// if let Some(angle) = arcsine(value) {
+/// if let Ok(angle) = arcsine(value) {
 ///     assert!((angle - std::f64::consts::PI / 6.0).abs() < 1e-10); // 30 degrees in radians
 /// }
 /// ```
@@ -106,11 +116,11 @@ pub fn tangent(angle_rad: f64) -> f64 {
 /// # Notes
 ///
 /// The arcsine function is the inverse of the sine function. It returns an angle such that `sin(angle) = value`.
-pub fn arcsine(value: f64) -> Option<f64> {
-    if value < -1.0 || value > 1.0 {
-        None // arcsine is only defined for values in the range [-1, 1]
+pub fn arcsine(value: f64) -> Result<f64, HellError> {
+    if !(-1.0..=1.0).contains(&value) {
+        Err(HellError::DomainError("Arcsine is only defined for values in the range [-1, 1]."))
     } else {
-        Some(value.asin())
+        Ok(value.asin())
     }
 }
 
@@ -123,14 +133,17 @@ pub fn arcsine(value: f64) -> Option<f64> {
 ///
 /// # Returns
 ///
-/// * An `Option<f64>` where `Some(angle)` is the result in radians, and `None` is returned if the input
-///   is outside the valid range of [-1, 1]. The result angle will be in the range of [0, π].
+/// * A `Result<f64, HellError>` where `Ok(angle)` is the result in radians, and
+///   `Err(HellError::DomainError)` is returned if the input is outside the valid range of [-1, 1].
+///   The result angle will be in the range of [0, π].
 ///
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::arccosine;
+///
 /// let value = 1.0;
-/// if let Some(angle) = arccosine(value) {
+/// if let Ok(angle) = arccosine(value) {
 ///     assert_eq!(angle, 0.0); // arccosine of 1.0 is 0 radians (0 degrees)
 /// }
 /// ```
@@ -138,11 +151,11 @@ pub fn arcsine(value: f64) -> Option<f64> {
 /// # Notes
 ///
 /// The arccosine function is the inverse of the cosine function. It returns an angle such that `cos(angle) = value`.
-pub fn arccosine(value: f64) -> Option<f64> {
-    if value < -1.0 || value > 1.0 {
-        None // arccosine is only defined for values in the range [-1, 1]
+pub fn arccosine(value: f64) -> Result<f64, HellError> {
+    if !(-1.0..=1.0).contains(&value) {
+        Err(HellError::DomainError("Arccosine is only defined for values in the range [-1, 1]."))
     } else {
-        Some(value.acos())
+        Ok(value.acos())
     }
 }
 
@@ -159,6 +172,8 @@ pub fn arccosine(value: f64) -> Option<f64> {
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::arctangent;
+///
 /// let value = 1.0;
 /// let angle = arctangent(value);
 /// assert_eq!(angle, std::f64::consts::PI / 4.0); // arctangent of 1.0 is π/4 radians (45 degrees)
@@ -185,6 +200,8 @@ pub fn arctangent(value: f64) -> f64 {
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::radians_to_degrees;
+///
 /// let angle_rad = std::f64::consts::PI;
 /// let angle_deg = radians_to_degrees(angle_rad);
 /// assert_eq!(angle_deg, 180.0);
@@ -212,6 +229,8 @@ pub fn radians_to_degrees(radians: f64) -> f64 {
 /// # Example
 ///
 /// ```
+/// use hell::trigonometry::degrees_to_radians;
+///
 /// let angle_deg = 180.0;
 /// let angle_rad = degrees_to_radians(angle_deg);
 /// assert_eq!(angle_rad, std::f64::consts::PI);
@@ -0,0 +1,267 @@
+//! Strongly typed physical quantities.
+//!
+//! Each quantity is a newtype around an `f64` in SI units. Quantities of the same kind can be added
+//! and subtracted, any quantity can be scaled by a plain `f64`, and dividing two quantities of the
+//! same kind gives a dimensionless `f64`. A small curated set of products and quotients yields the
+//! correct derived type, so mixing up units becomes a compile error instead of a silent bug.
+//!
+//! | Expression                                | Result                    |
+//! |-------------------------------------------|---------------------------|
+//! | `Meters / Seconds`                        | `MetersPerSecond`         |
+//! | `MetersPerSecond / Seconds`               | `MetersPerSecondSquared`  |
+//! | `MetersPerSecond * Seconds`               | `Meters`                  |
+//! | `MetersPerSecondSquared * Seconds`        | `MetersPerSecond`         |
+//! | `Kilograms * MetersPerSecondSquared`      | `Newtons`                 |
+//! | `Newtons / Kilograms`                     | `MetersPerSecondSquared`  |
+//! | `Radians / Seconds`                       | `RadiansPerSecond`        |
+//! | `RadiansPerSecond * Seconds`              | `Radians`                 |
+//!
+//! # Examples
+//!
+//! ```
+//! use hell::units::{Kilograms, Meters, MetersPerSecond, Seconds};
+//!
+//! let distance = Meters(100.0);
+//! let time = Seconds(9.58);
+//! let speed: MetersPerSecond = distance / time;
+//! assert!((speed.0 - 10.438).abs() < 1e-3);
+//!
+//! let acceleration = speed / Seconds(2.0);
+//! let force = Kilograms(94.0) * acceleration;
+//! assert!((force.0 - 490.6).abs() < 0.1);
+//!
+//! assert_eq!(format!("{:.1}", force), "490.6 N");
+//!
+//! // let nonsense = Meters(1.0) + Seconds(1.0); // Does not compile.
+//! ```
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+macro_rules! quantity {
+    ($(#[$meta:meta])* $name:ident, $symbol:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+        #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+        pub struct $name(pub f64);
+
+        impl $name {
+            /// The zero quantity.
+            pub const ZERO: $name = $name(0.0);
+
+            /// Returns the absolute value of the quantity.
+            pub fn abs(self) -> $name {
+                $name(self.0.abs())
+            }
+        }
+
+        impl Add for $name {
+            type Output = $name;
+
+            fn add(self, other: $name) -> $name {
+                $name(self.0 + other.0)
+            }
+        }
+
+        impl Sub for $name {
+            type Output = $name;
+
+            fn sub(self, other: $name) -> $name {
+                $name(self.0 - other.0)
+            }
+        }
+
+        impl Neg for $name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(-self.0)
+            }
+        }
+
+        impl AddAssign for $name {
+            fn add_assign(&mut self, other: $name) {
+                self.0 += other.0;
+            }
+        }
+
+        impl SubAssign for $name {
+            fn sub_assign(&mut self, other: $name) {
+                self.0 -= other.0;
+            }
+        }
+
+        impl Mul<f64> for $name {
+            type Output = $name;
+
+            fn mul(self, scalar: f64) -> $name {
+                $name(self.0 * scalar)
+            }
+        }
+
+        impl Mul<$name> for f64 {
+            type Output = $name;
+
+            fn mul(self, quantity: $name) -> $name {
+                $name(self * quantity.0)
+            }
+        }
+
+        impl Div<f64> for $name {
+            type Output = $name;
+
+            fn div(self, scalar: f64) -> $name {
+                $name(self.0 / scalar)
+            }
+        }
+
+        impl Div for $name {
+            type Output = f64;
+
+            fn div(self, other: $name) -> f64 {
+                self.0 / other.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)?;
+                write!(f, " {}", $symbol)
+            }
+        }
+    };
+}
+
+macro_rules! derived {
+    ($lhs:ident * $rhs:ident = $out:ident) => {
+        impl Mul<$rhs> for $lhs {
+            type Output = $out;
+
+            fn mul(self, other: $rhs) -> $out {
+                $out(self.0 * other.0)
+            }
+        }
+
+        impl Mul<$lhs> for $rhs {
+            type Output = $out;
+
+            fn mul(self, other: $lhs) -> $out {
+                $out(self.0 * other.0)
+            }
+        }
+
+        impl Div<$rhs> for $out {
+            type Output = $lhs;
+
+            fn div(self, other: $rhs) -> $lhs {
+                $lhs(self.0 / other.0)
+            }
+        }
+
+        impl Div<$lhs> for $out {
+            type Output = $rhs;
+
+            fn div(self, other: $lhs) -> $rhs {
+                $rhs(self.0 / other.0)
+            }
+        }
+    };
+    ($lhs:ident / $rhs:ident = $out:ident) => {
+        impl Div<$rhs> for $lhs {
+            type Output = $out;
+
+            fn div(self, other: $rhs) -> $out {
+                $out(self.0 / other.0)
+            }
+        }
+
+        impl Mul<$rhs> for $out {
+            type Output = $lhs;
+
+            fn mul(self, other: $rhs) -> $lhs {
+                $lhs(self.0 * other.0)
+            }
+        }
+
+        impl Mul<$out> for $rhs {
+            type Output = $lhs;
+
+            fn mul(self, other: $out) -> $lhs {
+                $lhs(self.0 * other.0)
+            }
+        }
+    };
+}
+
+quantity!(
+    /// A length in meters.
+    Meters, "m"
+);
+quantity!(
+    /// A duration in seconds.
+    Seconds, "s"
+);
+quantity!(
+    /// A mass in kilograms.
+    Kilograms, "kg"
+);
+quantity!(
+    /// A plane angle in radians.
+    Radians, "rad"
+);
+quantity!(
+    /// A speed in meters per second.
+    MetersPerSecond, "m/s"
+);
+quantity!(
+    /// An acceleration in meters per second squared.
+    MetersPerSecondSquared, "m/s²"
+);
+quantity!(
+    /// A force in newtons (kilogram meters per second squared).
+    Newtons, "N"
+);
+quantity!(
+    /// An angular velocity in radians per second.
+    RadiansPerSecond, "rad/s"
+);
+
+derived!(Meters / Seconds = MetersPerSecond);
+derived!(MetersPerSecond / Seconds = MetersPerSecondSquared);
+derived!(Kilograms * MetersPerSecondSquared = Newtons);
+derived!(Radians / Seconds = RadiansPerSecond);
+
+impl Radians {
+    /// Converts an angle in degrees to radians.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hell::units::Radians;
+    ///
+    /// assert_eq!(Radians::from_degrees(180.0), Radians(std::f64::consts::PI));
+    /// ```
+    pub fn from_degrees(degrees: f64) -> Radians {
+        Radians(degrees.to_radians())
+    }
+
+    /// Returns the angle in degrees.
+    pub fn to_degrees(self) -> f64 {
+        self.0.to_degrees()
+    }
+
+    /// Returns the sine of the angle.
+    pub fn sin(self) -> f64 {
+        self.0.sin()
+    }
+
+    /// Returns the cosine of the angle.
+    pub fn cos(self) -> f64 {
+        self.0.cos()
+    }
+
+    /// Returns the tangent of the angle.
+    pub fn tan(self) -> f64 {
+        self.0.tan()
+    }
+}
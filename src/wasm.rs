@@ -0,0 +1,101 @@
+//! JavaScript bindings for the crate, built with `wasm-bindgen`.
+//!
+//! This module is only compiled with the `wasm` feature. It exposes a small, JavaScript-friendly
+//! surface over the quadratic solver, the matrix operations and the expression parser, so the
+//! crate can power browser-based calculators and teaching tools. Build it with
+//! `wasm-pack build --target web -- --features wasm`.
+//!
+//! Matrices cross the boundary as flat, row-major `Float64Array`s together with their number of
+//! rows and columns, and failures are thrown as JavaScript `Error`s carrying the [`HellError`]
+//! message.
+//!
+//! # Examples
+//!
+//! ```text
+//! import init, { solveQuadratic, matrixMultiply, evaluate } from "./pkg/hell.js";
+//!
+//! await init();
+//! solveQuadratic(1, -3, 2);                            // Float64Array [2, 1]
+//! matrixMultiply([1, 2, 3, 4], 2, 2, [5, 6, 7, 8], 2, 2); // Float64Array [19, 22, 43, 50]
+//! evaluate("x^2 + y", ["x", "y"], [3, 1]);              // 10
+//! ```
+
+use std::collections::HashMap;
+
+use wasm_bindgen::prelude::*;
+
+use crate::error::HellError;
+use crate::matrix::Matrix;
+use crate::quadratic;
+use crate::symbolic::Expr;
+
+/// Solves `ax^2 + bx + c = 0` and returns the two real roots as `[root1, root2]`.
+///
+/// Throws if `a` is zero or the equation has no real roots.
+#[wasm_bindgen(js_name = solveQuadratic)]
+pub fn solve_quadratic(a: f64, b: f64, c: f64) -> Result<Vec<f64>, JsError> {
+    let (root1, root2) = quadratic::solve_quadratic(a, b, c)?;
+    Ok(vec![root1, root2])
+}
+
+/// Adds two `rows x cols` matrices given in row-major order.
+///
+/// Throws if the arrays do not match the given dimensions.
+#[wasm_bindgen(js_name = matrixAdd)]
+pub fn matrix_add(a: &[f64], b: &[f64], rows: usize, cols: usize) -> Result<Vec<f64>, JsError> {
+    let result = from_flat(a, rows, cols)?.add(&from_flat(b, rows, cols)?)?;
+    Ok(to_flat(&result))
+}
+
+/// Multiplies an `a_rows x a_cols` matrix by a `b_rows x b_cols` matrix, both in row-major order.
+///
+/// The result has `a_rows x b_cols` elements. Throws if the arrays do not match the given
+/// dimensions or the inner dimensions differ.
+#[wasm_bindgen(js_name = matrixMultiply)]
+pub fn matrix_multiply(
+    a: &[f64],
+    a_rows: usize,
+    a_cols: usize,
+    b: &[f64],
+    b_rows: usize,
+    b_cols: usize,
+) -> Result<Vec<f64>, JsError> {
+    let result = from_flat(a, a_rows, a_cols)?.multiply(&from_flat(b, b_rows, b_cols)?)?;
+    Ok(to_flat(&result))
+}
+
+/// Transposes a `rows x cols` matrix given in row-major order.
+///
+/// The result is a `cols x rows` matrix, also in row-major order.
+#[wasm_bindgen(js_name = matrixTranspose)]
+pub fn matrix_transpose(a: &[f64], rows: usize, cols: usize) -> Result<Vec<f64>, JsError> {
+    Ok(to_flat(&from_flat(a, rows, cols)?.transpose()))
+}
+
+/// Parses an expression such as `"2 * sin(x) + y^2"` and evaluates it.
+///
+/// `names` and `values` give the value of each variable, pairwise. Throws if the expression
+/// cannot be parsed, uses a variable that is not given, or the two arrays differ in length.
+#[wasm_bindgen]
+pub fn evaluate(expr: &str, names: Vec<String>, values: &[f64]) -> Result<f64, JsError> {
+    if names.len() != values.len() {
+        return Err(HellError::DimensionMismatch("Each variable name needs exactly one value.").into());
+    }
+    let vars: HashMap<&str, f64> = names.iter().map(String::as_str).zip(values.iter().copied()).collect();
+    Expr::parse(expr)?
+        .eval(&vars)
+        .ok_or_else(|| HellError::DomainError("Expression uses a variable without a value.").into())
+}
+
+/// Builds a matrix from a flat row-major slice, checking its length against the dimensions.
+fn from_flat(data: &[f64], rows: usize, cols: usize) -> Result<Matrix, HellError> {
+    if rows == 0 || cols == 0 || data.len() != rows * cols {
+        return Err(HellError::DimensionMismatch("Array length does not match the matrix dimensions."));
+    }
+    Ok(Matrix::from_flat(rows, cols, data.to_vec()))
+}
+
+/// Flattens a matrix into row-major order.
+fn to_flat(matrix: &Matrix) -> Vec<f64> {
+    matrix.to_rows().concat()
+}